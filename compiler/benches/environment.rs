@@ -0,0 +1,39 @@
+// compiler/benches/environment.rs
+
+// Times a recursive B+ function in environments holding more and more globals.
+// Calls share their closure environment instead of copying it, so the time per
+// run should stay about the same however many globals there are.
+// Run with: cargo bench --bench environment
+
+use std::time::Instant;
+
+use bplus_compiler::{Environment, Evaluator, Lexer, Object, Parser};
+
+const RECURSION: &str = "
+    dhoro gono = fn(n) { jodi (n == 0) { ferot 0; } ferot 1 + gono(n - 1); };
+    gono(200)
+";
+const RUNS: u32 = 50;
+
+fn main() {
+    let mut timings = Vec::new();
+    for globals in [10, 1_000, 10_000] {
+        let mut env = Environment::new();
+        for i in 0..globals {
+            env.set(format!("global_{}", i), Object::Integer(i), true);
+        }
+
+        let mut evaluator = Evaluator::new();
+        let start = Instant::now();
+        for _ in 0..RUNS {
+            let program = Parser::new(Lexer::new(RECURSION.to_string())).parse_program();
+            assert_eq!(evaluator.eval(program, &mut env), Object::Integer(200));
+        }
+        let per_run = start.elapsed().as_secs_f64() * 1000.0 / f64::from(RUNS);
+        println!("{:>6} globals: {:>8.3} ms per run", globals, per_run);
+        timings.push(per_run);
+    }
+
+    let ratio = timings[timings.len() - 1] / timings[0];
+    println!("largest / smallest environment: {:.2}x", ratio);
+}
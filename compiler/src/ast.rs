@@ -1,6 +1,7 @@
 // compiler/src/ast.rs
 
 // === IMPORTS ===
+use crate::token::Symbol;
 use std::fmt;
 
 // === PROGRAM STRUCTURE ===
@@ -14,6 +15,7 @@ pub enum Statement {
     // Variable declaration: let <name> = <value>;
     Let {
         name: Expression,  // Variable name as Expression::Identifier
+        type_annotation: Option<String>, // Optional declared type: dhoro x: purno_songkha = 5
         value: Expression, // Right-hand side expression
         mutable: bool, // Mutable flag
     },
@@ -51,6 +53,12 @@ pub enum Statement {
         body: Vec<Statement>,
     },
 
+    // Do-while loop: age koro { <body> } jotokhon <condition>
+    DoWhile {
+        body: Vec<Statement>,
+        condition: Expression,
+    },
+
     // For loop: jonno (<init>; <condition>; <update>) { <body> }
     For {
         init: Option<Box<Statement>>,         // Initialization
@@ -59,11 +67,36 @@ pub enum Statement {
         body: Vec<Statement>,                 // Loop body
     },
 
-    // Break statement: thamo;
-    Break,
+    // For-each loop: protitar jonno (<var> jekhane <iterable>) { <body> }
+    ForEach {
+        var: Expression,      // Loop variable as Expression::Identifier
+        iterable: Expression, // Array or string to iterate over
+        body: Vec<Statement>,
+    },
+
+    // Break statement: thamo; or thamo <label>; to leave an outer loop
+    Break(Option<Symbol>),
 
-    // Continue statement: choluk;
-    Continue,
+    // Continue statement: choluk; or choluk <label>; to go on with an outer loop
+    Continue(Option<Symbol>),
+
+    // Loop with a label for thamo/choluk to name: <label>: jotokhon (...) { ... }
+    Labeled {
+        label: Symbol,
+        body: Box<Statement>, // Always a loop statement
+    },
+
+    // Module import: import koro "<path>" ei hisebe <alias>;
+    Import {
+        path: String,  // Path to the .bp file, relative to the importing file
+        alias: String, // Name the module's bindings are available under
+    },
+
+    // Record type: type banao <Name> { <field>, ... }
+    TypeDefinition {
+        name: String,        // Type name, also the constructor: Name(value, ...)
+        fields: Vec<String>, // Field names, in constructor argument order
+    },
 }
 
 // === STATEMENT DISPLAY IMPLEMENTATION ===
@@ -71,12 +104,13 @@ pub enum Statement {
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Statement::Let { name, value, mutable } =>
-                if *mutable {
-                        write!(f, "dhoro {} = {};", name, value)
-                    } else {
-                        write!(f, "let {} = {};", name, value)
-                    },
+            Statement::Let { name, type_annotation, value, mutable } => {
+                let keyword = if *mutable { "dhoro" } else { "let" };
+                match type_annotation {
+                    Some(ty) => write!(f, "{} {}: {} = {};", keyword, name, ty, value),
+                    None => write!(f, "{} {} = {};", keyword, name, value),
+                }
+            }
             Statement::Assign { name, value } =>
                 write!(f, "{} = {};", name, value),
 
@@ -101,6 +135,15 @@ impl fmt::Display for Statement {
                 write!(f, "{}", s)
             }
 
+            Statement::DoWhile { body, condition } => {
+                let mut s = String::from("age koro { ");
+                for stmt in body {
+                    s.push_str(&format!("{}", stmt));
+                }
+                s.push_str(&format!(" }} jotokhon {};", condition));
+                write!(f, "{}", s)
+            }
+
             Statement::For { init, condition, update, body } => {
                 let mut s = String::from("jonno (");
 
@@ -126,39 +169,84 @@ impl fmt::Display for Statement {
                 write!(f, "{}", s)
             }
 
-            Statement::Break =>
+            Statement::ForEach { var, iterable, body } => {
+                let mut s = format!("protitar jonno ({} jekhane {}) {{ ", var, iterable);
+                for stmt in body {
+                    s.push_str(&format!("{}", stmt));
+                }
+                s.push_str(" }");
+                write!(f, "{}", s)
+            }
+
+            Statement::Break(None) =>
                 write!(f, "thamo;"),
 
-            Statement::Continue =>
+            Statement::Break(Some(label)) =>
+                write!(f, "thamo {};", label),
+
+            Statement::Continue(None) =>
                 write!(f, "choluk;"),
 
+            Statement::Continue(Some(label)) =>
+                write!(f, "choluk {};", label),
+
+            Statement::Labeled { label, body } =>
+                write!(f, "{}: {}", label, body),
+
+            Statement::Import { path, alias } =>
+                write!(f, "import koro {:?} ei hisebe {};", path, alias),
+
+            Statement::TypeDefinition { name, fields } =>
+                write!(f, "type banao {} {{ {} }}", name, fields.join(", ")),
+
             Statement::Expression(expr) =>
                 write!(f, "{}", expr),
         }
     }
 }
 
+// === SOURCE POSITIONS ===
+// Line and column (both 1-based) of the token an expression was parsed from.
+// Spans never affect equality: the same code laid out differently compares equal.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _other: &Span) -> bool {
+        true
+    }
+}
+
 // === EXPRESSION ENUM DEFINITION ===
 // The 'Expression' enum defines all possible expressions in B+.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression {
-    Identifier(String),            // e.g., variable name
+    Identifier(Symbol, Span),      // e.g., variable name, shared with the token it came from
 
     IntegerLiteral(i64),           // e.g., 123
 
+    FloatLiteral(f64),             // e.g., 3.14
+
     StringLiteral(String),         // e.g., "hello"
 
+    CharLiteral(char),             // e.g., 'a'
+
     Boolean(bool),                 // Ha / Na
 
     Prefix {
         operator: String,
         right: Box<Expression>,
+        span: Span, // Operator position
     },
 
     Infix {
         left: Box<Expression>,
         operator: String,
         right: Box<Expression>,
+        span: Span, // Operator position
     },
 
     If {
@@ -169,17 +257,55 @@ pub enum Expression {
 
     FunctionLiteral {
         parameters: Vec<Expression>,
+        parameter_types: Vec<Option<String>>, // Optional annotation per parameter: fn(a: shobdo)
+        parameter_defaults: Vec<Option<Expression>>, // Optional default per parameter: fn(a, b = 10)
+        variadic: bool, // Last parameter collects any extra arguments: fn(a, ...rest)
         body: Vec<Statement>,
     },
 
     Call {
         function: Box<Expression>,
         arguments: Vec<Expression>,
+        span: Span, // Position of '(' (or of dekhao when called without parentheses)
     },
 
     TemplateLiteral {
         parts: Vec<Expression>,
     },
+
+    ArrayLiteral {
+        elements: Vec<Expression>,
+    },
+
+    HashLiteral {
+        pairs: Vec<(Expression, Expression)>, // Key/value pairs in source order
+    },
+
+    Index {
+        left: Box<Expression>,
+        index: Box<Expression>,
+        span: Span, // Position of '[', or of '.' in obj.field
+    },
+
+    // shomoy niropekkho fn(...) { ... }: calling it gives a future instead of a value
+    AsyncFunction(Box<Expression>),
+
+    // opekkha koro expr: the value a future settled with
+    Await(Box<Expression>),
+}
+
+impl Expression {
+    // Source position of expressions that can fail at runtime
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Expression::Identifier(_, span)
+            | Expression::Prefix { span, .. }
+            | Expression::Infix { span, .. }
+            | Expression::Call { span, .. }
+            | Expression::Index { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
 }
 
 // === EXPRESSION DISPLAY IMPLEMENTATION ===
@@ -187,24 +313,30 @@ pub enum Expression {
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Expression::Identifier(s) =>
+            Expression::Identifier(s, _) =>
                 write!(f, "{}", s),
 
             Expression::IntegerLiteral(i) =>
                 write!(f, "{}", i),
 
+            Expression::FloatLiteral(x) =>
+                write!(f, "{:?}", x),
+
             Expression::StringLiteral(s) =>
                 write!(f, "\"{}\"", s),
 
+            Expression::CharLiteral(c) =>
+                write!(f, "'{}'", c.escape_debug()),
+
             Expression::Boolean(b) => {
                 let s = if *b { "Ha" } else { "Na" };
                 write!(f, "{}", s)
             }
 
-            Expression::Prefix { operator, right } =>
+            Expression::Prefix { operator, right, .. } =>
                 write!(f, "({}{})", operator, right),
 
-            Expression::Infix { left, operator, right } =>
+            Expression::Infix { left, operator, right, .. } =>
                 write!(f, "({} {} {})", left, operator, right),
 
             Expression::If { condition, consequence, alternative } => {
@@ -230,8 +362,25 @@ impl fmt::Display for Expression {
                 write!(f, "{}", s)
             }
 
-            Expression::FunctionLiteral { parameters, body } => {
-                let params: Vec<String> = parameters.iter().map(|p| format!("{}", p)).collect();
+            Expression::FunctionLiteral { parameters, parameter_types, parameter_defaults, variadic, body } => {
+                let mut params: Vec<String> = parameters
+                    .iter()
+                    .zip(parameter_types)
+                    .zip(parameter_defaults)
+                    .map(|((p, ty), default)| {
+                        let mut param = match ty {
+                            Some(ty) => format!("{}: {}", p, ty),
+                            None => format!("{}", p),
+                        };
+                        if let Some(default) = default {
+                            param.push_str(&format!(" = {}", default));
+                        }
+                        param
+                    })
+                    .collect();
+                if let (true, Some(last)) = (*variadic, params.last_mut()) {
+                    last.insert_str(0, "...");
+                }
                 let mut s = format!("fn({}) {{ ", params.join(", "));
                 for stmt in body {
                     s.push_str(&format!("{}", stmt));
@@ -240,7 +389,7 @@ impl fmt::Display for Expression {
                 write!(f, "{}", s)
             }
 
-            Expression::Call { function, arguments } => {
+            Expression::Call { function, arguments, .. } => {
                 let args: Vec<String> = arguments.iter().map(|a| format!("{}", a)).collect();
                 write!(f, "{}({})", function, args.join(", "))
             }
@@ -250,6 +399,25 @@ impl fmt::Display for Expression {
                 // backticks style
                 write!(f, "`{}`", rendered.join(""))
             }
+
+            Expression::ArrayLiteral { elements } => {
+                let rendered: Vec<String> = elements.iter().map(|e| format!("{}", e)).collect();
+                write!(f, "[{}]", rendered.join(", "))
+            }
+
+            Expression::HashLiteral { pairs } => {
+                let rendered: Vec<String> = pairs.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                write!(f, "{{{}}}", rendered.join(", "))
+            }
+
+            Expression::Index { left, index, .. } =>
+                write!(f, "({}[{}])", left, index),
+
+            Expression::AsyncFunction(function) =>
+                write!(f, "shomoy niropekkho {}", function),
+
+            Expression::Await(value) =>
+                write!(f, "(opekkha koro {})", value),
            
         }
     }
@@ -1 +1,435 @@
-// compiler/src/codegen.rs
\ No newline at end of file
+// compiler/src/codegen.rs
+
+// === IMPORTS ===
+use crate::ast::{Expression, Program, Statement};
+use crate::evaluator::logical_operator;
+use crate::parser::Precedence;
+use crate::token::TokenType;
+
+// === CODE GENERATOR ===
+// Turns the AST back into formatted B+ source: one statement per line,
+// blocks indented, and only the parentheses needed to keep nested infix
+// expressions unambiguous. Unlike the AST's Display impl (which prints
+// everything on one line for debugging), the output is meant to be read
+// and to parse back into the same program.
+pub struct CodeGenerator {
+    indent: usize,  // Current nesting depth
+    output: String, // Source generated so far
+}
+
+// Spaces per indentation level
+const INDENT: &str = "    ";
+
+impl Default for CodeGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator {
+    // === FUNCTION: new ===
+    pub fn new() -> Self {
+        CodeGenerator { indent: 0, output: String::new() }
+    }
+
+    // === FUNCTION: generate ===
+    // Generates source for a whole program
+    pub fn generate(&mut self, program: &Program) -> String {
+        self.indent = 0;
+        self.output.clear();
+        for statement in program {
+            self.statement(statement);
+        }
+        std::mem::take(&mut self.output)
+    }
+
+    // === FUNCTION: generate_expression ===
+    // Generates source for a single expression
+    pub fn generate_expression(&mut self, expression: &Expression) -> String {
+        self.expression(expression)
+    }
+
+    // === STATEMENTS ===
+
+    fn line(&mut self, text: &str) {
+        for _ in 0..self.indent {
+            self.output.push_str(INDENT);
+        }
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    // Writes `header {`, the indented body, and a closing line `footer`
+    fn block(&mut self, header: &str, body: &[Statement], footer: &str) {
+        self.line(&format!("{} {{", header));
+        self.indent += 1;
+        for statement in body {
+            self.statement(statement);
+        }
+        self.indent -= 1;
+        self.line(footer);
+    }
+
+    // A labeled loop: the label goes in front of the loop's first line
+    fn labeled(&mut self, label: &str, body: &Statement) {
+        let start = self.output.len() + INDENT.len() * self.indent;
+        self.statement(body);
+        self.output.insert_str(start, &format!("{}: ", label));
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Let { name, type_annotation, value, .. } => {
+                let value = self.expression(value);
+                match type_annotation {
+                    Some(ty) => self.line(&format!("dhoro {}: {} = {};", name, ty, value)),
+                    None => self.line(&format!("dhoro {} = {};", name, value)),
+                }
+            }
+            Statement::Assign { name, value } => {
+                let value = self.expression(value);
+                self.line(&format!("{} = {};", name, value));
+            }
+            Statement::Return { return_value } => {
+                let value = self.expression(return_value);
+                self.line(&format!("ferot {};", value));
+            }
+            Statement::Expression(expression) | Statement::ExpressionStatement { expression } => {
+                match expression {
+                    // Conditionals are written as blocks rather than one-liners
+                    Expression::If { .. } => self.if_statement(expression, "jodi"),
+                    _ => {
+                        let text = self.expression(expression);
+                        self.line(&format!("{};", text));
+                    }
+                }
+            }
+            Statement::CommentSingleLine { content } => self.line(&format!("//{}", content)),
+            Statement::CommentMultiLine { content } => self.line(&format!("/*{}*/", content)),
+            Statement::While { condition, body } => {
+                let condition = self.expression(condition);
+                self.block(&format!("jotokhon ({})", condition), body, "}");
+            }
+            Statement::DoWhile { body, condition } => {
+                let condition = self.expression(condition);
+                self.block("age koro", body, &format!("}} jotokhon ({});", condition));
+            }
+            Statement::ForEach { var, iterable, body } => {
+                let iterable = self.expression(iterable);
+                self.block(&format!("protitar jonno ({} jekhane {})", var, iterable), body, "}");
+            }
+            // The parser never produces C-style loops, so one built by hand is printed as Display does
+            Statement::Break(_) | Statement::Continue(_) | Statement::For { .. } => self.line(&statement.to_string()),
+            Statement::Labeled { label, body } => self.labeled(label, body),
+            Statement::Import { path, alias } => {
+                self.line(&format!("import koro \"{}\" ei hisebe {};", escape(path, '"'), alias))
+            }
+            Statement::TypeDefinition { name, fields } => {
+                self.line(&format!("type banao {} {{ {} }}", name, fields.join(", ")))
+            }
+        }
+    }
+
+    // Writes an if/else-if/else chain starting with `keyword`
+    fn if_statement(&mut self, expression: &Expression, keyword: &str) {
+        let Expression::If { condition, consequence, alternative } = expression else {
+            return;
+        };
+        let condition = self.expression(condition);
+        self.line(&format!("{} ({}) {{", keyword, condition));
+        self.indent += 1;
+        for statement in consequence {
+            self.statement(statement);
+        }
+        self.indent -= 1;
+
+        match alternative.as_deref() {
+            None => self.line("}"),
+            Some(alt @ Expression::If { .. }) => self.if_statement(alt, "} nahoy jodi"),
+            Some(alt) => {
+                self.line("} nahoy {");
+                self.indent += 1;
+                let text = self.expression(alt);
+                self.line(&format!("{};", text));
+                self.indent -= 1;
+                self.line("}");
+            }
+        }
+    }
+
+    // === EXPRESSIONS ===
+
+    fn expression(&mut self, expression: &Expression) -> String {
+        match expression {
+            Expression::Identifier(name, _) => name.to_string(),
+            Expression::IntegerLiteral(value) => value.to_string(),
+            Expression::FloatLiteral(value) => format!("{:?}", value),
+            Expression::StringLiteral(value) => format!("\"{}\"", escape(value, '"')),
+            Expression::CharLiteral(value) => format!("'{}'", escape(&value.to_string(), '\'')),
+            Expression::Boolean(true) => "Ha".to_string(),
+            Expression::Boolean(false) => "Na".to_string(),
+
+            Expression::Prefix { operator, right, .. } => {
+                // A nested prefix is parenthesized too, so -(-x) doesn't come out as --x
+                let right = self.operand(right, Precedence::POWER);
+                format!("{}{}", operator, right)
+            }
+
+            Expression::Infix { left, operator, right, .. } => {
+                // Operators group to the left, except ** which groups to the right
+                // and takes a prefix expression on its right: 2 ** -x
+                let precedence = infix_precedence(operator);
+                let (left, right) = if precedence == Precedence::POWER {
+                    (self.operand(left, Precedence::CALL), self.operand(right, Precedence::PREFIX))
+                } else {
+                    (self.operand(left, precedence), self.operand(right, tighter(precedence)))
+                };
+                format!("{} {} {}", left, operator, right)
+            }
+
+            Expression::If { .. } => {
+                // Nested inside another expression: generate the block form inline
+                let mut nested = CodeGenerator { indent: self.indent, output: String::new() };
+                nested.if_statement(expression, "jodi");
+                nested.output.trim().to_string()
+            }
+
+            Expression::FunctionLiteral { parameters, parameter_types, parameter_defaults, variadic, body } => {
+                let mut params: Vec<String> = parameters
+                    .iter()
+                    .zip(parameter_types)
+                    .zip(parameter_defaults)
+                    .map(|((p, ty), default)| {
+                        let param = match ty {
+                            Some(ty) => format!("{}: {}", p, ty),
+                            None => p.to_string(),
+                        };
+                        match default {
+                            Some(default) => format!("{} = {}", param, self.expression(default)),
+                            None => param,
+                        }
+                    })
+                    .collect();
+                if let (true, Some(last)) = (*variadic, params.last_mut()) {
+                    last.insert_str(0, "...");
+                }
+                if body.is_empty() {
+                    return format!("fn({}) {{}}", params.join(", "));
+                }
+                let mut nested = CodeGenerator { indent: self.indent + 1, output: String::new() };
+                for statement in body {
+                    nested.statement(statement);
+                }
+                let closing = INDENT.repeat(self.indent);
+                format!("fn({}) {{\n{}{}}}", params.join(", "), nested.output, closing)
+            }
+
+            Expression::Call { function, arguments, .. } => {
+                // dekhao with a template literal keeps its template form
+                if let [Expression::TemplateLiteral { parts }] = arguments.as_slice() {
+                    return format!("{} {{{}}}", function, self.template(parts));
+                }
+                let function = self.operand(function, Precedence::CALL);
+                let args = self.list(arguments);
+                format!("{}({})", function, args)
+            }
+
+            Expression::TemplateLiteral { parts } => format!("{{{}}}", self.template(parts)),
+
+            Expression::ArrayLiteral { elements } => format!("[{}]", self.list(elements)),
+
+            Expression::HashLiteral { pairs } => {
+                let rendered: Vec<String> = pairs
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", self.expression(k), self.expression(v)))
+                    .collect();
+                format!("{{{}}}", rendered.join(", "))
+            }
+
+            Expression::Index { left, index, .. } => {
+                let left = self.operand(left, Precedence::CALL);
+                let index = self.expression(index);
+                format!("{}[{}]", left, index)
+            }
+
+            Expression::AsyncFunction(function) => format!("shomoy niropekkho {}", self.expression(function)),
+
+            Expression::Await(value) => format!("opekkha koro {}", self.operand(value, Precedence::POWER)),
+        }
+    }
+
+    // An operand in a position that only takes expressions binding at least as
+    // tightly as `min`, parenthesized when it binds more loosely
+    fn operand(&mut self, expression: &Expression, min: Precedence) -> String {
+        let text = self.expression(expression);
+        if binding(expression) < min {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    fn list(&mut self, expressions: &[Expression]) -> String {
+        let rendered: Vec<String> = expressions.iter().map(|e| self.expression(e)).collect();
+        rendered.join(", ")
+    }
+
+    // Template body: text verbatim, interpolations in parentheses
+    fn template(&mut self, parts: &[Expression]) -> String {
+        parts
+            .iter()
+            .map(|part| match part {
+                Expression::StringLiteral(text) => text.clone(),
+                other => format!("({})", self.expression(other)),
+            })
+            .collect()
+    }
+}
+
+// How tightly an expression holds together as an operand, in the parser's
+// precedences; names, literals, calls and indexing are never split
+fn binding(expression: &Expression) -> Precedence {
+    match expression {
+        Expression::Infix { operator, .. } => infix_precedence(operator),
+        Expression::Prefix { .. } | Expression::Await(_) => Precedence::PREFIX,
+        // Folded constants can be negative, and read back as prefix minus
+        Expression::IntegerLiteral(value) if *value < 0 => Precedence::PREFIX,
+        Expression::FloatLiteral(value) if value.is_sign_negative() => Precedence::PREFIX,
+        Expression::If { .. } | Expression::FunctionLiteral { .. } | Expression::AsyncFunction(_) => {
+            Precedence::LOWEST
+        }
+        _ => Precedence::INDEX,
+    }
+}
+
+// Precedence the parser gives a binary operator
+fn infix_precedence(operator: &str) -> Precedence {
+    match operator {
+        "==" | "!=" => Precedence::EQUALS,
+        "<" | ">" | "<=" | ">=" => Precedence::LESSGREATER,
+        "+" | "-" => Precedence::SUM,
+        "*" | "/" => Precedence::PRODUCT,
+        "**" => Precedence::POWER,
+        _ => match logical_operator(operator) {
+            Some(TokenType::Ebong) => Precedence::LOGICALAND,
+            Some(TokenType::Othoba) => Precedence::LOGICALOR,
+            _ => Precedence::LOWEST,
+        },
+    }
+}
+
+// The next level up, which the right operand of a left-grouping operator must reach
+fn tighter(precedence: Precedence) -> Precedence {
+    match precedence {
+        Precedence::LOWEST => Precedence::CONDITIONAL,
+        Precedence::CONDITIONAL => Precedence::LOGICALOR,
+        Precedence::LOGICALOR => Precedence::LOGICALAND,
+        Precedence::LOGICALAND => Precedence::EQUALS,
+        Precedence::EQUALS => Precedence::LESSGREATER,
+        Precedence::LESSGREATER => Precedence::SUM,
+        Precedence::SUM => Precedence::PRODUCT,
+        Precedence::PRODUCT => Precedence::PREFIX,
+        Precedence::PREFIX => Precedence::POWER,
+        Precedence::POWER => Precedence::CALL,
+        Precedence::CALL | Precedence::INDEX => Precedence::INDEX,
+    }
+}
+
+// Escapes backslashes, control characters and the surrounding quote
+fn escape(text: &str, quote: char) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c if c == quote => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn parse(input: &str) -> Program {
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+        program
+    }
+
+    fn generate(input: &str) -> String {
+        CodeGenerator::new().generate(&parse(input))
+    }
+
+    #[test]
+    fn test_expressions() {
+        assert_eq!(generate("1 + 2 * 3"), "1 + 2 * 3;\n");
+        assert_eq!(generate("(1 + 2) * -x"), "(1 + 2) * -x;\n");
+        assert_eq!(generate("f(a, [1, 2.5], {\"k\": 'c'})[0]"), "f(a, [1, 2.5], {\"k\": 'c'})[0];\n");
+        assert_eq!(generate(r#""bolo \"ha\"\n""#), "\"bolo \\\"ha\\\"\\n\";\n");
+    }
+
+    #[test]
+    fn test_minimal_parentheses() {
+        // Only where the parser would group differently without them
+        assert_eq!(generate("a - (b - c)"), "a - (b - c);\n");
+        assert_eq!(generate("(a - b) - c"), "a - b - c;\n");
+        assert_eq!(generate("(a + b) * c == d ebong e"), "(a + b) * c == d ebong e;\n");
+        assert_eq!(generate("a othoba (b ebong c)"), "a othoba b ebong c;\n");
+        assert_eq!(generate("(a othoba b) ebong c"), "(a othoba b) ebong c;\n");
+        assert_eq!(generate("2 ** 3 ** 2"), "2 ** 3 ** 2;\n");
+        assert_eq!(generate("(2 ** 3) ** 2"), "(2 ** 3) ** 2;\n");
+        assert_eq!(generate("(-x) ** 2 + -(x ** 2) + 2 ** -x"), "(-x) ** 2 + -x ** 2 + 2 ** -x;\n");
+        assert_eq!(generate("-(-x) + -(a + b)"), "-(-x) + -(a + b);\n");
+        assert_eq!(generate("(fn(x) { x })(1) + (a + b)[0]"), "(fn(x) {\n    x;\n})(1) + (a + b)[0];\n");
+        assert_eq!(generate("dekhao(x + 1)"), "dekhao(x + 1);\n");
+
+        // Negative constants, as the optimizer folds them, read back as prefix minus
+        let power = Expression::Infix {
+            left: Box::new(Expression::IntegerLiteral(-2)),
+            operator: "**".to_string(),
+            right: Box::new(Expression::IntegerLiteral(2)),
+            span: Default::default(),
+        };
+        assert_eq!(CodeGenerator::new().generate_expression(&power), "(-2) ** 2");
+    }
+
+    #[test]
+    fn test_statements_are_indented() {
+        let output = generate("dhoro x: purno_songkha = 1; jotokhon x < 3 { x = x + 1; dekhao(x); }");
+        assert_eq!(
+            output,
+            "dhoro x: purno_songkha = 1;\njotokhon (x < 3) {\n    x = x + 1;\n    dekhao(x);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_generated_source_parses_back() {
+        let input = r#"
+            dhoro jog = fn(a, b: purno_songkha) { ferot a + b; };
+            dhoro total = 0;
+            protitar jonno (n jekhane [1, 2, 3]) {
+                jodi (n == 2) { choluk; }
+                total = jog(total, n);
+            }
+            bahir: jotokhon (Ha) {
+                protitar jonno (n jekhane [1]) { thamo bahir; }
+            }
+            dekhao {Total holo (total)}
+            dekhao(-(1 - 2) * 3 ** -1 - (4 - 5), Na othoba (Ha ebong !Na) == Ha)
+        "#;
+        let program = parse(input);
+        let output = CodeGenerator::new().generate(&program);
+        assert_eq!(parse(&output), program);
+    }
+}
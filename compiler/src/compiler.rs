@@ -0,0 +1,486 @@
+// compiler/src/compiler.rs
+
+// === IMPORTS ===
+use crate::ast::{Expression, Program, Span, Statement};
+use crate::evaluator::logical_operator;
+use crate::object::Object;
+use crate::token::TokenType;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// === INSTRUCTIONS ===
+// One step of the stack machine in vm.rs. Every expression pushes exactly one
+// value, and so does every statement, so a block is worth the value of its last
+// statement the same way it is in the tree-walking evaluator.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Constant(Object),          // Push a literal or a compiled function
+    Pop,                       // Drop the top value
+    GetGlobal(usize),          // Push the global in a slot
+    DefineGlobal(usize, bool), // Pop a value into a global slot (mutable or not)
+    AssignGlobal(usize),       // Store the top value in a global slot, leaving it on the stack
+    GetLocal(usize),           // Same three for the current call's local slots
+    DefineLocal(usize, bool),
+    AssignLocal(usize),
+    Prefix(String),            // Apply a prefix operator to the top value
+    Infix(String),             // Apply an infix operator to the top two values
+    Jump(usize),               // Continue at an instruction index
+    JumpIfFalse(usize),        // Pop a condition and jump when it isn't truthy
+    Call(usize),               // Call the value below this many arguments
+    Return,                    // Leave the current call with the top value
+}
+
+// === COMPILED FUNCTION ===
+// A function body compiled to instructions; its parameters take the first local slots
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledFunction {
+    pub arity: usize,
+    pub locals: Vec<String>, // Names of the local slots, parameters first
+    pub instructions: Vec<Instruction>,
+}
+
+impl CompiledFunction {
+    // Parameter names, for printing the function
+    pub fn parameters(&self) -> &[String] {
+        &self.locals[..self.arity]
+    }
+}
+
+// === BYTECODE ===
+// A compiled program: its top-level code and the names of its global slots
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bytecode {
+    pub main: Rc<CompiledFunction>,
+    pub globals: Vec<String>,
+}
+
+// Instructions and local slots of the function being compiled. Each open block
+// maps the names it declares to slots; a function starts with one for its
+// parameters and body, while the top level starts with none, as its names are globals.
+#[derive(Default)]
+struct FunctionScope {
+    locals: Vec<String>,
+    blocks: Vec<HashMap<String, usize>>,
+    instructions: Vec<Instruction>,
+}
+
+impl FunctionScope {
+    // Slot of the innermost visible block variable with the name
+    fn local(&self, name: &str) -> Option<usize> {
+        self.blocks.iter().rev().find_map(|block| block.get(name).copied())
+    }
+}
+
+// === COMPILER ===
+// Compiles a program for the VM. Top-level names are globals, names bound inside
+// a function or a block are locals of that call (of the top level, for blocks there),
+// each block's in a slot of its own like the evaluator's block scopes. Only part of the language is supported so far:
+// literals, operators, variables, jodi/nahoy, jotokhon and functions that use their
+// own locals and globals. Anything else is a compile error rather than a wrong result.
+#[derive(Default)]
+pub struct Compiler {
+    globals: Vec<String>,
+    global_slots: HashMap<String, usize>,
+    scopes: Vec<FunctionScope>, // Top level first, innermost function last
+    span: Option<Span>,         // Position of the innermost node being compiled that has one, for errors
+}
+
+impl Compiler {
+    // === FUNCTION: new ===
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // === FUNCTION: compile ===
+    // Compiles a whole program, or explains which construct the VM can't run yet
+    pub fn compile(mut self, program: &Program) -> Result<Bytecode, String> {
+        // Top-level names are globals even in functions compiled before their
+        // declaration, since by the time such a function runs they usually exist
+        for statement in program {
+            if let Statement::Let { name: Expression::Identifier(name, _), .. }
+            | Statement::Assign { name: Expression::Identifier(name, _), .. } = statement
+            {
+                self.global_slot(name);
+            }
+        }
+
+        self.scopes.push(FunctionScope::default());
+        self.block(program)?;
+        self.emit(Instruction::Return);
+
+        let main = self.scopes.pop().unwrap_or_default();
+        Ok(Bytecode {
+            main: Rc::new(CompiledFunction { arity: 0, locals: main.locals, instructions: main.instructions }),
+            globals: self.globals,
+        })
+    }
+
+    // Statements separated by pops, leaving the last statement's value (Null when empty)
+    fn block(&mut self, statements: &[Statement]) -> Result<(), String> {
+        if statements.is_empty() {
+            self.emit(Instruction::Constant(Object::Null));
+        }
+        for (i, statement) in statements.iter().enumerate() {
+            if i > 0 {
+                self.emit(Instruction::Pop);
+            }
+            self.statement(statement)?;
+        }
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), String> {
+        let enclosing = self.span;
+        if let Some(span) = statement_span(statement) {
+            self.span = Some(span);
+        }
+        let compiled = self.compile_statement(statement);
+        self.span = enclosing;
+        compiled
+    }
+
+    fn compile_statement(&mut self, statement: &Statement) -> Result<(), String> {
+        match statement {
+            Statement::ExpressionStatement { expression } | Statement::Expression(expression) => {
+                self.expression(expression)?
+            }
+            Statement::Let { name: Expression::Identifier(name, _), value, mutable, .. } => {
+                self.expression(value)?;
+                self.define(name, *mutable);
+                self.emit(Instruction::Constant(Object::Null));
+            }
+            Statement::Assign { name: Expression::Identifier(name, _), value } => {
+                self.expression(value)?;
+                self.assign(name);
+            }
+            Statement::Return { return_value } => {
+                self.expression(return_value)?;
+                self.emit(Instruction::Return);
+            }
+            Statement::CommentSingleLine { .. } | Statement::CommentMultiLine { .. } => {
+                self.emit(Instruction::Constant(Object::Null));
+            }
+            Statement::While { condition, body } => {
+                let start = self.position();
+                self.expression(condition)?;
+                let exit = self.emit(Instruction::JumpIfFalse(0));
+                self.scoped_block(body)?;
+                self.emit(Instruction::Pop);
+                self.emit(Instruction::Jump(start));
+                self.patch_jump(exit);
+                self.emit(Instruction::Constant(Object::Null));
+            }
+            Statement::Let { .. } | Statement::Assign { .. } => return Err(self.unsupported("destructuring targets")),
+            Statement::DoWhile { .. } => return Err(self.unsupported("age koro loops")),
+            Statement::For { .. } | Statement::ForEach { .. } => return Err(self.unsupported("jonno loops")),
+            Statement::Break(_) | Statement::Continue(_) => return Err(self.unsupported("thamo and choluk")),
+            Statement::Labeled { .. } => return Err(self.unsupported("loop labels")),
+            Statement::Import { .. } => return Err(self.unsupported("import koro")),
+            Statement::TypeDefinition { .. } => return Err(self.unsupported("type banao")),
+        }
+        Ok(())
+    }
+
+    fn expression(&mut self, expression: &Expression) -> Result<(), String> {
+        let enclosing = self.span;
+        if let Some(span) = expression.span() {
+            self.span = Some(span);
+        }
+        let compiled = self.compile_expression(expression);
+        self.span = enclosing;
+        compiled
+    }
+
+    fn compile_expression(&mut self, expression: &Expression) -> Result<(), String> {
+        match expression {
+            Expression::IntegerLiteral(value) => self.constant(Object::Integer(*value)),
+            Expression::FloatLiteral(value) => self.constant(Object::Float(*value)),
+            Expression::StringLiteral(value) => self.constant(Object::String(value.clone())),
+            Expression::CharLiteral(value) => self.constant(Object::Char(*value)),
+            Expression::Boolean(value) => self.constant(Object::Boolean(*value)),
+            Expression::Identifier(name, _) => self.load(name)?,
+            Expression::Prefix { operator, right, .. } => {
+                self.expression(right)?;
+                self.emit(Instruction::Prefix(operator.clone()));
+            }
+            Expression::Infix { left, operator, right, .. } if logical_operator(operator).is_some() => {
+                // Short-circuits like the evaluator: the right side runs only when it decides
+                self.expression(left)?;
+                let left_false = self.emit(Instruction::JumpIfFalse(0));
+                if logical_operator(operator) == Some(TokenType::Ebong) {
+                    self.expression(right)?;
+                    let right_false = self.emit(Instruction::JumpIfFalse(0));
+                    self.boolean_result(&[left_false, right_false], &[]);
+                } else {
+                    let left_true = self.emit(Instruction::Jump(0));
+                    self.patch_jump(left_false);
+                    self.expression(right)?;
+                    let right_false = self.emit(Instruction::JumpIfFalse(0));
+                    self.boolean_result(&[right_false], &[left_true]);
+                }
+            }
+            Expression::Infix { left, operator, right, .. } => {
+                self.expression(left)?;
+                self.expression(right)?;
+                self.emit(Instruction::Infix(operator.clone()));
+            }
+            Expression::If { condition, consequence, alternative } => {
+                self.expression(condition)?;
+                let otherwise = self.emit(Instruction::JumpIfFalse(0));
+                self.scoped_block(consequence)?;
+                let end = self.emit(Instruction::Jump(0));
+                self.patch_jump(otherwise);
+                match alternative {
+                    Some(alternative) => self.expression(alternative)?,
+                    None => self.constant(Object::Null),
+                }
+                self.patch_jump(end);
+            }
+            Expression::Call { function, arguments, .. } => {
+                self.expression(function)?;
+                for argument in arguments {
+                    self.expression(argument)?;
+                }
+                self.emit(Instruction::Call(arguments.len()));
+            }
+            Expression::FunctionLiteral { parameters, parameter_defaults, variadic, body, .. } => {
+                if *variadic || parameter_defaults.iter().any(Option::is_some) {
+                    return Err(self.unsupported("default and variadic parameters"));
+                }
+                let mut locals = Vec::new();
+                let mut names = HashMap::new();
+                for parameter in parameters {
+                    match parameter {
+                        Expression::Identifier(name, _) => {
+                            names.insert(name.to_string(), locals.len());
+                            locals.push(name.to_string());
+                        }
+                        _ => return Err(self.unsupported("destructuring parameters")),
+                    }
+                }
+
+                self.scopes.push(FunctionScope { locals, blocks: vec![names], instructions: Vec::new() });
+                let compiled = self.block(body);
+                self.emit(Instruction::Return);
+                let scope = self.scopes.pop().unwrap_or_default();
+                compiled?;
+
+                let function = CompiledFunction {
+                    arity: parameters.len(),
+                    locals: scope.locals,
+                    instructions: scope.instructions,
+                };
+                self.constant(Object::CompiledFunction(Rc::new(function)));
+            }
+            Expression::TemplateLiteral { .. } => return Err(self.unsupported("templates")),
+            Expression::ArrayLiteral { .. } => return Err(self.unsupported("arrays")),
+            Expression::HashLiteral { .. } => return Err(self.unsupported("hashes")),
+            Expression::Index { .. } => return Err(self.unsupported("indexing")),
+            Expression::AsyncFunction(_) | Expression::Await(_) => return Err(self.unsupported("async functions")),
+        }
+        Ok(())
+    }
+
+    // Statements of an if or loop body, whose declarations end with the block
+    fn scoped_block(&mut self, statements: &[Statement]) -> Result<(), String> {
+        self.current_scope().blocks.push(HashMap::new());
+        let compiled = self.block(statements);
+        self.current_scope().blocks.pop();
+        compiled
+    }
+
+    // Reads a variable: a local of the current function, otherwise a global
+    fn load(&mut self, name: &str) -> Result<(), String> {
+        if let Some(slot) = self.current_local(name) {
+            self.emit(Instruction::GetLocal(slot));
+            return Ok(());
+        }
+        let mut enclosing = self.scopes.iter().take(self.scopes.len().saturating_sub(1));
+        if enclosing.any(|scope| scope.local(name).is_some()) {
+            return Err(self.unsupported("closures over local variables"));
+        }
+        let slot = self.global_slot(name);
+        self.emit(Instruction::GetGlobal(slot));
+        Ok(())
+    }
+
+    // Binds a new variable in the innermost block, or globally outside any block
+    // at the top level. Declaring a name again in the same block reuses its slot.
+    fn define(&mut self, name: &str, mutable: bool) {
+        if self.current_scope().blocks.is_empty() {
+            let slot = self.global_slot(name);
+            self.emit(Instruction::DefineGlobal(slot, mutable));
+            return;
+        }
+        let existing = self.current_scope().blocks.last().and_then(|block| block.get(name).copied());
+        let slot = existing.unwrap_or_else(|| self.new_local(name));
+        self.emit(Instruction::DefineLocal(slot, mutable));
+    }
+
+    // Assigns to the nearest variable with the name; like Environment::assign, a
+    // name that isn't bound anywhere becomes a new variable of the current scope
+    fn assign(&mut self, name: &str) {
+        let slot = match self.current_local(name) {
+            Some(slot) => Some(slot),
+            None if self.current_scope().blocks.is_empty() || self.global_slots.contains_key(name) => None,
+            None => Some(self.new_local(name)),
+        };
+        match slot {
+            Some(slot) => self.emit(Instruction::AssignLocal(slot)),
+            None => {
+                let slot = self.global_slot(name);
+                self.emit(Instruction::AssignGlobal(slot))
+            }
+        };
+    }
+
+    fn current_scope(&mut self) -> &mut FunctionScope {
+        self.scopes.last_mut().expect("no function scope")
+    }
+
+    fn current_local(&self, name: &str) -> Option<usize> {
+        self.scopes.last().and_then(|scope| scope.local(name))
+    }
+
+    // A fresh local slot, bound to the name in the innermost block
+    fn new_local(&mut self, name: &str) -> usize {
+        let scope = self.current_scope();
+        let slot = scope.locals.len();
+        scope.locals.push(name.to_string());
+        if let Some(block) = scope.blocks.last_mut() {
+            block.insert(name.to_string(), slot);
+        }
+        slot
+    }
+
+    fn global_slot(&mut self, name: &str) -> usize {
+        if let Some(&slot) = self.global_slots.get(name) {
+            return slot;
+        }
+        self.globals.push(name.to_string());
+        self.global_slots.insert(name.to_string(), self.globals.len() - 1);
+        self.globals.len() - 1
+    }
+
+    fn constant(&mut self, value: Object) {
+        self.emit(Instruction::Constant(value));
+    }
+
+    // Appends an instruction and returns its index
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        let instructions = &mut self.scopes.last_mut().expect("no function scope").instructions;
+        instructions.push(instruction);
+        instructions.len() - 1
+    }
+
+    // Index the next instruction will get
+    fn position(&self) -> usize {
+        self.scopes.last().map_or(0, |scope| scope.instructions.len())
+    }
+
+    // Pushes Ha when execution falls through or arrives from a jump in `to_true`,
+    // and Na when it arrives from a jump in `to_false`
+    fn boolean_result(&mut self, to_false: &[usize], to_true: &[usize]) {
+        for &jump in to_true {
+            self.patch_jump(jump);
+        }
+        self.constant(Object::Boolean(true));
+        let end = self.emit(Instruction::Jump(0));
+        for &jump in to_false {
+            self.patch_jump(jump);
+        }
+        self.constant(Object::Boolean(false));
+        self.patch_jump(end);
+    }
+
+    // Points an earlier jump at the next instruction
+    fn patch_jump(&mut self, jump: usize) {
+        let target = self.position();
+        match &mut self.scopes.last_mut().expect("no function scope").instructions[jump] {
+            Instruction::Jump(to) | Instruction::JumpIfFalse(to) => *to = target,
+            other => unreachable!("patching a non-jump instruction: {:?}", other),
+        }
+    }
+
+    // Error for a construct the VM can't run yet, with a `line:column:` prefix
+    // like parser errors when the position being compiled is known
+    fn unsupported(&self, construct: &str) -> String {
+        let message = format!("the VM doesn't support {} yet", construct);
+        match self.span {
+            Some(span) => format!("{}:{}: {}", span.line, span.column, message),
+            None => message,
+        }
+    }
+}
+
+// Position to report for a statement, which has none of its own: that of the
+// name it binds or of the expression it is built around
+fn statement_span(statement: &Statement) -> Option<Span> {
+    match statement {
+        Statement::Let { name, .. } | Statement::Assign { name, .. } => name.span(),
+        Statement::While { condition, .. } | Statement::DoWhile { condition, .. } => condition.span(),
+        Statement::ForEach { var, .. } => var.span(),
+        Statement::Return { return_value } => return_value.span(),
+        Statement::Labeled { body, .. } => statement_span(body),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn compile(source: &str) -> Result<Bytecode, String> {
+        let mut parser = Parser::new(Lexer::new(source.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+        Compiler::new().compile(&program)
+    }
+
+    #[test]
+    fn test_compiles_to_stack_instructions() {
+        let bytecode = compile("dhoro x = 2; x * 3").unwrap();
+        assert_eq!(bytecode.globals, vec!["x".to_string()]);
+        assert_eq!(
+            bytecode.main.instructions,
+            vec![
+                Instruction::Constant(Object::Integer(2)),
+                Instruction::DefineGlobal(0, true),
+                Instruction::Constant(Object::Null),
+                Instruction::Pop,
+                Instruction::GetGlobal(0),
+                Instruction::Constant(Object::Integer(3)),
+                Instruction::Infix("*".to_string()),
+                Instruction::Return,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_function_locals_and_globals() {
+        let bytecode = compile("dhoro n = 1; dhoro f = fn(a) { dhoro b = a + n; b };").unwrap();
+        let function = bytecode.main.instructions.iter().find_map(|instruction| match instruction {
+            Instruction::Constant(Object::CompiledFunction(function)) => Some(function.clone()),
+            _ => None,
+        });
+        let function = function.expect("no compiled function");
+        assert_eq!(function.parameters(), ["a".to_string()]);
+        assert_eq!(function.locals, vec!["a".to_string(), "b".to_string()]);
+        assert!(function.instructions.contains(&Instruction::GetGlobal(0)));
+        assert!(function.instructions.contains(&Instruction::DefineLocal(1, true)));
+    }
+
+    #[test]
+    fn test_unsupported_constructs_are_errors() {
+        assert_eq!(compile("[1, 2]"), Err("the VM doesn't support arrays yet".to_string()));
+        assert_eq!(
+            compile("dhoro f = fn(a) {\n  fn() { a } };"),
+            Err("2:10: the VM doesn't support closures over local variables yet".to_string())
+        );
+        // Nodes without a position of their own are reported at the nearest one that has
+        assert_eq!(compile("dhoro xs = [1, 2];"), Err("1:7: the VM doesn't support arrays yet".to_string()));
+        assert_eq!(compile("jog(1, [2]);"), Err("1:4: the VM doesn't support arrays yet".to_string()));
+    }
+}
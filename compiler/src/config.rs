@@ -0,0 +1,119 @@
+// compiler/src/config.rs
+
+use crate::error::ColorChoice;
+use std::path::{Path, PathBuf};
+
+/// Project config file, looked for in the working directory.
+pub const PROJECT_CONFIG: &str = "bplus.toml";
+
+/// User config file, looked for in the home directory.
+pub const USER_CONFIG: &str = ".bplusrc";
+
+/// Interpreter defaults read from a config file. Every setting is optional;
+/// a missing one keeps the built-in default, and command line flags override them all.
+///
+/// The file holds one `key = value` per line, with `#` comments:
+///
+/// ```text
+/// language = "english"
+/// prelude = true
+/// max_call_depth = 500
+/// color = "never"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Config {
+    pub language: Option<String>,      // Language pack to activate on startup
+    pub prelude: Option<bool>,         // Load the default stdlib modules, like --prelude
+    pub max_call_depth: Option<usize>, // Limit on nested function calls, like --max-call-depth
+    pub color: Option<ColorChoice>,    // When to colorize errors, like --color
+}
+
+impl Config {
+    /// Reads the first config file found: `bplus.toml` in the working directory,
+    /// then `~/.bplusrc`. Without either, every setting keeps its default.
+    pub fn load() -> Result<Config, String> {
+        match Self::find() {
+            Some(path) => Self::load_file(&path),
+            None => Ok(Config::default()),
+        }
+    }
+
+    /// Path of the config file `load` would read, if there is one.
+    pub fn find() -> Option<PathBuf> {
+        let home = std::env::var_os("HOME").map(|home| Path::new(&home).join(USER_CONFIG));
+        std::iter::once(PathBuf::from(PROJECT_CONFIG))
+            .chain(home)
+            .find(|path| path.is_file())
+    }
+
+    /// Reads and parses one config file; errors name the file.
+    pub fn load_file(path: &Path) -> Result<Config, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Parses config text, rejecting unknown keys and bad values with their line number.
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected key = value", index + 1));
+            };
+            let key = key.trim();
+            let value = value.trim().trim_matches('"');
+            let invalid = || format!("line {}: invalid value '{}' for {}", index + 1, value, key);
+
+            match key {
+                "language" => config.language = Some(value.to_string()),
+                "prelude" => config.prelude = Some(value.parse().map_err(|_| invalid())?),
+                "max_call_depth" => config.max_call_depth = Some(value.parse().map_err(|_| invalid())?),
+                "color" => config.color = Some(ColorChoice::from_name(value).ok_or_else(invalid)?),
+                _ => return Err(format!("line {}: unknown setting '{}'", index + 1, key)),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            "# defaults for this project\nlanguage = \"english\"\n\nprelude = true\nmax_call_depth = 500\ncolor = never\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                language: Some("english".to_string()),
+                prelude: Some(true),
+                max_call_depth: Some(500),
+                color: Some(ColorChoice::Never),
+            }
+        );
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_config_errors() {
+        assert_eq!(Config::parse("rong = lal").unwrap_err(), "line 1: unknown setting 'rong'");
+        assert_eq!(
+            Config::parse("prelude = true\nmax_call_depth = onek").unwrap_err(),
+            "line 2: invalid value 'onek' for max_call_depth"
+        );
+        assert!(Config::parse("color = rongin").is_err());
+        assert!(Config::parse("prelude").is_err());
+    }
+}
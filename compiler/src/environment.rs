@@ -2,13 +2,14 @@
 
 // === IMPORTS ===
 // Importing 'Object' type from object.rs file
-use crate::object::Object;
+use crate::token::Symbol;
+use crate::object::{builtin_assert, builtin_assert_eq, builtin_print, builtin_range, builtin_type_of, BuiltinFunction, Object};
 
 // Using standard HashMap for variable bindings
+use std::cell::RefCell;
 use std::collections::HashMap;
-
-// For handling user input and flushing output
-use std::io::{self, Write};
+use std::fmt;
+use std::rc::Rc;
 
 // === VARIABLE STRUCT ===
 #[derive(Clone, Debug, PartialEq)]
@@ -20,10 +21,40 @@ pub struct Variable {
 // === ENVIRONMENT STRUCTURE ===
 // The Environment holds variable and function bindings.
 // It can have an optional outer environment (for nested scopes).
-#[derive(Clone, Debug, PartialEq)]
+// It is a shared handle: closures and calls clone it in O(1) instead of copying
+// every binding, and all clones see the same variables.
+#[derive(Clone)]
 pub struct Environment {
-    store: HashMap<String, Variable>,           // Variable/function storage
-    outer: Option<Box<Environment>>,          // Optional parent environment (for closures, scopes)
+    scope: Rc<RefCell<Scope>>,
+}
+
+// The bindings an Environment handle points to
+struct Scope {
+    store: HashMap<Symbol, Variable>,           // Variable/function storage, keyed by shared names
+    outer: Option<Environment>,               // Optional parent environment (for closures, scopes)
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Two handles are equal when they share the same scope; comparing contents
+// could loop forever through a function stored in its own closure environment
+impl PartialEq for Environment {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.scope, &other.scope)
+    }
+}
+
+// Lists the names only, for the same reason
+impl fmt::Debug for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.names();
+        names.sort();
+        f.debug_struct("Environment").field("names", &names).finish()
+    }
 }
 
 // === ENVIRONMENT IMPLEMENTATION START ===
@@ -35,105 +66,376 @@ impl Environment {
         let mut store = HashMap::new();
 
         // === BUILTIN: dekhao ===
-        // A native print-like function that prints one argument
+        // A native print function that prints all arguments separated by spaces
         store.insert(
-            "dekhao".to_string(),
+            "dekhao".into(),
             Variable {
-                value: Object::BuiltinNative(|args| {
-                    if args.len() != 1 {
-                        return Object::Error(format!(
-                            "wrong number of arguments. got={}, want=1",
-                            args.len()
-                        ));
-                    }
-                    println!("{}", args[0]);
-                    Object::Null
-                }),
+                value: Object::BuiltinNative(builtin_print),
                 mutable: true,
             },
         );
 
         // === BUILTIN: input ===
-        // Asks the user for input with optional prompt message
+        // Asks the user for input with optional prompt message; the evaluator
+        // runs it so it reads from its input source
+        store.insert(
+            "input".into(),
+            Variable {
+                value: Object::BuiltinFunction(BuiltinFunction::Input),
+                mutable: true,
+            },
+        );
+
+        // === BUILTINS: input_int, input_float ===
+        // Read a line and parse it as a number, asking again until it parses;
+        // the evaluator runs them so they read from its input source
+        for (name, builtin) in [("input_int", BuiltinFunction::InputInt), ("input_float", BuiltinFunction::InputFloat)] {
+            store.insert(
+                name.into(),
+                Variable {
+                    value: Object::BuiltinFunction(builtin),
+                    mutable: true,
+                },
+            );
+        }
+
+        // === BUILTIN: __panic (tests only) ===
+        // Panics inside the evaluator, to check that a panic becomes an error
+        #[cfg(test)]
+        store.insert(
+            "__panic".into(),
+            Variable {
+                value: Object::BuiltinFunction(BuiltinFunction::Panic),
+                mutable: true,
+            },
+        );
+
+        // === BUILTINS: assert, assert_eq ===
+        // Let programs check their own expectations; a failure is a runtime error
+        store.insert(
+            "assert".into(),
+            Variable {
+                value: Object::BuiltinNative(builtin_assert),
+                mutable: true,
+            },
+        );
         store.insert(
-            "input".to_string(),
+            "assert_eq".into(),
             Variable {
-                value: Object::BuiltinNative(|args| {
-                    let prompt = if !args.is_empty() {
-                        format!("{}", args[0])
-                    } else {
-                        "".to_string()
-                    };
-
-                    print!("{}", prompt);
-                    io::stdout().flush().unwrap();
-
-                    let mut input_line = String::new();
-                    match io::stdin().read_line(&mut input_line) {
-                        Ok(_) => Object::String(input_line.trim().to_string()),
-                        Err(e) => Object::Error(format!("Input error: {}", e)),
-                    }
-                }),
+                value: Object::BuiltinNative(builtin_assert_eq),
+                mutable: true,
+            },
+        );
+
+        // === BUILTIN: range ===
+        // Half-open integer ranges for protitar jonno loops
+        store.insert(
+            "range".into(),
+            Variable {
+                value: Object::BuiltinNative(builtin_range),
+                mutable: true,
+            },
+        );
+
+        // === BUILTIN: dhoroner ===
+        // Type name of a value; typeof and dhoron ber koro call it too
+        store.insert(
+            "dhoroner".into(),
+            Variable {
+                value: Object::BuiltinNative(builtin_type_of),
                 mutable: true,
             },
         );
 
         // Return the final environment with all built-ins loaded
-        Environment { store, outer: None }
+        Environment::with_scope(store, None)
+    }
+
+    // === FUNCTION: with_scope ===
+    // Wraps bindings and an optional parent in a fresh shared handle
+    fn with_scope(store: HashMap<Symbol, Variable>, outer: Option<Environment>) -> Environment {
+        Environment { scope: Rc::new(RefCell::new(Scope { store, outer })) }
     }
 
     // === FUNCTION: new_enclosed ===
     // Creates a new inner (child) environment with a parent scope
     pub fn new_enclosed(outer: Environment) -> Environment {
-        Environment {
-            store: HashMap::new(),
-            outer: Some(Box::new(outer)),
-        }
+        Environment::with_scope(HashMap::new(), Some(outer))
     }
 
     // === FUNCTION: get ===
     // Retrieves a value by name from the current or outer environment
     pub fn get(&self, name: &str) -> Option<Object> {
-        match self.store.get(name) {
+        let scope = self.scope.borrow();
+        match scope.store.get(name) {
             Some(var) => Some(var.value.clone()),
-            None => self.outer.as_ref().and_then(|o| o.get(name)),
+            None => scope.outer.as_ref().and_then(|o| o.get(name)),
         }
     }
 
     // === FUNCTION: set ===
-    // Sets a variable in the current environment
-    pub fn set(&mut self, name: String, val: Object, mutable: bool) -> Object {
-        self.store.insert(name, Variable { value: val.clone(), mutable });
+    // Sets a variable in the current environment; an identifier's Symbol is stored
+    // without copying the name
+    pub fn set(&mut self, name: impl Into<Symbol>, val: Object, mutable: bool) -> Object {
+        self.scope.borrow_mut().store.insert(name.into(), Variable { value: val.clone(), mutable });
         val
     }
 
-    pub fn assign(&mut self, name: String, value: Object) -> Result<(), String> {
-        if let Some(var) = self.store.get_mut(&name) {
+    pub fn assign(&mut self, name: impl Into<Symbol>, value: Object) -> Result<(), String> {
+        let name = name.into();
+        let mut scope = self.scope.borrow_mut();
+        if let Some(var) = scope.store.get_mut(&name) {
             if var.mutable {
                 var.value = value;
                 Ok(())
             } else {
                 Err(format!("Cannot assign to immutable variable '{}'", name))
             }
+        } else if scope.outer.as_ref().is_some_and(|o| o.contains(&name)) {
+            // Variable lives in an enclosing scope, update it there
+            scope.outer.as_mut().unwrap().assign(name, value)
         } else {
             // Auto-declare on first assignment as immutable by default
-            self.store.insert(name, Variable { value, mutable: false });
+            scope.store.insert(name, Variable { value, mutable: false });
             Ok(())
         }
     }
 
+    // === FUNCTION: contains ===
+    // Checks whether a name is bound in this or any enclosing environment
+    pub fn contains(&self, name: &str) -> bool {
+        let scope = self.scope.borrow();
+        scope.store.contains_key(name) || scope.outer.as_ref().is_some_and(|o| o.contains(name))
+    }
+
+    // === FUNCTION: names ===
+    // Lists every name visible from this environment (inner scopes first)
+    pub fn names(&self) -> Vec<String> {
+        let scope = self.scope.borrow();
+        let mut names: Vec<String> = scope.store.keys().map(|name| name.to_string()).collect();
+        if let Some(outer) = &scope.outer {
+            names.extend(outer.names().into_iter().filter(|n| !scope.store.contains_key(n.as_str())));
+        }
+        names
+    }
+
+    // === FUNCTION: builtin_names ===
+    // Sorted names of the builtin functions visible from this environment
+    pub fn builtin_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .names()
+            .into_iter()
+            .filter(|name| matches!(self.get(name), Some(Object::BuiltinNative(_) | Object::BuiltinFunction(_))))
+            .collect();
+        names.sort();
+        names
+    }
+
+    // === FUNCTION: exports ===
+    // Bindings a program defined in this environment, sorted by name;
+    // the preloaded builtins are left out unless the program replaced them
+    pub fn exports(&self) -> Vec<(String, Object)> {
+        let builtins = Environment::new();
+        let builtins = builtins.scope.borrow();
+        let mut exports: Vec<(String, Object)> = self
+            .scope
+            .borrow()
+            .store
+            .iter()
+            .filter(|(name, var)| builtins.store.get(*name).is_none_or(|builtin| builtin.value != var.value))
+            .map(|(name, var)| (name.to_string(), var.value.clone()))
+            .collect();
+        exports.sort_by(|a, b| a.0.cmp(&b.0));
+        exports
+    }
+
+    // === FUNCTION: release ===
+    // Called when a call or block scope ends. A function bound in the scope it closes
+    // over (`dhoro shahajjo = fn(..) {..}` in a function body) keeps that scope alive
+    // through an Rc cycle. When every remaining reference to the scope, and to the
+    // inner scopes that lead back to it, comes from those scopes' own bindings, nothing
+    // else can reach them, so their bindings are dropped to break the cycles.
+    // A closure that escaped (returned, or stored outside) keeps them all as they are.
+    pub fn release(&self) {
+        if Rc::strong_count(&self.scope) == 1 {
+            return; // Nothing points back at the scope; dropping the handle frees it
+        }
+
+        // Scopes that can reach this one, and how many strong references each gets
+        // from inside that group; every scope in `group` also has one handle in it
+        let root = Rc::as_ptr(&self.scope);
+        let mut group = vec![self.clone()];
+        let mut internal: HashMap<*const RefCell<Scope>, usize> = HashMap::new();
+        let mut next = 0;
+        while next < group.len() {
+            let current = group[next].clone();
+            next += 1;
+            let mut found = Vec::new();
+            {
+                let scope = current.scope.borrow();
+                for variable in scope.store.values() {
+                    closure_environments(&variable.value, &mut found);
+                }
+                if Rc::as_ptr(&current.scope) != root {
+                    found.extend(scope.outer.clone());
+                }
+            }
+            for env in found {
+                if !env.descends_from(root) {
+                    continue;
+                }
+                let ptr = Rc::as_ptr(&env.scope);
+                *internal.entry(ptr).or_default() += 1;
+                if group.iter().all(|member| Rc::as_ptr(&member.scope) != ptr) {
+                    group.push(env);
+                }
+            }
+        }
+
+        let reachable_from_outside = group.iter().any(|env| {
+            let ptr = Rc::as_ptr(&env.scope);
+            let handles = if ptr == root { 2 } else { 1 }; // `self` and the one in `group`
+            Rc::strong_count(&env.scope) != internal.get(&ptr).copied().unwrap_or(0) + handles
+        });
+        if reachable_from_outside {
+            return;
+        }
+        for env in &group {
+            let store = std::mem::take(&mut env.scope.borrow_mut().store);
+            drop(store);
+        }
+    }
+
+    // Whether `ancestor` is this scope or one it is nested in
+    fn descends_from(&self, ancestor: *const RefCell<Scope>) -> bool {
+        let mut current = Some(self.clone());
+        while let Some(env) = current {
+            if Rc::as_ptr(&env.scope) == ancestor {
+                return true;
+            }
+            current = env.scope.borrow().outer.clone();
+        }
+        false
+    }
+
+    // === FUNCTION: into_outer ===
+    // Consumes an enclosed environment and hands back its parent
+    pub fn into_outer(self) -> Option<Environment> {
+        self.scope.borrow().outer.clone()
+    }
+
+
+    // === FUNCTION: set_script_args ===
+    // Makes the command-line arguments after the script name available through args()
+    pub fn set_script_args(&mut self, args: Vec<String>) {
+        crate::stdlib::system::set_script_args(args);
+        self.add_builtin("args".to_string(), Object::BuiltinNative(crate::stdlib::system::script_args));
+    }
 
     // === FUNCTION: has_builtin ===
     // Checks whether a builtin or variable exists in the current environment
     pub fn has_builtin(&self, name: &str) -> bool {
-        self.store.contains_key(name)
+        self.scope.borrow().store.contains_key(name)
     }
 
     // === FUNCTION: add_builtin ===
     // Manually adds a new builtin function to the environment
-    pub fn add_builtin(&mut self, name: String, func: Object) {
-        self.store.insert(name, Variable { value: func, mutable: true });
+    pub fn add_builtin(&mut self, name: impl Into<Symbol>, func: Object) {
+        self.scope.borrow_mut().store.insert(name.into(), Variable { value: func, mutable: true });
     }
 }
 // === ENVIRONMENT IMPLEMENTATION END ===
 
+// Collects the closure environments a value holds, looking inside containers
+fn closure_environments(value: &Object, found: &mut Vec<Environment>) {
+    match value {
+        Object::Function { env, .. } => found.push(env.clone()),
+        Object::AsyncFunction(inner) | Object::Future(inner) | Object::ReturnValue(inner) => {
+            closure_environments(inner, found)
+        }
+        Object::Array(elements) => elements.iter().for_each(|element| closure_environments(element, found)),
+        Object::Hash(pairs) | Object::Record { fields: pairs, .. } => {
+            for (key, value) in pairs {
+                closure_environments(key, found);
+                closure_environments(value, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enclosed_scopes_share_the_outer_bindings() {
+        let mut outer = Environment::new();
+        outer.set("x".to_string(), Object::Integer(1), true);
+
+        // Enclosing and cloning hand out the same scope instead of a copy of it
+        let mut inner = Environment::new_enclosed(outer.clone());
+        assert_eq!(inner.clone(), inner);
+        assert_eq!(inner.clone().into_outer(), Some(outer.clone()));
+        assert_ne!(Environment::new(), Environment::new());
+
+        // Assignments through the inner scope land in the outer one
+        inner.assign("x".to_string(), Object::Integer(2)).unwrap();
+        inner.set("y".to_string(), Object::Integer(3), true);
+        assert_eq!(outer.get("x"), Some(Object::Integer(2)));
+        assert_eq!(outer.get("y"), None);
+        assert_eq!(inner.get("y"), Some(Object::Integer(3)));
+    }
+
+    // Runs a program in `env` and returns its result
+    fn run_in(env: &mut Environment, source: &str) -> Object {
+        let program = crate::parser::Parser::new(crate::lexer::Lexer::new(source.to_string())).parse_program();
+        crate::evaluator::Evaluator::new().eval(program, env)
+    }
+
+    #[test]
+    fn test_scopes_with_their_own_functions_are_freed() {
+        // Every call and block scope still alive holds a handle to the global scope,
+        // so the count stays the same however often the program runs
+        let mut env = Environment::new();
+        let program = "
+            dhoro kori = fn(n) {
+                dhoro shahajjo = fn(x) { x * 2 };
+                jodi (n > 0) { dhoro arekta = fn() { shahajjo(n) }; arekta() } nahoy { shahajjo(n) }
+            };
+            dhoro i = 0;
+            jotokhon (i < 20) { kori(i); i = i + 1; }
+            protitar jonno (j jekhane [1, 2, 3]) { dhoro f = fn() { j }; f(); }
+            i
+        ";
+        assert_eq!(run_in(&mut env, program), Object::Integer(20));
+        let handles = Rc::strong_count(&env.scope);
+        run_in(&mut env, program);
+        run_in(&mut env, program);
+        assert_eq!(Rc::strong_count(&env.scope), handles);
+    }
+
+    #[test]
+    fn test_escaped_closures_keep_their_scope() {
+        let mut env = Environment::new();
+        let program = "
+            dhoro gona = fn() {
+                dhoro c = 0;
+                dhoro barao = fn() { c = c + 1; c };
+                barao
+            };
+            dhoro g = gona();
+            g(); g();
+            dhoro list = fn() { dhoro f = fn() { 7 }; [f] };
+            list()[0]() + g()
+        ";
+        assert_eq!(run_in(&mut env, program), Object::Integer(10));
+
+        // A released scope still referenced from outside keeps its bindings
+        let scope = Environment::new_enclosed(env.clone());
+        let mut inner = scope.clone();
+        inner.set("x".to_string(), Object::Integer(1), true);
+        scope.release();
+        assert_eq!(inner.get("x"), Some(Object::Integer(1)));
+    }
+}
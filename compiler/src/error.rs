@@ -2,6 +2,45 @@
 
 use std::fmt;
 use std::collections::HashMap;
+use std::io::IsTerminal;
+
+// ANSI escape sequences for colorized error output
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// When to colorize output, chosen with `--color=auto|always|never`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color terminals only, unless the `NO_COLOR` environment variable is set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Parses a `--color` value.
+    pub fn from_name(name: &str) -> Option<ColorChoice> {
+        match name {
+            "auto" => Some(ColorChoice::Auto),
+            "always" => Some(ColorChoice::Always),
+            "never" => Some(ColorChoice::Never),
+            _ => None,
+        }
+    }
+
+    /// Whether output to a stream should be colorized under this choice.
+    /// Following https://no-color.org, a non-empty `NO_COLOR` turns off `Auto` color,
+    /// while an explicit `--color=always` still wins.
+    pub fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => is_terminal && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+        }
+    }
+}
 
 /// Represents the position (line and column) of an error in the source code.
 /// Optionally includes the file name.
@@ -58,6 +97,34 @@ pub enum ErrorType {
     InternalError(String),
 }
 
+impl ErrorType {
+    /// Stable snake_case name of the error kind (same as its message template key).
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorType::UnexpectedCharacter(_) => "unexpected_character",
+            ErrorType::UnterminatedString => "unterminated_string",
+            ErrorType::UnterminatedComment => "unterminated_comment",
+            ErrorType::InvalidNumber(_) => "invalid_number",
+            ErrorType::UnexpectedToken(_, _) => "unexpected_token",
+            ErrorType::MissingToken(_) => "missing_token",
+            ErrorType::InvalidExpression(_) => "invalid_expression",
+            ErrorType::InvalidStatement(_) => "invalid_statement",
+            ErrorType::TypeMismatch(_, _) => "type_mismatch",
+            ErrorType::UndefinedVariable(_) => "undefined_variable",
+            ErrorType::UndefinedFunction(_) => "undefined_function",
+            ErrorType::WrongArgumentCount(_, _) => "wrong_argument_count",
+            ErrorType::DivisionByZero => "division_by_zero",
+            ErrorType::IndexOutOfBounds(_, _) => "index_out_of_bounds",
+            ErrorType::FileNotFound(_) => "file_not_found",
+            ErrorType::PermissionDenied(_) => "permission_denied",
+            ErrorType::NetworkError(_) => "network_error",
+            ErrorType::OutOfMemory => "out_of_memory",
+            ErrorType::StackOverflow => "stack_overflow",
+            ErrorType::InternalError(_) => "internal_error",
+        }
+    }
+}
+
 /// Struct holding complete error information including type, position, and optional custom message.
 #[derive(Debug, Clone)]
 pub struct BPlusError {
@@ -85,6 +152,30 @@ impl BPlusError {
         }
     }
 
+    /// Create an error at `line:column`, naming the file when there is one.
+    pub fn at(error_type: ErrorType, line: usize, column: usize, file: Option<&str>) -> Self {
+        let position = match file {
+            Some(file) => ErrorPosition::with_file(line, column, file.to_string()),
+            None => ErrorPosition::new(line, column),
+        };
+        BPlusError::with_position(error_type, position)
+    }
+
+    /// Wrap a parser error message as an invalid statement, moving its
+    /// `line:column:` prefix into the error's position.
+    pub fn syntax(message: &str, file: Option<&str>) -> Self {
+        match crate::parser::split_error_position(message) {
+            (Some((line, column)), rest) => {
+                let position = match file {
+                    Some(file) => ErrorPosition::with_file(line, column, file.to_string()),
+                    None => ErrorPosition::new(line, column),
+                };
+                BPlusError::with_position(ErrorType::InvalidStatement(rest.to_string()), position)
+            }
+            (None, _) => BPlusError::new(ErrorType::InvalidStatement(message.to_string())),
+        }
+    }
+
     /// Create an error with custom message.
     pub fn with_message(error_type: ErrorType, message: String) -> Self {
         BPlusError {
@@ -219,16 +310,19 @@ impl ErrorMessages {
     }
 
     /// Creates an ErrorMessages instance from a given language pack.
+    /// Errors the pack has no template for keep their Banglish message.
     pub fn from_language_pack(language_pack: &LanguagePack) -> Self {
+        let mut templates = ErrorMessages::new_default_banglish().templates;
+        templates.extend(language_pack.error_templates.clone());
         ErrorMessages {
-            templates: language_pack.error_templates.clone(),
+            templates,
             language: language_pack.language.clone(),
         }
     }
 }
 
 /// Represents a language pack that overrides default keywords and error messages.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LanguagePack {
     pub language: String,
     pub version: String,
@@ -249,6 +343,14 @@ pub struct ErrorManager {
     error_messages: ErrorMessages,
     show_position: bool,
     using_language_pack: bool,
+    json_output: bool, // Print errors as JSON objects (--json-errors)
+    color: bool,       // Colorize errors with ANSI escapes
+}
+
+impl Default for ErrorManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ErrorManager {
@@ -258,6 +360,8 @@ impl ErrorManager {
             error_messages: ErrorMessages::new_default_banglish(),
             show_position: true,
             using_language_pack: false,
+            json_output: false,
+            color: ColorChoice::Auto.enabled(std::io::stderr().is_terminal()),
         }
     }
 
@@ -267,32 +371,80 @@ impl ErrorManager {
             error_messages: ErrorMessages::from_language_pack(language_pack),
             show_position: true,
             using_language_pack: true,
+            json_output: false,
+            color: ColorChoice::Auto.enabled(std::io::stderr().is_terminal()),
         }
     }
 
-    /// Formats a `BPlusError` into a user-friendly string.
-    pub fn format_error(&self, error: &BPlusError) -> String {
-        let message = if let Some(ref custom_msg) = error.message {
+    /// Returns the error's message text (custom message or localized template), without position.
+    fn message_text(&self, error: &BPlusError) -> String {
+        if let Some(ref custom_msg) = error.message {
             custom_msg.clone()
         } else {
             self.error_messages.get_message(&error.error_type)
-        };
+        }
+    }
 
-        if self.show_position && error.position.is_some() {
-            let pos = error.position.as_ref().unwrap();
-            if let Some(ref file) = pos.file {
-                format!("{}:{}:{}: {}", file, pos.line, pos.column, message)
-            } else {
-                format!("{}:{}: {}", pos.line, pos.column, message)
-            }
+    /// Formats a `BPlusError` into a user-friendly string.
+    /// With color on, the message is red and the position prefix dimmed.
+    pub fn format_error(&self, error: &BPlusError) -> String {
+        let mut message = self.message_text(error);
+        if self.color {
+            message = format!("{}{}{}", ANSI_RED, message, ANSI_RESET);
+        }
+
+        let Some(pos) = error.position.as_ref().filter(|_| self.show_position) else {
+            return message;
+        };
+        let prefix = if let Some(ref file) = pos.file {
+            format!("{}:{}:{}:", file, pos.line, pos.column)
         } else {
-            message
+            format!("{}:{}:", pos.line, pos.column)
+        };
+        if self.color {
+            format!("{}{}{} {}", ANSI_DIM, prefix, ANSI_RESET, message)
+        } else {
+            format!("{} {}", prefix, message)
         }
     }
 
+    /// Turns colorized output on or off (by default, on when stderr is a terminal and `NO_COLOR` is unset).
+    pub fn set_color(&mut self, enabled: bool) {
+        self.color = enabled;
+    }
+
+    /// Formats a `BPlusError` as a single-line JSON object for editors and other tools:
+    /// `{"type": ..., "message": ..., "line": ..., "column": ..., "file": ...}`.
+    /// Missing position fields are `null`.
+    pub fn format_error_json(&self, error: &BPlusError) -> String {
+        let position = error.position.as_ref();
+        let number = |value: Option<usize>| value.map_or("null".to_string(), |n| n.to_string());
+        let file = position
+            .and_then(|pos| pos.file.as_deref())
+            .map_or("null".to_string(), json_string);
+
+        format!(
+            "{{\"type\":{},\"message\":{},\"line\":{},\"column\":{},\"file\":{}}}",
+            json_string(error.error_type.code()),
+            json_string(&self.message_text(error)),
+            number(position.map(|pos| pos.line)),
+            number(position.map(|pos| pos.column)),
+            file
+        )
+    }
+
+    /// Switches `print_error` between plain text and JSON output.
+    pub fn set_json_output(&mut self, enabled: bool) {
+        self.json_output = enabled;
+    }
+
     /// Prints a formatted error to standard error.
     pub fn print_error(&self, error: &BPlusError) {
-        eprintln!("{}", self.format_error(error));
+        if self.json_output {
+            eprintln!("{}", self.format_error_json(error));
+        } else {
+            eprintln!("{}", self.format_error(error));
+        }
     }
 
     /// Switches to a new language pack.
@@ -318,6 +470,106 @@ impl ErrorManager {
     }
 }
 
+/// Quotes and escapes text as a JSON string literal.
+pub(crate) fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 /// Type alias for results returned by the B+ compiler.
 /// This encapsulates both successful results and errors.
 pub type BPlusResult<T> = Result<T, BPlusError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_error_json() {
+        let manager = ErrorManager::new();
+        let error = BPlusError::with_position(
+            ErrorType::DivisionByZero,
+            ErrorPosition::with_file(3, 7, "main.bp".to_string()),
+        );
+        assert_eq!(
+            manager.format_error_json(&error),
+            r#"{"type":"division_by_zero","message":"Shunno diye bhag kora jay na","line":3,"column":7,"file":"main.bp"}"#
+        );
+
+        let error = BPlusError::new(ErrorType::InternalError("bad \"quote\"\n".to_string()));
+        assert_eq!(
+            manager.format_error_json(&error),
+            r#"{"type":"internal_error","message":"Antoronio truti: bad \"quote\"\n","line":null,"column":null,"file":null}"#
+        );
+    }
+
+    #[test]
+    fn test_language_pack_falls_back_to_banglish() {
+        let mut error_templates = HashMap::new();
+        error_templates.insert("division_by_zero".to_string(), "Cannot divide by zero".to_string());
+        let pack = LanguagePack {
+            language: "Partial".to_string(),
+            version: "1.0".to_string(),
+            author: "test".to_string(),
+            keyword_mappings: HashMap::new(),
+            error_templates,
+        };
+        let messages = ErrorMessages::from_language_pack(&pack);
+        assert_eq!(messages.get_message(&ErrorType::DivisionByZero), "Cannot divide by zero");
+        assert_eq!(
+            messages.get_message(&ErrorType::InternalError("x".to_string())),
+            "Antoronio truti: x"
+        );
+    }
+
+    #[test]
+    fn test_colorized_errors() {
+        let mut manager = ErrorManager::new();
+        let positioned = BPlusError::with_position(
+            ErrorType::DivisionByZero,
+            ErrorPosition::with_file(3, 7, "main.bp".to_string()),
+        );
+        let plain = BPlusError::new(ErrorType::UndefinedVariable("x".to_string()));
+
+        manager.set_color(false);
+        assert_eq!(manager.format_error(&positioned), "main.bp:3:7: Shunno diye bhag kora jay na");
+        assert_eq!(manager.format_error(&plain), "Ojana variable 'x' - prothome ghoshona korun");
+
+        manager.set_color(true);
+        assert_eq!(
+            manager.format_error(&positioned),
+            "\x1b[2mmain.bp:3:7:\x1b[0m \x1b[31mShunno diye bhag kora jay na\x1b[0m"
+        );
+        assert_eq!(
+            manager.format_error(&plain),
+            "\x1b[31mOjana variable 'x' - prothome ghoshona korun\x1b[0m"
+        );
+        // JSON output is never colorized
+        assert!(!manager.format_error_json(&positioned).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_color_choice() {
+        assert_eq!(ColorChoice::from_name("always"), Some(ColorChoice::Always));
+        assert_eq!(ColorChoice::from_name("never"), Some(ColorChoice::Never));
+        assert_eq!(ColorChoice::from_name("auto"), Some(ColorChoice::Auto));
+        assert_eq!(ColorChoice::from_name("rongin"), None);
+
+        assert!(ColorChoice::Always.enabled(false));
+        assert!(!ColorChoice::Never.enabled(true));
+        // Pipes and files never get color unless asked for
+        assert!(!ColorChoice::Auto.enabled(false));
+    }
+}
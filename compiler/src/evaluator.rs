@@ -1,283 +1,872 @@
 // compiler/src/evaluator.rs
 
 // Imports required modules from the project and standard library
-use crate::ast::{Expression, Program, Statement};
+use crate::ast::{Expression, Program, Span, Statement};
 use crate::environment::Environment;
-use crate::object::{BuiltinFunction, Object};
+use crate::lexer::Lexer;
+use crate::object::{builtin_input, builtin_print, format_print_args, BuiltinFunction, Object};
+use crate::stdlib::collections;
+use crate::stdlib::math::pow_function;
+use crate::parser::Parser;
+use crate::token::{lookup_ident, Symbol, TokenType};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
 use std::panic;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
-// Main evaluation function for the program (list of statements)
-pub fn eval(node: Program, env: &mut Environment) -> Object {
-    let mut result = Object::Null;
+// Default limit on nested B+ function calls
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1000;
 
-    // Evaluate each statement in sequence
-    for statement in node {
-        result = eval_statement(statement, env);
+// Start of the error raised when the call depth limit is exceeded
+pub const STACK_OVERFLOW_ERROR: &str = "stack overflow";
+// Start of the error raised when a function is called with the wrong number of arguments
+pub const WRONG_ARGUMENT_COUNT_ERROR: &str = "wrong number of arguments";
+// Longest string, in bytes, that repetition may build; more would exhaust memory and abort
+pub const MAX_STRING_LENGTH: usize = 100_000_000;
 
-        // Handle early returns or errors
-        match &result {
-            Object::ReturnValue(value) => return format_boolean(*value.clone()),
-            Object::Error(_) => return result,
-            _ => (),
+// === EVALUATOR ===
+// Walks the AST and carries the state that outlives a single expression:
+// how deeply B+ functions are nested and where the last runtime error was
+// raised. One evaluator can run several programs (the REPL reuses it for
+// every line), sharing its settings between them.
+pub struct Evaluator {
+    call_depth: usize,     // Nested B+ function calls currently being evaluated
+    max_call_depth: usize, // Limit on call_depth before evaluation stops with an error
+    output: Box<dyn Write>, // Where dekhao writes, stdout unless redirected
+    input: Box<dyn BufRead>, // Where input builtins read lines from, stdin unless redirected
+    module_stack: Vec<PathBuf>, // Script and modules being evaluated, innermost last (for relative paths and cycles)
+    keyword_aliases: HashMap<String, String>, // Active language pack's keywords, for lexing imported modules
+
+    // Message and position of the most recent runtime error, recorded by the innermost
+    // expression that produced it (errors are plain strings, so the position travels separately)
+    error_span: Option<(String, Span)>,
+}
+
+impl Default for Evaluator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Evaluator {
+    // === FUNCTION: new ===
+    pub fn new() -> Self {
+        Evaluator {
+            call_depth: 0,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            output: Box::new(io::stdout()),
+            input: Box::new(StdinInput::default()),
+            module_stack: Vec::new(),
+            keyword_aliases: HashMap::new(),
+            error_span: None,
         }
     }
 
-    // Format and return the final result
-    format_boolean(result)
-}
+    // Sets the file being run, so its imports resolve relative to it (otherwise to the working directory)
+    pub fn set_script_path(&mut self, path: &Path) {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.module_stack = vec![path];
+    }
 
-// Evaluates a single statement
-fn eval_statement(statement: Statement, env: &mut Environment) -> Object {
-    match statement {
-        // Evaluate expression statements
-        Statement::ExpressionStatement { expression } => eval_expression(expression, env),
-
-        // Handle variable declaration
-        Statement::Let { name, value, mutable } => {
-            let val = eval_expression(value, env);
-            if is_error(&val) { return val; }
-            if let Expression::Identifier(ident_name) = name {
-                env.set(ident_name, val, mutable);
-            } else {
-                return Object::Error("invalid let target".to_string());
-            }
-            Object::Null
+    // Lets imported modules use the same keywords as the script, from the active language pack
+    pub fn set_keyword_aliases(&mut self, aliases: HashMap<String, String>) {
+        self.keyword_aliases = aliases;
+    }
+
+    // Evaluates a program read from `path` in the given environment, with its imports
+    // resolving relative to that file; used to load a file into a running session
+    pub fn eval_file(&mut self, program: Program, path: &Path, env: &mut Environment) -> Object {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.module_stack.push(path);
+        let result = self.eval(program, env);
+        self.module_stack.pop();
+        result
+    }
+
+    // Clears the call depth and error position a panic left behind mid-evaluation,
+    // so the evaluator can keep running later input (see catch_panic)
+    pub fn recover_from_panic(&mut self) {
+        self.call_depth = 0;
+        self.error_span = None;
+    }
+
+    // Sends program output (dekhao) to `output` instead of stdout
+    pub fn set_output(&mut self, output: Box<dyn Write>) {
+        self.output = output;
+    }
+
+    // Makes input builtins read from `input` instead of stdin
+    pub fn set_input(&mut self, input: Box<dyn BufRead>) {
+        self.input = input;
+    }
+
+    // Shows `prompt` and reads one line of input without its line ending; None at end of input
+    fn read_input_line(&mut self, prompt: &str) -> Result<Option<String>, Object> {
+        let written = write!(self.output, "{}", prompt).and_then(|_| self.output.flush());
+        if let Err(e) = written {
+            return Err(Object::Error(format!("output error: {}", e)));
+        }
+
+        let mut line = String::new();
+        match self.input.read_line(&mut line) {
+            Ok(0) => Ok(None),
+            Ok(_) => Ok(Some(line.trim_end_matches(['\n', '\r']).to_string())),
+            Err(e) => Err(Object::Error(format!("Input error: {}", e))),
         }
+    }
 
+    // input: the next line with surrounding whitespace trimmed, or an empty string at end of input
+    fn read_text_input(&mut self, args: Vec<Object>) -> Object {
+        let prompt = args.first().map(|p| p.to_string()).unwrap_or_default();
+        match self.read_input_line(&prompt) {
+            Ok(line) => Object::String(line.unwrap_or_default().trim().to_string()),
+            Err(e) => e,
+        }
+    }
 
-        Statement::Assign { name, value } => {
-            let val = eval_expression(value, env);
-            if is_error(&val) {
-                return val;
+    // input_int and input_float: prompt until the line parses as the wanted number type
+    fn read_number_input(&mut self, builtin: BuiltinFunction, args: Vec<Object>) -> Object {
+        let name = if builtin == BuiltinFunction::InputInt { "input_int" } else { "input_float" };
+        if args.len() > 1 {
+            return Object::Error(format!("{}() takes an optional prompt", name));
+        }
+        let prompt = args.first().map(|p| p.to_string()).unwrap_or_default();
+
+        loop {
+            let line = match self.read_input_line(&prompt) {
+                Ok(Some(line)) => line,
+                Ok(None) => return Object::Error(format!("{}(): input ended before a number was entered", name)),
+                Err(e) => return e,
+            };
+            let text = line.trim();
+            let parsed = match builtin {
+                BuiltinFunction::InputInt => text.parse::<i64>().ok().map(Object::Integer),
+                _ => text.parse::<f64>().ok().filter(|x| x.is_finite()).map(Object::Float),
+            };
+            if let Some(number) = parsed {
+                return number;
             }
 
-            if let Expression::Identifier(ident_name) = name {
-                match env.assign(ident_name.clone(), val.clone()) {
-                    Ok(_) => val,  // Return evaluated value
-                    Err(e) => Object::Error(e),
-                }
-            } else {
-                Object::Error("invalid assignment target".to_string())
+            let kind = if builtin == BuiltinFunction::InputInt { "whole number" } else { "number" };
+            let retry = self.print_line(&format!("'{}' {} noy, abar cheshta korun", text, kind));
+            if is_error(&retry) {
+                return retry;
             }
         }
+    }
+
+    // Writes one line of program output; a failed write becomes a runtime error
+    fn print_line(&mut self, line: &str) -> Object {
+        match writeln!(self.output, "{}", line) {
+            Ok(()) => Object::Null,
+            Err(e) => Object::Error(format!("output error: {}", e)),
+        }
+    }
 
+    // Changes how deeply B+ functions may recurse before evaluation stops with an error
+    pub fn set_max_call_depth(&mut self, limit: usize) {
+        self.max_call_depth = limit;
+    }
+
+    // Source position where `error` was raised, if the evaluator recorded one
+    pub fn error_span(&self, error: &Object) -> Option<Span> {
+        let Object::Error(message) = error else { return None };
+        match &self.error_span {
+            Some((recorded, span)) if recorded == message => Some(*span),
+            _ => None,
+        }
+    }
 
+    // Remembers where an error was first raised; outer expressions it passes through keep that position
+    fn record_error_span(&mut self, error: &Object, span: Span) {
+        if let Object::Error(message) = error {
+            if self.error_span.as_ref().is_none_or(|(recorded, _)| recorded != message) {
+                self.error_span = Some((message.clone(), span));
+            }
+        }
+    }
 
-        
-        Statement::Expression(expr) => eval_expression(expr, env),
+    // Main evaluation function for the program (list of statements)
+    pub fn eval(&mut self, node: Program, env: &mut Environment) -> Object {
+        let mut result = Object::Null;
+        // A position recorded by earlier input must not stick to an error with the same message
+        self.error_span = None;
 
+        // Evaluate each statement in sequence
+        for statement in node {
+            result = self.eval_statement(statement, env);
 
-        // Handle return statements
-        Statement::Return { return_value } => {
-            let val = eval_expression(return_value, env);
-            if is_error(&val) {
-                return val;
+            // Handle early returns or errors
+            match &result {
+                Object::ReturnValue(value) => return format_boolean(*value.clone()),
+                Object::Error(_) => return result,
+                Object::Break(_) | Object::Continue(_) => return loop_signal_error(&result),
+                _ => (),
             }
-            Object::ReturnValue(Box::new(val))
         }
 
-        // Ignore comments during evaluation
-        Statement::CommentSingleLine { .. } => Object::Null,
-        Statement::CommentMultiLine { .. } => Object::Null,
+        // Format and return the final result
+        format_boolean(result)
+    }
 
-        // Handle while loops
-        Statement::While { condition, body } => {
-            while is_truthy(&eval_expression(condition.clone(), env)) {
-                let result = eval_block_statement(body.clone(), env);
-                match result {
-                    Object::ReturnValue(_) | Object::Error(_) => return result,
-                    _ => {}
+    // Evaluates a single statement
+    fn eval_statement(&mut self, statement: Statement, env: &mut Environment) -> Object {
+        match statement {
+            // Evaluate expression statements
+            Statement::ExpressionStatement { expression } => self.eval_expression(expression, env),
+
+            // Handle variable declaration
+            Statement::Let { name, value, mutable, .. } => {
+                let val = self.eval_expression(value, env);
+                if is_error(&val) { return val; }
+                if let Expression::Identifier(ident_name, _) = name {
+                    env.set(ident_name, val, mutable);
+                } else {
+                    return Object::Error("invalid let target".to_string());
+                }
+                Object::Null
+            }
+
+
+            Statement::Assign { name, value } => {
+                let val = self.eval_expression(value, env);
+                if is_error(&val) {
+                    return val;
+                }
+
+                if let Expression::Identifier(ident_name, _) = name {
+                    match env.assign(ident_name.clone(), val.clone()) {
+                        Ok(_) => val,  // Return evaluated value
+                        Err(e) => Object::Error(e),
+                    }
+                } else {
+                    Object::Error("invalid assignment target".to_string())
+                }
+            }
+
+
+
+
+            Statement::Expression(expr) => self.eval_expression(expr, env),
+
+
+            // Handle return statements
+            Statement::Return { return_value } => {
+                let val = self.eval_expression(return_value, env);
+                if is_error(&val) {
+                    return val;
                 }
+                Object::ReturnValue(Box::new(val))
+            }
+
+            // Ignore comments during evaluation
+            Statement::CommentSingleLine { .. } => Object::Null,
+            Statement::CommentMultiLine { .. } => Object::Null,
+
+            // Loops run in eval_loop, which also handles the labeled ones
+            statement @ (Statement::While { .. }
+            | Statement::DoWhile { .. }
+            | Statement::For { .. }
+            | Statement::ForEach { .. }) => self.eval_loop(statement, None, env),
+            Statement::Labeled { label, body } => self.eval_loop(*body, Some(label), env),
+
+            // Loop control signals, consumed by the innermost loop or the one with the label
+            Statement::Break(label) => Object::Break(label),
+            Statement::Continue(label) => Object::Continue(label),
+
+            Statement::Import { path, alias } => self.eval_import(&path, alias, env),
+
+            // The type's name becomes its constructor
+            Statement::TypeDefinition { name, fields } => {
+                env.set(name.clone(), Object::RecordType { name, fields }, false);
+                Object::Null
             }
-            Object::Null
         }
+    }
+
+    // Evaluates a .bp file in a fresh environment and binds what it defined, as a hash, under `alias`
+    fn eval_import(&mut self, path: &str, alias: String, env: &mut Environment) -> Object {
+        let base = self.module_stack.last().and_then(|current| current.parent());
+        let resolved = base.map_or_else(|| PathBuf::from(path), |dir| dir.join(path));
+        let module_path = match fs::canonicalize(&resolved) {
+            Ok(module_path) => module_path,
+            Err(e) => return Object::Error(format!("import koro: cannot open '{}': {}", path, e)),
+        };
 
-        // Handle for loops
-        Statement::For { init, condition, update, body } => {
-            if let Some(init_stmt) = init {
-                let result = eval_statement(*init_stmt, env);
-                if is_error(&result) {
-                    return result;
+        if let Some(start) = self.module_stack.iter().position(|p| *p == module_path) {
+            let cycle: Vec<String> = self.module_stack[start..]
+                .iter()
+                .chain(std::iter::once(&module_path))
+                .map(|p| p.file_name().map_or_else(|| p.display().to_string(), |n| n.to_string_lossy().into_owned()))
+                .collect();
+            return Object::Error(format!("import koro: cyclic import {}", cycle.join(" -> ")));
+        }
+
+        let source = match fs::read_to_string(&module_path) {
+            Ok(source) => source,
+            Err(e) => return Object::Error(format!("import koro: cannot read '{}': {}", path, e)),
+        };
+        let mut parser = Parser::new(Lexer::new(source).with_keyword_aliases(self.keyword_aliases.clone()));
+        let program = parser.parse_program();
+        if let Some(error) = parser.errors.first() {
+            return Object::Error(format!("import koro: syntax error in '{}': {}", path, error));
+        }
+
+        let mut module_env = Environment::new();
+        self.module_stack.push(module_path);
+        let result = self.eval(program, &mut module_env);
+        self.module_stack.pop();
+
+        // Positions inside the module would be misread as positions in the importing file
+        if let Object::Error(message) = &result {
+            let position = self.error_span(&result).map(|span| format!(":{}:{}", span.line, span.column));
+            return Object::Error(format!("{}{}: {}", path, position.unwrap_or_default(), message));
+        }
+
+        let exports = module_env
+            .exports()
+            .into_iter()
+            .map(|(name, value)| (Object::String(name), value))
+            .collect();
+        env.set(alias, Object::Hash(exports), false);
+        Object::Null
+    }
+
+    // Runs a loop statement. `label` names the loop for thamo/choluk with a label;
+    // signals for other labels are passed on to the enclosing loops.
+    fn eval_loop(&mut self, statement: Statement, label: Option<Symbol>, env: &mut Environment) -> Object {
+        match statement {
+            // Handle while loops
+            Statement::While { condition, body } => {
+                loop {
+                    let condition_obj = self.eval_expression(condition.clone(), env);
+                    if is_error(&condition_obj) {
+                        return condition_obj;
+                    }
+                    if !is_truthy(&condition_obj) {
+                        break;
+                    }
+
+                    let result = self.eval_scoped_block(body.clone(), env);
+                    match loop_flow(result, label.as_deref()) {
+                        LoopFlow::Exit(result) => return result,
+                        LoopFlow::Stop => break,
+                        LoopFlow::Next => {}
+                    }
                 }
+                Object::Null
             }
 
-            while match &condition {
-                Some(cond_expr) => is_truthy(&eval_expression(cond_expr.clone(), env)),
-                None => true, // If no condition, treat as infinite loop
-            } {
-                let result = eval_block_statement(body.clone(), env);
-                match result {
-                    Object::ReturnValue(_) | Object::Error(_) => return result,
-                    _ => {}
+            // Handle do-while loops: the body always runs once before the condition is checked
+            Statement::DoWhile { body, condition } => {
+                loop {
+                    let result = self.eval_scoped_block(body.clone(), env);
+                    match loop_flow(result, label.as_deref()) {
+                        LoopFlow::Exit(result) => return result,
+                        LoopFlow::Stop => break,
+                        LoopFlow::Next => {}
+                    }
+
+                    let condition_obj = self.eval_expression(condition.clone(), env);
+                    if is_error(&condition_obj) {
+                        return condition_obj;
+                    }
+                    if !is_truthy(&condition_obj) {
+                        break;
+                    }
                 }
+                Object::Null
+            }
 
-                // Evaluate update expression after each iteration
-                if let Some(ref upd_expr) = update {
-                    let result = eval_expression(upd_expr.clone(), env);
+            // Handle for loops; what the init part declares belongs to the loop
+            Statement::For { init, condition, update, body } => {
+                let env = &mut Environment::new_enclosed(env.clone());
+                if let Some(init_stmt) = init {
+                    let result = self.eval_statement(*init_stmt, env);
                     if is_error(&result) {
                         return result;
                     }
                 }
+
+                loop {
+                    if let Some(cond_expr) = &condition {
+                        let condition_obj = self.eval_expression(cond_expr.clone(), env);
+                        if is_error(&condition_obj) {
+                            return condition_obj;
+                        }
+                        if !is_truthy(&condition_obj) {
+                            break;
+                        }
+                    } // If no condition, treat as infinite loop
+
+                    let result = self.eval_scoped_block(body.clone(), env);
+                    match loop_flow(result, label.as_deref()) {
+                        LoopFlow::Exit(result) => return result,
+                        LoopFlow::Stop => break,
+                        LoopFlow::Next => {}
+                    }
+
+                    // Evaluate update expression after each iteration
+                    if let Some(ref upd_expr) = update {
+                        let result = self.eval_expression(upd_expr.clone(), env);
+                        if is_error(&result) {
+                            return result;
+                        }
+                    }
+                }
+
+                Object::Null
             }
 
-            Object::Null
-        }
+            // Handle for-each loops, each iteration gets its own enclosed scope
+            Statement::ForEach { var, iterable, body } => {
+                let iterable = self.eval_expression(iterable, env);
+                if is_error(&iterable) {
+                    return iterable;
+                }
 
-        // Placeholders for break/continue support
-        Statement::Break => Object::Null,
-        Statement::Continue => Object::Null,
-    }
-}
+                let items: Vec<Object> = match iterable {
+                    Object::Array(elements) => elements,
+                    Object::String(s) => s.chars().map(Object::Char).collect(),
+                    other => {
+                        return Object::Error(format!(
+                            "protitar jonno: cannot iterate over {} (expected array or string)",
+                            other.type_name()
+                        ))
+                    }
+                };
+
+                let var_name = match var {
+                    Expression::Identifier(name, _) => name,
+                    _ => return Object::Error("invalid loop variable".to_string()),
+                };
 
-// Evaluates a block of statements
-fn eval_block_statement(statements: Vec<Statement>, env: &mut Environment) -> Object {
-    let mut result = Object::Null;
+                for item in items {
+                    let mut scope = Environment::new_enclosed(env.clone());
+                    scope.set(var_name.clone(), item, true);
+                    let result = self.eval_block_statement(body.clone(), &mut scope);
+                    scope.release();
 
-    for statement in statements {
-        result = eval_statement(statement, env);
+                    match loop_flow(result, label.as_deref()) {
+                        LoopFlow::Exit(result) => return result,
+                        LoopFlow::Stop => break,
+                        LoopFlow::Next => {}
+                    }
+                }
 
-        // Early return on return or error
-        match &result {
-            Object::ReturnValue(_) | Object::Error(_) => return result,
-            _ => (),
+                Object::Null
+            }
+            other => self.eval_statement(other, env),
         }
     }
 
-    result
-}
+    // Evaluates a block of statements
+    fn eval_block_statement(&mut self, statements: Vec<Statement>, env: &mut Environment) -> Object {
+        let mut result = Object::Null;
 
-// Evaluates an expression
-fn eval_expression(expr: Expression, env: &mut Environment) -> Object {
-    match expr {
-        // Integer literal
-        Expression::IntegerLiteral(value) => Object::Integer(value),
+        for statement in statements {
+            result = self.eval_statement(statement, env);
 
-        // String literal
-        Expression::StringLiteral(value) => Object::String(value),
+            // Early return on return, error or loop control signal
+            match &result {
+                Object::ReturnValue(_) | Object::Error(_) | Object::Break(_) | Object::Continue(_) => return result,
+                _ => (),
+            }
+        }
 
-        // Boolean literal
-        Expression::Boolean(value) => Object::Boolean(value),
+        result
+    }
 
-        // Prefix expressions like ! or -
-        Expression::Prefix { operator, right } => {
-            let right = eval_expression(*right, env);
-            if is_error(&right) { return right; }
-            eval_prefix_expression(&operator, right)
-        }
+    // Evaluates a block in a scope of its own, so variables it declares end with it;
+    // assignments still reach variables of the enclosing scopes
+    fn eval_scoped_block(&mut self, statements: Vec<Statement>, env: &Environment) -> Object {
+        let mut scope = Environment::new_enclosed(env.clone());
+        let result = self.eval_block_statement(statements, &mut scope);
+        scope.release();
+        result
+    }
 
-        // Infix expressions like +, -, *, /, ==, !=, <, >
-        Expression::Infix { left, operator, right } => {
-            let left = eval_expression(*left, env);
-            if is_error(&left) { return left; }
-            let right = eval_expression(*right, env);
-            if is_error(&right) { return right; }
-            eval_infix_expression(&operator, left, right)
+    // Evaluates an expression, recording the position of any error it raises
+    fn eval_expression(&mut self, expr: Expression, env: &mut Environment) -> Object {
+        let span = expr.span();
+        let result = self.eval_expression_kind(expr, env);
+        if let Some(span) = span {
+            self.record_error_span(&result, span);
         }
+        result
+    }
 
-        // Variable lookup in environment
-        Expression::Identifier(name) => match env.get(&name) {
-            Some(obj) => obj,
-            None => Object::Error(format!("identifier not found: {}", name)),
-        },
+    fn eval_expression_kind(&mut self, expr: Expression, env: &mut Environment) -> Object {
+        match expr {
+            // Integer literal
+            Expression::IntegerLiteral(value) => Object::Integer(value),
 
-        // Conditional expressions
-        Expression::If { condition, consequence, alternative } => {
-            let condition_obj = eval_expression(*condition, env);
-            if is_error(&condition_obj) { return condition_obj; }
-            if is_truthy(&condition_obj) {
-                eval_block_statement(consequence, env)
-            } else if let Some(alt_expr) = alternative {
-                eval_expression(*alt_expr, env)
-            } else {
-                Object::Null
+            // Float literal
+            Expression::FloatLiteral(value) => Object::Float(value),
+
+            // String literal
+            Expression::StringLiteral(value) => Object::String(value),
+
+            // Character literal
+            Expression::CharLiteral(value) => Object::Char(value),
+
+            // Boolean literal
+            Expression::Boolean(value) => Object::Boolean(value),
+
+            // Prefix expressions like ! or -
+            Expression::Prefix { operator, right, .. } => {
+                let right = self.eval_expression(*right, env);
+                if is_error(&right) { return right; }
+                eval_prefix_expression(&operator, right)
             }
-        },
 
-        // Function literal creation
-        Expression::FunctionLiteral { parameters, body } => {
-            Object::Function { parameters, body, env: env.clone() }
-        },
+            // Infix expressions like +, -, *, /, ==, !=, <, >
+            Expression::Infix { left, operator, right, .. } => {
+                let left = self.eval_expression(*left, env);
+                if is_error(&left) { return left; }
 
-        // Function call expression
-        Expression::Call { function, arguments } => {
-            // Evaluate the function itself
-            let function_obj = eval_expression(*function.clone(), env);
-            if is_error(&function_obj) { return function_obj; }
-
-            // Handle "dekhao" builtin with template literal support
-            if let Expression::Identifier(ref name) = *function {
-                if name == "dekhao" {
-                    let mut output = String::new();
-
-                    // Check if first argument is a template literal
-                    if let Some(Expression::TemplateLiteral { parts }) = arguments.get(0) {
-                        for part in parts {
-                            let val = match part {
-                                Expression::StringLiteral(s) => Object::String(s.clone()),
-                                expr => eval_expression(expr.clone(), env),
-                            };
-                            match val {
-                                Object::String(s) => output.push_str(&s),
-                                Object::Integer(i) => output.push_str(&i.to_string()),
-                                Object::Boolean(b) => output.push_str(if b { "Ha" } else { "Na" }),
-                                Object::Null => output.push_str("Null"),
-                                Object::Error(ref e) => return Object::Error(e.clone()),
-                                _ => output.push_str(&format!("{:?}", val)),
+                // ebong/othoba only evaluate the right side when the left doesn't decide
+                if let Some(logical) = logical_operator(&operator) {
+                    if is_truthy(&left) != (logical == TokenType::Ebong) {
+                        return Object::Boolean(is_truthy(&left));
+                    }
+                    let right = self.eval_expression(*right, env);
+                    if is_error(&right) { return right; }
+                    return Object::Boolean(is_truthy(&right));
+                }
+
+                let right = self.eval_expression(*right, env);
+                if is_error(&right) { return right; }
+                eval_infix_expression(&operator, left, right)
+            }
+
+            // Variable lookup in environment
+            Expression::Identifier(name, _) => match env.get(&name) {
+                Some(obj) => obj,
+                None => Object::Error(format!("identifier not found: {}", name)),
+            },
+
+            // Conditional expressions
+            Expression::If { condition, consequence, alternative } => {
+                let condition_obj = self.eval_expression(*condition, env);
+                if is_error(&condition_obj) { return condition_obj; }
+                if is_truthy(&condition_obj) {
+                    self.eval_scoped_block(consequence, env)
+                } else if let Some(alt_expr) = alternative {
+                    self.eval_expression(*alt_expr, env)
+                } else {
+                    Object::Null
+                }
+            },
+
+            // Function literal creation
+            Expression::FunctionLiteral { parameters, parameter_defaults, variadic, body, .. } => {
+                Object::Function { parameters, defaults: parameter_defaults, variadic, body, env: env.clone() }
+            },
+
+            // Function call expression
+            Expression::Call { function, arguments, .. } => {
+                // Evaluate the function itself
+                let function_obj = self.eval_expression(*function.clone(), env);
+                if is_error(&function_obj) { return function_obj; }
+
+                // Handle "dekhao" builtin with template literal support
+                if let Expression::Identifier(ref name, _) = *function {
+                    if &**name == "dekhao" {
+                        let mut output = String::new();
+
+                        // Check if first argument is a template literal
+                        if let Some(Expression::TemplateLiteral { parts }) = arguments.first() {
+                            for part in parts {
+                                let val = match part {
+                                    Expression::StringLiteral(s) => Object::String(s.clone()),
+                                    expr => self.eval_expression(expr.clone(), env),
+                                };
+                                if is_error(&val) { return val; }
+                                output.push_str(&val.to_string());
                             }
+                            return self.print_line(&output);
                         }
-                        println!("{}", output);
-                        return Object::Null;
-                    }
 
-                    // Fallback for regular single/multiple arguments
-                    for arg in arguments {
-                        let val = eval_expression(arg, env);
-                        if is_error(&val) { return val; }
-                        match val {
-                            Object::String(s) => output.push_str(&s),
-                            Object::Integer(i) => output.push_str(&i.to_string()),
-                            Object::Boolean(b) => output.push_str(if b { "Ha" } else { "Na" }),
-                            Object::Null => output.push_str("Null"),
-                            Object::Error(ref e) => return Object::Error(e.clone()),
-                            _ => output.push_str(&format!("{:?}", val)),
+                        // Fallback for regular arguments, printed space separated like builtin_print;
+                        // both paths render values through Object's Display so they print the same
+                        let mut values = Vec::new();
+                        for arg in arguments {
+                            let val = self.eval_expression(arg, env);
+                            if is_error(&val) { return val; }
+                            values.push(val);
                         }
+                        return self.print_line(&format_print_args(&values));
                     }
-                    println!("{}", output);
-                    return Object::Null;
                 }
+
+                // Evaluate all arguments and apply function
+                let args = self.eval_expressions(arguments, env);
+                if args.len() == 1 && is_error(&args[0]) {
+                    return args[0].clone();
+                }
+                self.apply_function(function_obj, args)
+            },
+
+            // TemplateLiteral evaluation for general expressions
+            Expression::TemplateLiteral { parts } => {
+                // Concatenate all parts into a single string
+                let mut result = String::new();
+                for part in parts {
+                    let val = self.eval_expression(part.clone(), env);
+                    if is_error(&val) { return val; }
+                    result.push_str(&val.to_string());
+                }
+                Object::String(result)
+            },
+
+            // Array literal: evaluate every element in order
+            Expression::ArrayLiteral { elements } => {
+                let elements = self.eval_expressions(elements, env);
+                if elements.len() == 1 && is_error(&elements[0]) {
+                    return elements[0].clone();
+                }
+                Object::Array(elements)
+            },
+
+            // Hash literal: evaluate every key/value pair in order
+            Expression::HashLiteral { pairs } => self.eval_hash_literal(pairs, env),
+
+            // Index access into arrays and hashes
+            Expression::Index { left, index, .. } => {
+                let left = self.eval_expression(*left, env);
+                if is_error(&left) { return left; }
+                let index = self.eval_expression(*index, env);
+                if is_error(&index) { return index; }
+                eval_index_expression(left, index)
+            },
+
+            // Async functions: there is no scheduler, so a call runs the body right away
+            // and hands back a future already settled with the result (or the error)
+            Expression::AsyncFunction(function) => {
+                let function = self.eval_expression(*function, env);
+                if is_error(&function) { return function; }
+                Object::AsyncFunction(Box::new(function))
+            },
+
+            // Awaiting a future gives its settled value, re-raising its error; any other value is its own result
+            Expression::Await(value) => match self.eval_expression(*value, env) {
+                Object::Future(result) => *result,
+                other => other,
+            },
+        }
+    }
+
+    // Builds a hash object, later duplicate keys overwrite earlier ones
+    fn eval_hash_literal(&mut self, pairs: Vec<(Expression, Expression)>, env: &mut Environment) -> Object {
+        let mut entries: Vec<(Object, Object)> = Vec::new();
+
+        for (key_expr, value_expr) in pairs {
+            let key = self.eval_expression(key_expr, env);
+            if is_error(&key) { return key; }
+            if !key.is_hashable() {
+                return Object::Error(format!("unusable as hash key: {}", key));
             }
 
-            // Evaluate all arguments and apply function
-            let args = eval_expressions(arguments, env);
-            if args.len() == 1 && is_error(&args[0]) {
-                return args[0].clone();
+            let value = self.eval_expression(value_expr, env);
+            if is_error(&value) { return value; }
+
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some(entry) => entry.1 = value,
+                None => entries.push((key, value)),
             }
-            apply_function(function_obj, args)
-        },
+        }
+
+        Object::Hash(entries)
+    }
 
-        // TemplateLiteral evaluation for general expressions
-        Expression::TemplateLiteral { parts } => {
-            // Concatenate all parts into a single string
-            let mut result = String::new();
-            for part in parts {
-                let val = eval_expression(part.clone(), env);
-                match val {
-                    Object::String(s) => result.push_str(&s),
-                    Object::Integer(i) => result.push_str(&i.to_string()),
-                    Object::Boolean(b) => result.push_str(if b { "Ha" } else { "Na" }),
-                    Object::Null => result.push_str("Null"),
-                    Object::Error(ref e) => return Object::Error(e.clone()),
-                    _ => result.push_str(&format!("{:?}", val)),
+    // Evaluates a list of expressions (arguments to a function)
+    fn eval_expressions(&mut self, exprs: Vec<Expression>, env: &mut Environment) -> Vec<Object> {
+        let mut result = Vec::new();
+        for e in exprs {
+            let evaluated = self.eval_expression(e, env);
+            if is_error(&evaluated) {
+                return vec![evaluated];
+            }
+            result.push(evaluated);
+        }
+        result
+    }
+
+    // Applies a function (user-defined or built-in)
+    fn apply_function(&mut self, func: Object, args: Vec<Object>) -> Object {
+        match func {
+            // dekhao reached through another name still goes to the evaluator's output
+            Object::BuiltinNative(builtin_fn) if std::ptr::fn_addr_eq(builtin_fn, builtin_print as fn(Vec<Object>) -> Object) => {
+                self.print_line(&format_print_args(&args))
+            }
+            // So does input, which reads from the evaluator's input source
+            Object::BuiltinNative(builtin_fn) if std::ptr::fn_addr_eq(builtin_fn, builtin_input as fn(Vec<Object>) -> Object) => {
+                self.read_text_input(args)
+            }
+            Object::BuiltinNative(builtin_fn) => {
+                // Catch panic during built-in function execution
+                let result = panic::catch_unwind(panic::AssertUnwindSafe(|| builtin_fn(args)));
+                match result {
+                    Ok(val) => val,
+                    Err(_) => Object::Error("panic occurred in built-in function".to_string()),
                 }
             }
-            Object::String(result)
-        },
+            Object::Function { parameters, defaults, variadic, body, env } => {
+                // Fail gracefully instead of overflowing the Rust stack on runaway recursion
+                if self.call_depth >= self.max_call_depth {
+                    return Object::Error(format!(
+                        "{}: maximum call depth of {} exceeded",
+                        STACK_OVERFLOW_ERROR, self.max_call_depth
+                    ));
+                }
+
+                // Every parameter without a default needs an argument; only a
+                // variadic function takes more arguments than it has parameters
+                let fixed = if variadic { parameters.len() - 1 } else { parameters.len() };
+                let required = defaults.iter().take(fixed).filter(|d| d.is_none()).count();
+                if args.len() < required || (!variadic && args.len() > fixed) {
+                    let expected = if variadic {
+                        format!("at least {}", required)
+                    } else if required < fixed {
+                        format!("{} to {}", required, fixed)
+                    } else {
+                        fixed.to_string()
+                    };
+                    return Object::Error(format!(
+                        "{}: expected {}, got {}",
+                        WRONG_ARGUMENT_COUNT_ERROR, expected, args.len()
+                    ));
+                }
+
+                let mut extended_env = Environment::new_enclosed(env);
+
+                // Bind arguments to parameter names, the rest go into the variadic parameter.
+                // Defaults are evaluated in the call's scope, so they can use earlier parameters.
+                let mut args = args.into_iter();
+                for (i, param) in parameters.iter().enumerate() {
+                    let value = if variadic && i == fixed {
+                        Object::Array(args.by_ref().collect())
+                    } else if let Some(arg) = args.next() {
+                        arg
+                    } else if let Some(Some(default)) = defaults.get(i) {
+                        let value = self.eval_expression(default.clone(), &mut extended_env);
+                        if is_error(&value) {
+                            return value;
+                        }
+                        value
+                    } else {
+                        Object::Null
+                    };
+                    if let Expression::Identifier(param_name, _) = param {
+                        extended_env.set(param_name.clone(), value, true);
+                    }
+                }
+
+                // Execute the function body
+                self.call_depth += 1;
+                let evaluated = self.eval_block_statement(body, &mut extended_env);
+                self.call_depth -= 1;
+                extended_env.release();
+
+                // Unwrap return value if needed
+                match evaluated {
+                    Object::ReturnValue(value) => *value,
+                    Object::Break(_) | Object::Continue(_) => loop_signal_error(&evaluated),
+                    _ => evaluated,
+                }
+            }
+            Object::AsyncFunction(function) => Object::Future(Box::new(self.apply_function(*function, args))),
+            Object::RecordType { name, fields } => {
+                if args.len() != fields.len() {
+                    return Object::Error(format!(
+                        "{}: expected {}, got {}",
+                        WRONG_ARGUMENT_COUNT_ERROR, fields.len(), args.len()
+                    ));
+                }
+                let fields = fields.into_iter().map(Object::String).zip(args).collect();
+                Object::Record { type_name: name, fields }
+            }
+            Object::BuiltinFunction(builtin) => self.apply_builtin_function(builtin, args),
+            _ => Object::Error(format!("not a function: {:?}", func)),
+        }
+    }
+
+    // Applies a builtin that calls back into B+ functions, such as map
+    fn apply_builtin_function(&mut self, builtin: BuiltinFunction, args: Vec<Object>) -> Object {
+        let mut apply = |function, args| self.apply_function(function, args);
+        match builtin {
+            BuiltinFunction::Map => collections::array_map(args, &mut apply),
+            BuiltinFunction::Filter => collections::array_filter(args, &mut apply),
+            BuiltinFunction::Reduce => collections::array_reduce(args, &mut apply),
+            BuiltinFunction::Sort => collections::array_sort(args, &mut apply),
+            BuiltinFunction::Input => self.read_text_input(args),
+            BuiltinFunction::InputInt | BuiltinFunction::InputFloat => self.read_number_input(builtin, args),
+            #[cfg(test)]
+            BuiltinFunction::Panic => panic!("__panic called"),
+            other => Object::Error(format!("builtin {:?} cannot be called directly", other)),
+        }
+    }
+}
+
+// Repeats `text` `count` times, refusing results longer than MAX_STRING_LENGTH
+pub(crate) fn repeat_string(text: &str, count: usize) -> Object {
+    match text.len().checked_mul(count) {
+        Some(length) if length <= MAX_STRING_LENGTH => Object::String(text.repeat(count)),
+        _ => Object::Error(format!(
+            "repeated string would be longer than {} bytes: {} bytes * {}",
+            MAX_STRING_LENGTH,
+            text.len(),
+            count
+        )),
+    }
+}
+
+// Evaluates arr[i] and hash[key]; a missing hash key yields Null
+fn eval_index_expression(left: Object, index: Object) -> Object {
+    match (&left, &index) {
+        (Object::Array(elements), Object::Integer(i)) => {
+            if *i < 0 || *i as usize >= elements.len() {
+                Object::Error(format!("index out of bounds: {} (length {})", i, elements.len()))
+            } else {
+                elements[*i as usize].clone()
+            }
+        }
+        (Object::String(text), Object::Integer(i)) => {
+            // Strings index by character, not byte, so Bengali text works as expected
+            let length = text.chars().count();
+            if *i < 0 || *i as usize >= length {
+                Object::Error(format!("index out of bounds: {} (length {})", i, length))
+            } else {
+                Object::Char(text.chars().nth(*i as usize).unwrap())
+            }
+        }
+        (Object::Hash(pairs), key) => {
+            if !key.is_hashable() {
+                return Object::Error(format!("unusable as hash key: {}", key));
+            }
+            pairs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+                .unwrap_or(Object::Null)
+        }
+        // Unlike hashes, records have a fixed set of fields, so a wrong name is an error
+        (Object::Record { type_name, fields }, key) => fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .unwrap_or_else(|| Object::Error(format!("{} has no field {}", type_name, key))),
+        _ => Object::Error(format!("index operator not supported: {}[{}]", left, index)),
     }
 }
 
 
 // Evaluates prefix operations like !value or -value
-fn eval_prefix_expression(operator: &str, right: Object) -> Object {
+pub(crate) fn eval_prefix_expression(operator: &str, right: Object) -> Object {
     match operator {
         "!" => eval_bang_operator_expression(right),
         "-" => eval_minus_prefix_operator_expression(right),
@@ -285,28 +874,69 @@ fn eval_prefix_expression(operator: &str, right: Object) -> Object {
     }
 }
 
-// Evaluates logical NOT (!)
+// Evaluates logical NOT (! or noy): the opposite of the value's truthiness
 fn eval_bang_operator_expression(right: Object) -> Object {
-    match right {
-        Object::Boolean(true) => Object::Boolean(false),
-        Object::Boolean(false) => Object::Boolean(true),
-        Object::String(ref s) if s == "Ha" => Object::Boolean(false),
-        Object::String(ref s) if s == "Na" => Object::Boolean(true),
-        Object::Null => Object::Boolean(true),
-        _ => Object::Boolean(false),
-    }
+    Object::Boolean(!is_truthy(&right))
 }
 
 // Evaluates unary minus (-)
 fn eval_minus_prefix_operator_expression(right: Object) -> Object {
     match right {
-        Object::Integer(val) => Object::Integer(-val),
-        _ => Object::Error(format!("unknown operator: -{:?}", right)),
+        Object::Integer(val) => match val.checked_neg() {
+            Some(negated) => Object::Integer(negated),
+            None => Object::Error(format!("integer overflow: -({})", val)),
+        },
+        Object::Float(val) => Object::Float(-val),
+        _ => Object::Error(format!("cannot negate a {} value: -{}", right.type_name(), right)),
+    }
+}
+
+// Result of checked integer arithmetic, or an overflow error naming the operation
+fn checked_integer(result: Option<i64>, left: &i64, operator: &str, right: &i64) -> Object {
+    match result {
+        Some(value) => Object::Integer(value),
+        None => Object::Error(format!("integer overflow: {} {} {}", left, operator, right)),
+    }
+}
+
+// Deep equality used by == and assert_eq: arrays match element by element, hashes and
+// records by their key/value pairs in any order, and other values as == compares them
+pub(crate) fn values_equal(left: &Object, right: &Object) -> bool {
+    fn same_pairs(l: &[(Object, Object)], r: &[(Object, Object)]) -> bool {
+        l.len() == r.len()
+            && l.iter().all(|(key, value)| {
+                r.iter().any(|(other_key, other_value)| key == other_key && values_equal(value, other_value))
+            })
+    }
+
+    match (left, right) {
+        (Object::Array(l), Object::Array(r)) => l.len() == r.len() && l.iter().zip(r).all(|(a, b)| values_equal(a, b)),
+        (Object::Hash(l), Object::Hash(r)) => same_pairs(l, r),
+        (Object::Record { type_name: l_name, fields: l }, Object::Record { type_name: r_name, fields: r }) => {
+            l_name == r_name && same_pairs(l, r)
+        }
+        (Object::Array(_) | Object::Hash(_) | Object::Record { .. }, _)
+        | (_, Object::Array(_) | Object::Hash(_) | Object::Record { .. }) => false,
+        (Object::Null, Object::Null) => true,
+        _ => eval_infix_expression("==", left.clone(), right.clone()) == Object::Boolean(true),
+    }
+}
+
+// Logical operator an infix operator stands for: Ebong for ebong, and, &&;
+// Othoba for othoba, ba, or, ||
+pub(crate) fn logical_operator(operator: &str) -> Option<TokenType> {
+    match operator {
+        "&&" => Some(TokenType::Ebong),
+        "||" => Some(TokenType::Othoba),
+        word if word.starts_with(|c: char| c.is_alphabetic()) => {
+            Some(lookup_ident(word)).filter(|t| matches!(t, TokenType::Ebong | TokenType::Othoba))
+        }
+        _ => None,
     }
 }
 
 // Evaluates binary operations like +, -, ==, etc.
-fn eval_infix_expression(operator: &str, left: Object, right: Object) -> Object {
+pub(crate) fn eval_infix_expression(operator: &str, left: Object, right: Object) -> Object {
     // Helper to convert strings like "Ha"/"Na" into booleans
     fn to_bool(obj: &Object) -> Option<bool> {
         match obj {
@@ -318,24 +948,92 @@ fn eval_infix_expression(operator: &str, left: Object, right: Object) -> Object
     }
 
     match (&left, &right) {
+        // Overflow is an error in every build profile rather than a panic or a wrapped result
         (Object::Integer(l), Object::Integer(r)) => match operator {
-            "+" => Object::Integer(l + r),
-            "-" => Object::Integer(l - r),
-            "*" => Object::Integer(l * r),
-            "/" => Object::Integer(l / r),
+            "+" => checked_integer(l.checked_add(*r), l, operator, r),
+            "-" => checked_integer(l.checked_sub(*r), l, operator, r),
+            "*" => checked_integer(l.checked_mul(*r), l, operator, r),
+            "**" => pow_function(vec![left.clone(), right.clone()]),
+            "/" if *r == 0 => Object::Error("division by zero".to_string()),
+            "/" => checked_integer(l.checked_div(*r), l, operator, r),
             "<" => Object::Boolean(l < r),
             ">" => Object::Boolean(l > r),
+            "<=" => Object::Boolean(l <= r),
+            ">=" => Object::Boolean(l >= r),
             "==" => Object::Boolean(l == r),
             "!=" => Object::Boolean(l != r),
             _ => Object::Error(format!("unknown operator: {:?} {} {:?}", left, operator, right)),
         },
-        (Object::String(l), Object::String(r)) => {
-            if operator == "+" {
-                Object::String(format!("{}{}", l, r))
+        // Any float operand promotes the arithmetic to floating point
+        (Object::Float(_), Object::Float(_) | Object::Integer(_))
+        | (Object::Integer(_), Object::Float(_)) => {
+            let l = as_float(&left).unwrap();
+            let r = as_float(&right).unwrap();
+            match operator {
+                "+" => Object::Float(l + r),
+                "-" => Object::Float(l - r),
+                "*" => Object::Float(l * r),
+                "**" => Object::Float(l.powf(r)),
+                "/" if r == 0.0 => Object::Error("division by zero".to_string()),
+                "/" => Object::Float(l / r),
+                "<" => Object::Boolean(l < r),
+                ">" => Object::Boolean(l > r),
+                "<=" => Object::Boolean(l <= r),
+                ">=" => Object::Boolean(l >= r),
+                "==" => Object::Boolean(l == r),
+                "!=" => Object::Boolean(l != r),
+                _ => Object::Error(format!("unknown operator: {:?} {} {:?}", left, operator, right)),
+            }
+        }
+        // Strings concatenate with + and compare lexicographically
+        (Object::String(l), Object::String(r)) => match operator {
+            "+" => Object::String(format!("{}{}", l, r)),
+            "<" => Object::Boolean(l < r),
+            ">" => Object::Boolean(l > r),
+            "<=" => Object::Boolean(l <= r),
+            ">=" => Object::Boolean(l >= r),
+            "==" => Object::Boolean(l == r),
+            "!=" => Object::Boolean(l != r),
+            _ => Object::Error(format!("unknown operator for strings: {}", operator)),
+        },
+        // Characters compare by code point and join into strings with +
+        (Object::Char(l), Object::Char(r)) => match operator {
+            "+" => Object::String(format!("{}{}", l, r)),
+            "<" => Object::Boolean(l < r),
+            ">" => Object::Boolean(l > r),
+            "<=" => Object::Boolean(l <= r),
+            ">=" => Object::Boolean(l >= r),
+            "==" => Object::Boolean(l == r),
+            "!=" => Object::Boolean(l != r),
+            _ => Object::Error(format!("unknown operator for chars: {}", operator)),
+        },
+        (Object::Char(c), Object::String(text)) | (Object::String(text), Object::Char(c)) => {
+            let single = c.to_string() == *text;
+            match operator {
+                "+" if matches!(left, Object::Char(_)) => Object::String(format!("{}{}", c, text)),
+                "+" => Object::String(format!("{}{}", text, c)),
+                "==" => Object::Boolean(single),
+                "!=" => Object::Boolean(!single),
+                _ => Object::Error(format!("unknown operator for char and string: {}", operator)),
+            }
+        }
+        // String repetition: "ab" * 3 or 3 * "ab"
+        (Object::String(text), Object::Integer(count)) | (Object::Integer(count), Object::String(text))
+            if operator == "*" =>
+        {
+            if *count < 0 {
+                Object::Error(format!("cannot repeat a string a negative number of times: {}", count))
             } else {
-                Object::Error(format!("unknown operator for strings: {}", operator))
+                repeat_string(text, *count as usize)
             }
         }
+        // Arrays, hashes and records compare by their contents
+        (Object::Array(_) | Object::Hash(_) | Object::Record { .. }, _)
+        | (_, Object::Array(_) | Object::Hash(_) | Object::Record { .. })
+            if operator == "==" || operator == "!=" =>
+        {
+            Object::Boolean(values_equal(&left, &right) == (operator == "=="))
+        }
         _ => {
             // Handle boolean comparisons
             if let (Some(lb), Some(rb)) = (to_bool(&left), to_bool(&right)) {
@@ -351,64 +1049,54 @@ fn eval_infix_expression(operator: &str, left: Object, right: Object) -> Object
     }
 }
 
-// Evaluates a list of expressions (arguments to a function)
-fn eval_expressions(exprs: Vec<Expression>, env: &mut Environment) -> Vec<Object> {
-    let mut result = Vec::new();
-    for e in exprs {
-        let evaluated = eval_expression(e, env);
-        if is_error(&evaluated) {
-            return vec![evaluated];
-        }
-        result.push(evaluated);
+// Numeric value of an integer or float as f64
+pub fn as_float(obj: &Object) -> Option<f64> {
+    match obj {
+        Object::Integer(i) => Some(*i as f64),
+        Object::Float(x) => Some(*x),
+        _ => None,
     }
-    result
 }
 
-// Applies a function (user-defined or built-in)
-fn apply_function(func: Object, args: Vec<Object>) -> Object {
-    match func {
-        Object::BuiltinNative(builtin_fn) => {
-            // Catch panic during built-in function execution
-            let result = panic::catch_unwind(|| builtin_fn(args));
-            match result {
-                Ok(val) => val,
-                Err(_) => Object::Error("panic occurred in built-in function".to_string()),
-            }
-        }
-        Object::Function { parameters, body, env } => {
-            let mut extended_env = Environment::new_enclosed(env);
-
-            // Bind arguments to parameter names
-            for (param, arg) in parameters.iter().zip(args.iter()) {
-                if let Expression::Identifier(param_name) = param {
-                    extended_env.set(param_name.clone(), arg.clone(), true);
-                }
-            }
+// What a loop does after its body ran: go on, stop, or stop and pass the result on
+enum LoopFlow {
+    Next,
+    Stop,
+    Exit(Object),
+}
 
-            // Execute the function body
-            let evaluated = eval_block_statement(body, &mut extended_env);
+fn loop_flow(result: Object, label: Option<&str>) -> LoopFlow {
+    let for_this_loop = |target: &Option<Symbol>| target.as_deref().is_none_or(|target| Some(target) == label);
+    match result {
+        Object::Break(ref target) if for_this_loop(target) => LoopFlow::Stop,
+        Object::Continue(ref target) if for_this_loop(target) => LoopFlow::Next,
+        Object::ReturnValue(_) | Object::Error(_) | Object::Break(_) | Object::Continue(_) => LoopFlow::Exit(result),
+        _ => LoopFlow::Next,
+    }
+}
 
-            // Unwrap return value if needed
-            if let Object::ReturnValue(value) = evaluated {
-                *value
-            } else {
-                evaluated
-            }
-        }
-        _ => {
-            eprintln!("TypeError: tried to call a non-function object: {:?}", func);
-            Object::Error(format!("not a function: {:?}", func))
+// Error for a thamo/choluk that escaped every loop
+fn loop_signal_error(signal: &Object) -> Object {
+    match signal {
+        Object::Break(Some(label)) | Object::Continue(Some(label)) => {
+            Object::Error(format!("'{}' used outside of a loop labeled '{}'", signal, label))
         }
+        _ => Object::Error(format!("'{}' used outside of a loop", signal)),
     }
 }
 
-// Determines truthiness of an object
-fn is_truthy(obj: &Object) -> bool {
+// Determines truthiness of an object, used by jodi, loops, ebong/othoba and !.
+// Falsy: Na (and the string "Na"), null, 0, 0.0, "" and empty arrays and hashes.
+// Everything else is truthy.
+pub fn is_truthy(obj: &Object) -> bool {
     match obj {
         Object::Boolean(b) => *b,
         Object::Null => false,
-        Object::String(ref s) if s == "Ha" => true,
-        Object::String(ref s) if s == "Na" => false,
+        Object::Integer(i) => *i != 0,
+        Object::Float(x) => *x != 0.0,
+        Object::String(ref s) => !s.is_empty() && s != "Na",
+        Object::Array(elements) => !elements.is_empty(),
+        Object::Hash(pairs) => !pairs.is_empty(),
         _ => true,
     }
 }
@@ -419,10 +1107,1062 @@ fn is_error(obj: &Object) -> bool {
 }
 
 // Converts booleans to Bangla-style "Ha"/"Na" strings
-fn format_boolean(obj: Object) -> Object {
+pub(crate) fn format_boolean(obj: Object) -> Object {
     match obj {
         Object::Boolean(true) => Object::String("Ha".to_string()),
         Object::Boolean(false) => Object::String("Na".to_string()),
         _ => obj,
     }
 }
+
+// Shared byte buffer, so output written through the evaluator can be read back afterwards
+#[derive(Clone, Default)]
+struct CapturedOutput(Rc<RefCell<Vec<u8>>>);
+
+impl Write for CapturedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Default input source: reads stdin a byte at a time through std's shared buffer, so lines
+// input builtins don't ask for stay in stdin for the REPL to read
+#[derive(Default)]
+struct StdinInput {
+    byte: [u8; 1],
+    filled: bool,
+}
+
+impl Read for StdinInput {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for StdinInput {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.filled {
+            if io::stdin().read(&mut self.byte)? == 0 {
+                return Ok(&[]);
+            }
+            self.filled = true;
+        }
+        Ok(&self.byte)
+    }
+
+    fn consume(&mut self, amount: usize) {
+        if amount > 0 {
+            self.filled = false;
+        }
+    }
+}
+
+// Runs B+ source in a fresh environment and returns everything it printed.
+// Syntax errors, or the runtime error that stopped the program, end the output as `Error: ...` lines.
+pub fn run_capturing(source: &str) -> String {
+    run_capturing_with_input(source, "")
+}
+
+// Same as run_capturing, with input builtins reading their lines from `input` instead of stdin
+pub fn run_capturing_with_input(source: &str, input: &str) -> String {
+    let captured = CapturedOutput::default();
+    let mut parser = Parser::new(Lexer::new(source.to_string()));
+    let program = parser.parse_program();
+    if !parser.errors.is_empty() {
+        return parser.errors.iter().map(|e| format!("Error: {}\n", e)).collect();
+    }
+
+    let mut evaluator = Evaluator::new();
+    evaluator.set_max_call_depth(crate::LIBRARY_MAX_CALL_DEPTH);
+    evaluator.set_output(Box::new(captured.clone()));
+    evaluator.set_input(Box::new(io::Cursor::new(input.to_string())));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| evaluator.eval(program, &mut Environment::new())))
+        .unwrap_or_else(|payload| Object::Error(crate::panic_message(payload.as_ref())));
+    drop(evaluator);
+
+    let mut output = String::from_utf8_lossy(&captured.0.borrow()).into_owned();
+    if is_error(&result) {
+        output.push_str(&format!("{}\n", result));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    // Lex, parse and evaluate a snippet with the given evaluator in a fresh environment
+    fn run_with(evaluator: &mut Evaluator, input: &str) -> Object {
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+        evaluator.eval(program, &mut Environment::new())
+    }
+
+    // Same as run_with, using a new evaluator
+    fn run(input: &str) -> Object {
+        run_with(&mut Evaluator::new(), input)
+    }
+
+    #[test]
+    fn test_dekhao_multiple_arguments() {
+        let mut parser = Parser::new(Lexer::new(r#"dekhao("x", 1, Ha); dekhao "y", 2"#.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+        assert_eq!(program.len(), 2);
+        match &program[0] {
+            Statement::ExpressionStatement { expression: Expression::Call { arguments, .. } } => {
+                assert_eq!(arguments.len(), 3)
+            }
+            other => panic!("expected dekhao call, got {:?}", other),
+        }
+
+        let values = vec![Object::String("x".to_string()), Object::Integer(1), Object::Boolean(true)];
+        assert_eq!(format_print_args(&values), "x 1 Ha");
+        assert_eq!(run(r#"dekhao("x", 1, Ha)"#), Object::Null);
+    }
+
+    #[test]
+    fn test_dekhao_formats_values_the_same_everywhere() {
+        let input = "
+            dhoro h = {\"a\": 1};
+            dhoro x = h.nei;
+            dekhao(x);
+            dekhao {(x)};
+            dekhao([x, 'c', 1.0], h);
+            dekhao {(x) ([x, 'c', 1.0]) (h)};
+        ";
+        assert_eq!(run_capturing(input), "null\nnull\n[null, c, 1.0] {a: 1}\nnull [null, c, 1.0] {a: 1}\n");
+    }
+
+    #[test]
+    fn test_dekhao_prints_functions_readably() {
+        let input = "
+            dhoro jog = fn(a, b) { ferot a + b; };
+            dekhao(jog);
+            dekhao {jog holo (jog)};
+            dekhao([jog], range);
+        ";
+        assert_eq!(
+            run_capturing(input),
+            "fn(a, b) { ... }\njog holo fn(a, b) { ... }\n[fn(a, b) { ... }] [native builtin function]\n"
+        );
+    }
+
+    #[test]
+    fn test_run_capturing() {
+        assert_eq!(run_capturing(r#"dekhao("x", 1, Ha); dekhao "y", 2.0"#), "x 1 Ha\ny 2.0\n");
+        assert_eq!(run_capturing("dhoro naam = \"B+\";\ndekhao {Shagotom (naam)!}"), "Shagotom B+!\n");
+
+        let input = "
+            protitar jonno (n jekhane [1, 2, 3]) {
+                jodi (n == 2) { choluk; }
+                dekhao(n * n);
+            }
+        ";
+        assert_eq!(run_capturing(input), "1\n9\n");
+
+        // Output before an error, and syntax errors
+        assert_eq!(run_capturing("dekhao(1); dekhao(1 / 0); dekhao(2)"), "1\nError: division by zero\n");
+        assert!(run_capturing("dhoro = 5;").starts_with("Error: "));
+        assert_eq!(run_capturing("1 + 2"), "");
+    }
+
+    #[test]
+    fn test_assert_builtins() {
+        assert_eq!(run_capturing("assert(2 > 1); assert_eq(2 + 3, 5); dekhao(\"ok\")"), "ok\n");
+        assert_eq!(run("assert_eq([1, \"a\"], [1, \"a\"])"), Object::Null);
+
+        assert_eq!(run("assert(1 > 2)"), Object::Error("assertion failed: condition was Na".to_string()));
+        assert_eq!(run("assert(0 > 1, \"khali\")"), Object::Error("assertion failed: khali".to_string()));
+        assert_eq!(run("assert_eq(2 * 2, 5)"), Object::Error("assertion failed: 4 != 5".to_string()));
+        assert_eq!(run("assert_eq(\"1\", 1)"), Object::Error("assertion failed: \"1\" != 1".to_string()));
+
+        // A failed assertion stops the program
+        assert_eq!(run_capturing("assert_eq(1, 2); dekhao(3)"), "Error: assertion failed: 1 != 2\n");
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        let max = i64::MAX;
+        let cases = [
+            (format!("{} * 2", max), format!("integer overflow: {} * 2", max)),
+            (format!("{} + 1", max), format!("integer overflow: {} + 1", max)),
+            (format!("-{} - 2", max), format!("integer overflow: -{} - 2", max)),
+            (format!("(-{} - 1) / -1", max), format!("integer overflow: {} / -1", i64::MIN)),
+            (format!("dhoro x = {}; x *= 3; x", max / 2), format!("integer overflow: {} * 3", max / 2)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(run(&input), Object::Error(expected), "{}", input);
+        }
+        assert_eq!(run(&format!("{} - 1 + 1", max)), Object::Integer(max));
+        assert_eq!(run("3037000499 * 3037000499"), Object::Integer(9_223_372_030_926_249_001));
+    }
+
+    #[test]
+    fn test_structural_equality() {
+        let cases = [
+            ("[1, [2]] == [1, [2]]", true),
+            ("[1, [2]] != [1, [2]]", false),
+            ("[1, [2, 3]] == [1, [2, 4]]", false),
+            ("[1, 2] == [1, 2, 3]", false),
+            ("[1, 2.0] == [1.0, 2]", true),
+            ("[\"a\", 'b'] == [\"a\", \"b\"]", true),
+            ("[1] == 1", false),
+            ("[] != \"\"", true),
+            ("{\"a\": [1], \"b\": 2} == {\"b\": 2, \"a\": [1]}", true),
+            ("{\"a\": 1} == {\"a\": 2}", false),
+            ("{\"a\": {\"b\": [1]}} == {\"a\": {\"b\": [1]}}", true),
+            ("type banao P { x } P([1]) == P([1])", true),
+            ("type banao P { x } type banao Q { x } P(1) == Q(1)", false),
+        ];
+        for (input, expected) in cases {
+            let expected = if expected { "Ha" } else { "Na" };
+            assert_eq!(run(input), Object::String(expected.to_string()), "{}", input);
+        }
+        assert_eq!(run("assert_eq({\"a\": 1, \"b\": 2}, {\"b\": 2, \"a\": 1})"), Object::Null);
+        assert!(run("[1] < [2]").is_error());
+    }
+
+    #[test]
+    fn test_function_arity() {
+        let add = "dhoro jog = fn(a, b) { ferot a + b; };";
+        assert_eq!(run(&format!("{} jog(1, 2)", add)), Object::Integer(3));
+        assert_eq!(
+            run(&format!("{} jog(1)", add)),
+            Object::Error("wrong number of arguments: expected 2, got 1".to_string())
+        );
+        assert_eq!(
+            run(&format!("{} jog(1, 2, 3)", add)),
+            Object::Error("wrong number of arguments: expected 2, got 3".to_string())
+        );
+        assert!(run("dhoro f = fn() { ferot 1; }; f(1)").is_error());
+    }
+
+    #[test]
+    fn test_async_and_await() {
+        let fetch = "dhoro anao = shomoy niropekkho fn(x) { ferot x * 2; };";
+        assert_eq!(run(&format!("{} opekkha koro anao(21)", fetch)), Object::Integer(42));
+        assert_eq!(run(&format!("{} await anao(1) + await anao(2)", fetch)), Object::Integer(6));
+        assert_eq!(run(&format!("{} dhoro f = anao(5); [f, opekkha koro f, opekkha koro f]", fetch)).to_string(), "[[future], 10, 10]");
+        assert_eq!(run("opekkha koro 7"), Object::Integer(7));
+
+        // An error inside the async function surfaces where the future is awaited
+        let failing = "dhoro bhag = shomoy niropekkho fn(n) { ferot n / 0; };";
+        assert_eq!(run(&format!("{} dhoro f = bhag(1); 5", failing)), Object::Integer(5));
+        assert_eq!(
+            run(&format!("{} dhoro f = bhag(1); opekkha koro f; 5", failing)),
+            Object::Error("division by zero".to_string())
+        );
+    }
+
+    #[test]
+    fn test_default_parameters() {
+        let greet = "dhoro shubhecha = fn(naam, shuru = \"Shagotom\", shesh = \"!\") { ferot shuru + \" \" + naam + shesh; };";
+        assert_eq!(run(&format!("{} shubhecha(\"Rahim\")", greet)), Object::String("Shagotom Rahim!".to_string()));
+        assert_eq!(run(&format!("{} shubhecha(\"Rahim\", \"Hello\")", greet)), Object::String("Hello Rahim!".to_string()));
+        assert_eq!(run(&format!("{} shubhecha(\"Rahim\", \"Hi\", \".\")", greet)), Object::String("Hi Rahim.".to_string()));
+        assert_eq!(
+            run(&format!("{} shubhecha()", greet)),
+            Object::Error("wrong number of arguments: expected 1 to 3, got 0".to_string())
+        );
+
+        // Defaults see earlier parameters and the function's closure, and are evaluated on every call
+        assert_eq!(run("dhoro f = fn(a, b = a * 2) { ferot a + b; }; f(5)"), Object::Integer(15));
+        assert_eq!(run("dhoro x = 2; dhoro f = fn(a = x * 3) { ferot a; }; [f(), f(1)]"), Object::Array(vec![Object::Integer(6), Object::Integer(1)]));
+        assert!(run("dhoro f = fn(a = 1 / 0) { ferot a; }; f()").is_error());
+    }
+
+    #[test]
+    fn test_variadic_function() {
+        let sum = "
+            dhoro mot = fn(prothom, ...baki) {
+                dhoro s = prothom;
+                protitar jonno (n jekhane baki) { s = s + n; }
+                ferot s;
+            };
+        ";
+        assert_eq!(run(&format!("{} mot(1, 2, 3, 4)", sum)), Object::Integer(10));
+        assert_eq!(run(&format!("{} mot(5)", sum)), Object::Integer(5));
+        assert_eq!(
+            run(&format!("{} mot()", sum)),
+            Object::Error("wrong number of arguments: expected at least 1, got 0".to_string())
+        );
+        assert_eq!(
+            run("dhoro f = fn(...xs) { ferot xs; }; [f(), f(1, \"a\")]"),
+            Object::Array(vec![Object::Array(vec![]), Object::Array(vec![Object::Integer(1), Object::String("a".to_string())])])
+        );
+        assert_eq!(run("fn(a, ...rest) { ferot a; }").to_string(), "fn(a, ...rest) { ... }");
+    }
+
+    #[test]
+    fn test_member_access() {
+        let input = "
+            dhoro person = {\"naam\": \"Rahim\", \"thikana\": {\"shohor\": \"Dhaka\"}};
+            [person.naam, person.thikana.shohor, person.boyosh]
+        ";
+        assert_eq!(
+            run(input),
+            Object::Array(vec![Object::String("Rahim".to_string()), Object::String("Dhaka".to_string()), Object::Null])
+        );
+        assert!(run("dhoro x = 5; x.naam").is_error());
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        assert_eq!(run("-2.5"), Object::Float(-2.5));
+        assert_eq!(run("--5"), Object::Integer(5));
+        assert_eq!(run("- -5"), Object::Integer(5));
+        assert_eq!(run("dhoro x = 1.5; --(x)"), Object::Float(1.5));
+        assert!(run("-(-9223372036854775807 - 1)").is_error());
+        match run("-\"abc\"") {
+            Object::Error(msg) => assert_eq!(msg, "cannot negate a string value: -abc"),
+            other => panic!("expected an error, got {:?}", other),
+        }
+        assert!(run("-Ha").is_error());
+    }
+
+    #[test]
+    fn test_record_types() {
+        let point = "type banao Point { x, y } dhoro p = Point(3, 4);";
+        assert_eq!(run(&format!("{} p.x * p.y", point)), Object::Integer(12));
+        assert_eq!(run(&format!("{} p[\"y\"]", point)), Object::Integer(4));
+        assert_eq!(run(&format!("{} dhoroner(p)", point)), Object::String("Point".to_string()));
+        assert_eq!(run(&format!("{} typeof(p)", point)), Object::String("Point".to_string()));
+        assert_eq!(run("dhoroner([1])"), Object::String("array".to_string()));
+        assert_eq!(run(&format!("{} p", point)).to_string(), "Point {x: 3, y: 4}");
+
+        match run(&format!("{} p.z", point)) {
+            Object::Error(msg) => assert_eq!(msg, "Point has no field z"),
+            other => panic!("expected an error, got {:?}", other),
+        }
+        match run("type banao Point { x, y } Point(1)") {
+            Object::Error(msg) => assert_eq!(msg, "wrong number of arguments: expected 2, got 1"),
+            other => panic!("expected an error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_conditional_expression() {
+        assert_eq!(run("dhoro x = 5; x > 3 ? \"boro\" : \"choto\""), Object::String("boro".to_string()));
+        assert_eq!(run("1 > 3 ? 1 : 2"), Object::Integer(2));
+        let sign = "dhoro sign = fn(n) { ferot n > 0 ? 1 : n < 0 ? -1 : 0; };";
+        assert_eq!(run(&format!("{} [sign(7), sign(-7), sign(0)]", sign)), Object::Array(vec![Object::Integer(1), Object::Integer(-1), Object::Integer(0)]));
+
+        // Only the branch taken is evaluated
+        assert_eq!(run_capturing("dekhao(Ha ? 1 : 1 / 0); dekhao(Na ? dekhao(\"no\") : 2)"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let input = "
+            dhoro mot = 0;
+            dhoro i = 0;
+            jotokhon (i < 5) {
+                i += 1;
+                mot += i * 2;
+            }
+            mot -= 5;
+            mot *= 2;
+            mot /= 5;
+            mot
+        ";
+        assert_eq!(run(input), Object::Integer(10));
+        assert_eq!(run("dhoro naam = \"B\"; naam += \"+\"; naam"), Object::String("B+".to_string()));
+
+        // A name first bound by plain assignment is immutable
+        match run("x = 1; x += 1; x") {
+            Object::Error(message) => assert!(message.contains("immutable"), "{}", message),
+            other => panic!("expected an error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_power_operator() {
+        assert_eq!(run("2 ** 10"), Object::Integer(1024));
+        assert_eq!(run("2 ** 3 ** 2"), Object::Integer(512));
+        assert_eq!(run("-2 ** 2"), Object::Integer(-4));
+        assert_eq!(run("2 ** -1"), Object::Float(0.5));
+        assert_eq!(run("1 ** -3"), Object::Integer(1));
+        assert_eq!(run("2.0 ** 3"), Object::Float(8.0));
+        assert_eq!(run("4 ** 0.5"), Object::Float(2.0));
+        assert!(run("2 ** 64").is_error());
+        assert!(run("\"a\" ** 2").is_error());
+    }
+
+    #[test]
+    fn test_bengali_digit_literals() {
+        assert_eq!(run("৫ + ৩"), Object::Integer(8));
+        assert_eq!(run("dhoro x = ১০০; x - 1"), Object::Integer(99));
+        assert_eq!(run("২.৫ * 2"), Object::Float(5.0));
+    }
+
+    #[test]
+    fn test_range_builtin() {
+        let ints = |values: &[i64]| Object::Array(values.iter().map(|v| Object::Integer(*v)).collect());
+        assert_eq!(run("range(4)"), ints(&[0, 1, 2, 3]));
+        assert_eq!(run("range(2, 5)"), ints(&[2, 3, 4]));
+        assert_eq!(run("range(0, 10, 3)"), ints(&[0, 3, 6, 9]));
+        assert_eq!(run("range(5, 0, -2)"), ints(&[5, 3, 1]));
+        assert_eq!(run("range(-3)"), ints(&[]));
+        assert_eq!(run("range(3, 3)"), ints(&[]));
+        assert_eq!(run("range(0, 5, -1)"), ints(&[]));
+
+        assert_eq!(run("range(0, 5, 0)"), Object::Error("range() step must not be zero".to_string()));
+        assert!(run("range()").is_error());
+        assert!(run("range(1, 2, 3, 4)").is_error());
+        assert!(run("range(1.5)").is_error());
+
+        assert_eq!(run_capturing("protitar jonno (i jekhane range(1, 4)) { dekhao(i * 10); }"), "10\n20\n30\n");
+    }
+
+    #[test]
+    fn test_number_input_builtins() {
+        // Read from canned input, keeping prompts and retry messages in captured output
+        fn run_with_input(source: &str, input: &str) -> (Object, String) {
+            let captured = CapturedOutput::default();
+            let mut evaluator = Evaluator::new();
+            evaluator.set_output(Box::new(captured.clone()));
+            evaluator.set_input(Box::new(io::Cursor::new(input.to_string())));
+            let result = run_with(&mut evaluator, source);
+            drop(evaluator);
+            let output = String::from_utf8_lossy(&captured.0.borrow()).into_owned();
+            (result, output)
+        }
+
+        let (result, output) = run_with_input("input_int(\"Boyosh: \") + 1", " 41\r\n");
+        assert_eq!((result, output.as_str()), (Object::Integer(42), "Boyosh: "));
+
+        let (result, output) = run_with_input("input_float()", "onek\n2.5\n");
+        assert_eq!(result, Object::Float(2.5));
+        assert_eq!(output, "'onek' number noy, abar cheshta korun\n");
+
+        let (result, output) = run_with_input("input_int(\"> \")", "1.5\n7\n");
+        assert_eq!((result, output.as_str()), (Object::Integer(7), "> '1.5' whole number noy, abar cheshta korun\n> "));
+
+        let (result, _) = run_with_input("input_int()", "x\n");
+        assert!(result.is_error());
+    }
+
+    #[test]
+    fn test_input_reads_from_evaluator_input() {
+        assert_eq!(run_capturing_with_input("dekhao(input_int() * 2)", "42\n"), "84\n");
+        assert_eq!(run_capturing_with_input("dekhao(input(\"Naam: \"))", "  Rahim \n"), "Naam: Rahim\n");
+        assert_eq!(run_capturing_with_input("dekhao([input(), input()])", "ek\n"), "[ek, ]\n");
+
+        // The input builtin bound under another name reads the same source
+        let captured = CapturedOutput::default();
+        let mut evaluator = Evaluator::new();
+        evaluator.set_output(Box::new(captured.clone()));
+        evaluator.set_input(Box::new(io::Cursor::new("dui\n".to_string())));
+        let mut env = Environment::new();
+        env.set("poro".to_string(), Object::BuiltinNative(builtin_input), true);
+        let program = Parser::new(Lexer::new("poro()".to_string())).parse_program();
+        assert_eq!(evaluator.eval(program, &mut env), Object::String("dui".to_string()));
+    }
+
+    #[test]
+    fn test_builtin_print_uses_evaluator_output() {
+        // A host binding the print builtin under another name still gets captured output
+        let captured = CapturedOutput::default();
+        let mut evaluator = Evaluator::new();
+        evaluator.set_output(Box::new(captured.clone()));
+        let mut env = Environment::new();
+        env.set("likho".to_string(), Object::BuiltinNative(builtin_print), true);
+        let program = Parser::new(Lexer::new("likho(\"ek\", 'c', [1])".to_string())).parse_program();
+        assert_eq!(evaluator.eval(program, &mut env), Object::Null);
+        assert_eq!(String::from_utf8_lossy(&captured.0.borrow()), "ek c [1]\n");
+    }
+
+    #[test]
+    fn test_foreach_sums_array() {
+        let input = "
+            dhoro sum = 0;
+            protitar jonno (x jekhane [1, 2, 3, 4]) {
+                sum = sum + x;
+            }
+            sum
+        ";
+        assert_eq!(run(input), Object::Integer(10));
+    }
+
+    #[test]
+    fn test_foreach_string_characters() {
+        let input = r#"
+            dhoro ulta = "";
+            protitar jonno (c jekhane "abc") {
+                ulta = c + ulta;
+            }
+            ulta
+        "#;
+        assert_eq!(run(input), Object::String("cba".to_string()));
+    }
+
+    #[test]
+    fn test_foreach_scope_and_errors() {
+        // The loop variable does not leak out of the loop
+        assert!(run("protitar jonno (x jekhane [1]) { x; } x").is_error());
+
+        match run("protitar jonno (x jekhane 5) { x; }") {
+            Object::Error(msg) => assert!(msg.contains("cannot iterate over integer"), "{}", msg),
+            other => panic!("expected error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_blocks_have_their_own_scope() {
+        let not_found = |name: &str| Object::Error(format!("identifier not found: {}", name));
+
+        // Declarations inside a block end with it
+        assert_eq!(run("jodi (Ha) { dhoro y = 1; } y"), not_found("y"));
+        assert_eq!(run("dhoro i = 0; jotokhon (i < 2) { dhoro d = i; i = i + 1; } d"), not_found("d"));
+        assert_eq!(run("dhoro i = 0; age koro { dhoro d = i; i = i + 1; } jotokhon (i < 2); d"), not_found("d"));
+        assert_eq!(run("protitar jonno (n jekhane [1, 2]) { dhoro d = n; } d"), not_found("d"));
+
+        // A block can shadow an outer variable without changing it
+        assert_eq!(run("dhoro x = 1; jodi (Ha) { dhoro x = 2; x = x + 10; } x"), Object::Integer(1));
+        assert_eq!(run("dhoro x = 1; jodi (Ha) { dhoro x = 2; x }"), Object::Integer(2));
+
+        // ...while assignments still update the outer variables
+        assert_eq!(run("dhoro x = 1; jodi (Ha) { x = 5; } x"), Object::Integer(5));
+        assert_eq!(
+            run("dhoro i = 0; dhoro s = 0; jotokhon (i < 4) { dhoro d = i * 2; s = s + d; i = i + 1; } s"),
+            Object::Integer(12)
+        );
+        assert_eq!(
+            run("dhoro s = 0; protitar jonno (n jekhane [1, 2, 3]) { dhoro d = n * n; s = s + d; } s"),
+            Object::Integer(14)
+        );
+
+        // Functions defined in a block see the block's variables
+        assert_eq!(
+            run("dhoro f = 0; jodi (Ha) { dhoro n = 7; f = fn() { n * 2 }; } f()"),
+            Object::Integer(14)
+        );
+    }
+
+    #[test]
+    fn test_closures_share_their_environment() {
+        // A function can call itself by the name it is bound to
+        let fact = "dhoro fact = fn(n) { jodi (n < 2) { ferot 1; } ferot n * fact(n - 1); }; fact(10)";
+        assert_eq!(run(fact), Object::Integer(3628800));
+
+        // Updates a closure makes to captured variables stick between calls
+        let counter = "
+            dhoro ginti = 0;
+            dhoro barao = fn() { ginti = ginti + 1; ferot ginti; };
+            barao();
+            barao();
+            [barao(), ginti]
+        ";
+        assert_eq!(run(counter), Object::Array(vec![Object::Integer(3), Object::Integer(3)]));
+
+        // Parameters and locals stay inside the call, and each call gets its own scope
+        let scopes = "
+            dhoro x = 1;
+            dhoro f = fn(x) { dhoro y = x * 2; ferot y; };
+            dhoro banao = fn(i) { fn() { i } };
+            dhoro ek = banao(1);
+            dhoro dui = banao(2);
+            [f(5), x, ek(), dui()]
+        ";
+        let expected = [10, 1, 1, 2].map(Object::Integer).to_vec();
+        assert_eq!(run(scopes), Object::Array(expected));
+        assert!(run("dhoro f = fn() { dhoro y = 1; }; f(); y").is_error());
+    }
+
+    #[test]
+    fn test_negation_follows_truthiness() {
+        let cases = [
+            ("!5", "Na"),
+            ("!0", "Ha"),
+            ("!-1", "Na"),
+            ("!0.0", "Ha"),
+            ("!\"\"", "Ha"),
+            ("!\"kichu\"", "Na"),
+            ("!\"Na\"", "Ha"),
+            ("![]", "Ha"),
+            ("![0]", "Na"),
+            ("!{}", "Ha"),
+            ("!!7", "Ha"),
+            ("noy Ha", "Na"),
+            ("noy 0", "Ha"),
+            ("NOY \"\"", "Ha"),
+            ("dhoro x = 2; noy (x > 1)", "Na"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(run(input), Object::String(expected.to_string()), "{}", input);
+        }
+
+        // Conditions use the same rule
+        assert_eq!(run("jodi (0) { 1 } nahoy { 2 }"), Object::Integer(2));
+        assert_eq!(run("jodi (\"a\") { 1 } nahoy { 2 }"), Object::Integer(1));
+        assert_eq!(run("jodi (noy []) { 1 } nahoy { 2 }"), Object::Integer(1));
+    }
+
+    #[test]
+    fn test_logical_operators() {
+        let cases = [
+            ("dhoro x = 3; jodi (x > 1 && x < 5) { 1 } nahoy { 2 }", Object::Integer(1)),
+            ("dhoro x = 3; jodi (x < 1 || x > 5) { 1 } nahoy { 2 }", Object::Integer(2)),
+            ("1 == 1 && 2 == 2", Object::String("Ha".to_string())),
+            ("Na ebong Ha othoba Ha", Object::String("Ha".to_string())),
+            ("Ha and Na", Object::String("Na".to_string())),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(run(input), expected, "{}", input);
+        }
+
+        // The right side only runs when the left side doesn't decide the result
+        assert_eq!(run("Na && ojana"), Object::String("Na".to_string()));
+        assert_eq!(run("Ha || 1 / 0"), Object::String("Ha".to_string()));
+        assert!(run("Ha && ojana").is_error());
+    }
+
+    #[test]
+    fn test_do_while_runs_body_once() {
+        let input = "
+            dhoro count = 0;
+            age koro {
+                count = count + 1;
+            } jotokhon (Na);
+            count
+        ";
+        assert_eq!(run(input), Object::Integer(1));
+    }
+
+    #[test]
+    fn test_do_while_loops_until_condition_fails() {
+        let input = "
+            dhoro i = 0;
+            age koro {
+                i = i + 1;
+            } jotokhon (i < 5)
+            i
+        ";
+        assert_eq!(run(input), Object::Integer(5));
+    }
+
+    #[test]
+    fn test_loops_honor_break_and_continue() {
+        let input = "
+            dhoro i = 0;
+            dhoro sum = 0;
+            age koro {
+                i = i + 1;
+                jodi (i == 2) { choluk; }
+                jodi (i == 4) { thamo; }
+                sum = sum + i;
+            } jotokhon (Ha)
+            sum
+        ";
+        assert_eq!(run(input), Object::Integer(4));
+
+        let input = "
+            dhoro i = 0;
+            jotokhon (i < 10) {
+                i = i + 1;
+                jodi (i == 3) { thamo; }
+            }
+            i
+        ";
+        assert_eq!(run(input), Object::Integer(3));
+
+        assert!(run("thamo;").is_error());
+    }
+
+    #[test]
+    fn test_labeled_break_and_continue() {
+        // Without a label thamo only leaves the inner loop
+        let input = "
+            dhoro count = 0;
+            protitar jonno (i jekhane [1, 2, 3]) {
+                protitar jonno (j jekhane [1, 2, 3]) {
+                    jodi (j == 2) { thamo; }
+                    count = count + 1;
+                }
+            }
+            count
+        ";
+        assert_eq!(run(input), Object::Integer(3));
+
+        let input = "
+            dhoro found = 0;
+            bahir: protitar jonno (i jekhane [1, 2, 3]) {
+                protitar jonno (j jekhane [1, 2, 3]) {
+                    jodi (i * j == 4) { found = i * 10 + j; thamo bahir; }
+                }
+            }
+            found
+        ";
+        assert_eq!(run(input), Object::Integer(22));
+
+        let input = "
+            dhoro count = 0;
+            dhoro i = 0;
+            bahir: jotokhon (i < 3) {
+                i = i + 1;
+                protitar jonno (j jekhane [1, 2, 3]) {
+                    jodi (j == 2) { choluk bahir; }
+                    count = count + 1;
+                }
+            }
+            count
+        ";
+        assert_eq!(run(input), Object::Integer(3));
+
+        assert_eq!(
+            run("jotokhon (Ha) { thamo bahir; }"),
+            Object::Error("'thamo bahir' used outside of a loop labeled 'bahir'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_string_comparison() {
+        assert_eq!(run(r#""apple" < "banana""#), Object::String("Ha".to_string()));
+        assert_eq!(run(r#""apple" > "banana""#), Object::String("Na".to_string()));
+        assert_eq!(run(r#""kola" == "kola""#), Object::String("Ha".to_string()));
+        assert_eq!(run(r#""kola" != "kola""#), Object::String("Na".to_string()));
+        assert_eq!(run(r#""b" >= "b""#), Object::String("Ha".to_string()));
+        assert_eq!(run(r#""a" <= "B""#), Object::String("Na".to_string()));
+        assert_eq!(run(r#""ab" + "cd""#), Object::String("abcd".to_string()));
+        assert!(run(r#""ab" - "cd""#).is_error());
+    }
+
+    #[test]
+    fn test_string_repetition() {
+        assert_eq!(run(r#""ab" * 3"#), Object::String("ababab".to_string()));
+        assert_eq!(run(r#"2 * "-""#), Object::String("--".to_string()));
+        assert_eq!(run(r#""ab" * 0"#), Object::String("".to_string()));
+        assert!(run(r#""ab" * -1"#).is_error());
+        assert!(run(r#""ab" + 1"#).is_error());
+        assert_eq!(run(r#""" * 9223372036854775807"#), Object::String("".to_string()));
+        assert!(run(r#""ab" * 9999999999999999"#).is_error());
+        assert!(run(r#""ab" * 9223372036854775807"#).is_error());
+    }
+
+    #[test]
+    fn test_char_literals() {
+        assert_eq!(run("'a'"), Object::Char('a'));
+        assert_eq!(run(r"'\n'"), Object::Char('\n'));
+        assert_eq!(run(r"'\''"), Object::Char('\''));
+        assert_eq!(format!("{}", run("'z'")), "z");
+    }
+
+    #[test]
+    fn test_char_operations() {
+        assert_eq!(run("'a' < 'b'"), Object::String("Ha".to_string()));
+        assert_eq!(run("'a' == 'a'"), Object::String("Ha".to_string()));
+        assert_eq!(run("'b' >= 'c'"), Object::String("Na".to_string()));
+        assert_eq!(run("'a' + 'b'"), Object::String("ab".to_string()));
+        assert_eq!(run(r#"'x' + "yz""#), Object::String("xyz".to_string()));
+        assert_eq!(run(r#""xy" + 'z'"#), Object::String("xyz".to_string()));
+        assert_eq!(run(r#""abc"[1]"#), Object::Char('b'));
+        assert_eq!(run(r#""abc"[0] == "a""#), Object::String("Ha".to_string()));
+        assert!(run(r#""abc"[3]"#).is_error());
+    }
+
+    #[test]
+    fn test_float_arithmetic() {
+        assert_eq!(run("1.5 + 2.25"), Object::Float(3.75));
+        assert_eq!(run("1 + 0.5"), Object::Float(1.5));
+        assert_eq!(run("7.0 / 2"), Object::Float(3.5));
+        assert_eq!(run("2.5e1 - 5"), Object::Float(20.0));
+        assert_eq!(run("1e3+2"), Object::Float(1002.0));
+        assert_eq!(run("0.5 < 1"), Object::String("Ha".to_string()));
+        assert_eq!(format!("{}", run("2.0 * 3")), "6.0");
+        assert!(run("1.0 / 0").is_error());
+        assert!(run("1 / 0").is_error());
+    }
+
+    #[test]
+    fn test_script_args() {
+        let mut env = Environment::new();
+        env.set_script_args(vec!["prothom".to_string(), "42".to_string()]);
+        let program = Parser::new(Lexer::new("args()".to_string())).parse_program();
+        assert_eq!(
+            Evaluator::new().eval(program, &mut env),
+            Object::Array(vec![Object::String("prothom".to_string()), Object::String("42".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_error_span() {
+        let mut evaluator = Evaluator::new();
+        let error = run_with(&mut evaluator, "dhoro x = 1;\ndhoro y = x + 2;\ndekhao(y / (x - 1));");
+        assert_eq!(error, Object::Error("division by zero".to_string()));
+        let span = evaluator.error_span(&error).unwrap();
+        assert_eq!((span.line, span.column), (3, 10));
+
+        // The same message raised by later input gets its own position
+        let error = run_with(&mut evaluator, "dekhao(1); dekhao(2); dekhao(10 / 0)");
+        let span = evaluator.error_span(&error).unwrap();
+        assert_eq!((span.line, span.column), (1, 33));
+
+        let error = run_with(&mut evaluator, "dhoro f = fn(n) {\n    ferot n + ojana;\n};\nf(1)");
+        let span = evaluator.error_span(&error).unwrap();
+        assert_eq!((span.line, span.column), (2, 15));
+
+        // Only the error that was recorded has a position, and only by the evaluator that raised it
+        assert_eq!(evaluator.error_span(&Object::Error("onno kichu".to_string())), None);
+        assert_eq!(Evaluator::new().error_span(&error), None);
+    }
+
+    #[test]
+    fn test_runaway_recursion_is_an_error() {
+        // The default limit needs more stack than a test thread has by default
+        let worker = std::thread::Builder::new()
+            .stack_size(256 * 1024 * 1024)
+            .spawn(|| {
+                let mut evaluator = Evaluator::new();
+                let error = run_with(&mut evaluator, "dhoro f = fn(g, n) { ferot g(g, n + 1); };\nf(f, 0)");
+                assert_eq!(
+                    error,
+                    Object::Error(format!(
+                        "{}: maximum call depth of {} exceeded",
+                        STACK_OVERFLOW_ERROR, DEFAULT_MAX_CALL_DEPTH
+                    ))
+                );
+
+                // Bounded recursion under the limit still works, and the depth was unwound
+                let countdown = "dhoro f = fn(g, n) { jodi (n == 0) { ferot 0; } ferot 1 + g(g, n - 1); };\nf(f, 900)";
+                assert_eq!(evaluator.call_depth, 0);
+                assert_eq!(run_with(&mut evaluator, countdown), Object::Integer(900));
+
+                evaluator.set_max_call_depth(10);
+                let error = run_with(&mut evaluator, countdown);
+                assert!(matches!(error, Object::Error(msg) if msg.contains("maximum call depth of 10")));
+            })
+            .unwrap();
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn test_evaluator_runs_existing_programs() {
+        let input = "
+            dhoro jog = fn(a, b) { ferot a + b; };
+            dhoro dbl = fn(f, x) { f(x, x) };
+            dhoro total = 0;
+            dhoro i = 0;
+            jotokhon (i < 4) {
+                i = i + 1;
+                jodi (i == 2) { choluk; }
+                total = jog(total, dbl(jog, i));
+            }
+            total
+        ";
+        assert_eq!(run(input), Object::Integer(16));
+        assert_eq!(run("5 > 3"), Object::String("Ha".to_string()));
+        assert_eq!(run("jodi (1 > 2) { 10 } nahoy { 20 }"), Object::Integer(20));
+        assert_eq!(run("ojana"), Object::Error("identifier not found: ojana".to_string()));
+    }
+
+    #[test]
+    fn test_evaluator_is_reusable() {
+        // Like the REPL: one evaluator, one environment, several programs
+        let mut evaluator = Evaluator::new();
+        let mut env = Environment::new();
+        for (input, expected) in [
+            ("dhoro x = 2;", Object::Null),
+            ("x = x * 21;", Object::Integer(42)),
+            ("x / 0", Object::Error("division by zero".to_string())),
+            ("x", Object::Integer(42)),
+        ] {
+            let program = Parser::new(Lexer::new(input.to_string())).parse_program();
+            assert_eq!(evaluator.eval(program, &mut env), expected, "{}", input);
+        }
+        assert_eq!(evaluator.call_depth, 0);
+    }
+
+    #[test]
+    fn test_map_filter_reduce() {
+        let mut env = Environment::new();
+        collections::load_collection_functions(&mut env);
+        let mut run_in_env = |input: &str| {
+            let program = Parser::new(Lexer::new(input.to_string())).parse_program();
+            Evaluator::new().eval(program, &mut env)
+        };
+        let ints = |values: &[i64]| Object::Array(values.iter().map(|v| Object::Integer(*v)).collect());
+
+        assert_eq!(run_in_env("map([1, 2, 3], fn(x) { x * 2 })"), ints(&[2, 4, 6]));
+        assert_eq!(run_in_env("filter([1, 2, 3, 4, 5, 6], fn(x) { x / 2 * 2 == x })"), ints(&[2, 4, 6]));
+        assert_eq!(run_in_env("reduce([1, 2, 3, 4], fn(acc, x) { acc + x }, 0)"), Object::Integer(10));
+
+        // Closures, builtins as the function, and errors raised inside the callback
+        assert_eq!(run_in_env("dhoro n = 10; map([1, 2], fn(x) { x + n })"), ints(&[11, 12]));
+        assert_eq!(
+            run_in_env("map([[1], [2, 3]], pop)"),
+            Object::Array(vec![ints(&[]), ints(&[2])])
+        );
+        assert_eq!(run_in_env("reduce([], fn(acc, x) { acc + x }, 7)"), Object::Integer(7));
+        assert_eq!(
+            run_in_env("map([1, 0], fn(x) { 1 / x })"),
+            Object::Error("division by zero".to_string())
+        );
+        assert!(run_in_env("map(5, fn(x) { x })").is_error());
+    }
+
+    #[test]
+    fn test_sort_with_comparator() {
+        let mut env = Environment::new();
+        collections::load_collection_functions(&mut env);
+        let mut run_in_env = |input: &str| {
+            let program = Parser::new(Lexer::new(input.to_string())).parse_program();
+            Evaluator::new().eval(program, &mut env)
+        };
+        let ints = |values: &[i64]| Object::Array(values.iter().map(|v| Object::Integer(*v)).collect());
+
+        assert_eq!(run_in_env("sort([3, 1, 2])"), ints(&[1, 2, 3]));
+        assert_eq!(run_in_env("sort([3, 1, 2], fn(a, b) { b - a })"), ints(&[3, 2, 1]));
+        assert_eq!(
+            run_in_env(r#"sort(["kola", "am", "jam"], fn(a, b) { jodi (a > b) { -1 } nahoy { 1 } })"#),
+            Object::Array(vec![
+                Object::String("kola".to_string()),
+                Object::String("jam".to_string()),
+                Object::String("am".to_string()),
+            ])
+        );
+
+        assert!(run_in_env("sort([1, 2], fn(a, b) { a < b })").is_error());
+        assert_eq!(
+            run_in_env("sort([1, 2], fn(a, b) { a / 0 })"),
+            Object::Error("division by zero".to_string())
+        );
+    }
+
+    // Writes the given files into a fresh temp directory and returns its path
+    fn module_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bplus_modules_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("lib")).unwrap();
+        for (file, source) in files {
+            fs::write(dir.join(file), source).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_import_module() {
+        let dir = module_dir(
+            "import",
+            &[
+                ("lib/gonit.bp", "dhoro jog = fn(a, b) { ferot a + b; };\ndhoro PI = 3.14;\ndekhao(\"gonit loaded\")"),
+                ("main.bp", "import koro \"lib/gonit.bp\" ei hisebe gonit;\ngonit[\"jog\"](2, 3)"),
+                ("bhul.bp", "dhoro x = 1;\nx / 0"),
+            ],
+        );
+        let run_file = |file: &str| {
+            let captured = CapturedOutput::default();
+            let mut evaluator = Evaluator::new();
+            evaluator.set_output(Box::new(captured.clone()));
+            evaluator.set_script_path(&dir.join(file));
+            let source = fs::read_to_string(dir.join(file)).unwrap();
+            let program = Parser::new(Lexer::new(source)).parse_program();
+            let result = evaluator.eval(program, &mut Environment::new());
+            let output = String::from_utf8_lossy(&captured.0.borrow()).into_owned();
+            (result, output)
+        };
+
+        // The module runs once, in its own environment, and its bindings are reachable through the alias
+        assert_eq!(run_file("main.bp"), (Object::Integer(5), "gonit loaded\n".to_string()));
+
+        let mut env = Environment::new();
+        let mut evaluator = Evaluator::new();
+        evaluator.set_script_path(&dir.join("main.bp"));
+        let program = Parser::new(Lexer::new("import koro \"lib/gonit.bp\" ei hisebe g;".to_string())).parse_program();
+        evaluator.set_output(Box::new(CapturedOutput::default()));
+        evaluator.eval(program, &mut env);
+        match env.get("g") {
+            Some(Object::Hash(pairs)) => {
+                let names: Vec<String> = pairs.iter().map(|(k, _)| k.to_string()).collect();
+                assert_eq!(names, vec!["PI", "jog"]);
+            }
+            other => panic!("expected module hash, got {:?}", other),
+        }
+
+        // Errors inside the module name the module and their position in it
+        fs::write(dir.join("main.bp"), "import koro \"bhul.bp\" ei hisebe b;").unwrap();
+        assert_eq!(run_file("main.bp").0, Object::Error("bhul.bp:2:3: division by zero".to_string()));
+
+        fs::write(dir.join("main.bp"), "import koro \"nei.bp\" ei hisebe n;").unwrap();
+        assert!(matches!(run_file("main.bp").0, Object::Error(msg) if msg.starts_with("import koro: cannot open 'nei.bp'")));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_imported_modules_use_keyword_aliases() {
+        let dir = module_dir(
+            "aliases",
+            &[("lib/saat.bp", "let f = fn() { return 7 };"), ("main.bp", "import koro \"lib/saat.bp\" ei hisebe m;\nm.f()")],
+        );
+        let aliases = HashMap::from([("let".to_string(), "dhoro".to_string())]);
+        let run_main = |aliases: HashMap<String, String>| {
+            let mut evaluator = Evaluator::new();
+            evaluator.set_script_path(&dir.join("main.bp"));
+            evaluator.set_keyword_aliases(aliases.clone());
+            let source = fs::read_to_string(dir.join("main.bp")).unwrap();
+            let program = Parser::new(Lexer::new(source).with_keyword_aliases(aliases)).parse_program();
+            evaluator.eval(program, &mut Environment::new())
+        };
+        assert_eq!(run_main(aliases), Object::Integer(7));
+        assert!(run_main(HashMap::new()).is_error());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cyclic_import_is_an_error() {
+        let dir = module_dir(
+            "cycle",
+            &[
+                ("a.bp", "import koro \"lib/b.bp\" ei hisebe b;"),
+                ("lib/b.bp", "import koro \"../a.bp\" ei hisebe a;"),
+            ],
+        );
+        let mut evaluator = Evaluator::new();
+        evaluator.set_script_path(&dir.join("a.bp"));
+        let program = Parser::new(Lexer::new(fs::read_to_string(dir.join("a.bp")).unwrap())).parse_program();
+        let result = evaluator.eval(program, &mut Environment::new());
+        assert_eq!(
+            result,
+            Object::Error("lib/b.bp: import koro: cyclic import a.bp -> b.bp -> a.bp".to_string())
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_hash_literal() {
+        let result = run(r#"{"naam": "Rahim", 2: 10 + 5, "naam": "Karim"}"#);
+        assert_eq!(
+            result,
+            Object::Hash(vec![
+                (Object::String("naam".to_string()), Object::String("Karim".to_string())),
+                (Object::Integer(2), Object::Integer(15)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_hash_index() {
+        assert_eq!(run(r#"dhoro h = {"a": 1, 2: "dui"}; h["a"]"#), Object::Integer(1));
+        assert_eq!(run(r#"dhoro h = {"a": 1, 2: "dui"}; h[2]"#), Object::String("dui".to_string()));
+        assert_eq!(run(r#"dhoro h = {"a": 1}; h["nei"]"#), Object::Null);
+        assert!(run(r#"{"a": 1}[{}]"#).is_error());
+    }
+
+    #[test]
+    fn test_hash_display() {
+        assert_eq!(format!("{}", run(r#"{"a": 1, 2: Ha}"#)), "{a: 1, 2: Ha}");
+        assert_eq!(format!("{}", run("{}")), "{}");
+    }
+}
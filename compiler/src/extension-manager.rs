@@ -1,10 +1,14 @@
 // compiler/src/extension-manager.rs
 
 use crate::error::{ErrorManager, LanguagePack};
+use crate::token::{lookup_ident, TokenType};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+// Language packs compiled into B+, available without any file on disk
+const BUILTIN_LANGUAGE_PACKS: &[&str] = &["english", "bangla-unicode", "hindi"];
+
 pub struct ExtensionManager {
     language_packs: HashMap<String, LanguagePack>, // Stores loaded language packs by name
     active_language_pack: Option<String>,          // Currently active language pack name
@@ -133,19 +137,22 @@ auto_typecast = { enabled = false, priority = 1 }
         Ok(())
     }
     
-    // Load a compiled language pack from a .bplp file (placeholder implementation)
+    // Load a compiled language pack from a .bplp file
     fn load_compiled_language_pack(&self, path: &Path) -> Result<LanguagePack, String> {
-        // Read the file content as text
-        let content = fs::read_to_string(path)
+        let bytes = fs::read(path)
             .map_err(|e| format!("Failed to read language pack file: {}", e))?;
         
-        // Check for expected header in compiled file
-        if content.starts_with("// Compiled Binery File for B Plus Language") {
-            // Return a default English language pack for now
-            Ok(self.create_english_language_pack())
-        } else {
-            Err("Invalid language pack format".to_string())
-        }
+        decode_language_pack(&bytes)
+    }
+    
+    // Compile a language pack source (.bplpsrc) into a compiled .bplp file
+    pub fn compile_language_pack(&self, source_path: &Path, output_path: &Path) -> Result<LanguagePack, String> {
+        let pack = self.compile_and_load_language_pack(source_path)?;
+        
+        fs::write(output_path, encode_language_pack(&pack))
+            .map_err(|e| format!("Failed to write compiled language pack: {}", e))?;
+        
+        Ok(pack)
     }
     
     // Compile and load a language pack from source (.bplpsrc)
@@ -180,6 +187,16 @@ auto_typecast = { enabled = false, priority = 1 }
                 continue;
             }
             
+            // Parse keyword mappings formatted like "jodi => if"
+            if current_section == "mapping" {
+                if let Some(arrow_pos) = line.find("=>") {
+                    let from_key = line[..arrow_pos].trim().to_string();
+                    let to_key = line[arrow_pos+2..].trim().to_string();
+                    keyword_mappings.insert(from_key, to_key);
+                }
+                continue;
+            }
+            
             // Parse key=value pairs inside sections
             if let Some(eq_pos) = line.find('=') {
                 let key = line[..eq_pos].trim();
@@ -194,14 +211,6 @@ auto_typecast = { enabled = false, priority = 1 }
                             _ => {}
                         }
                     }
-                    "mapping" => {
-                        // Parse keyword mappings formatted like "jodi => if"
-                        if let Some(arrow_pos) = value.find("=>") {
-                            let from_key = value[..arrow_pos].trim().to_string();
-                            let to_key = value[arrow_pos+2..].trim().to_string();
-                            keyword_mappings.insert(from_key, to_key);
-                        }
-                    }
                     "error_messages" => {
                         // Parse error message templates keyed by error code
                         error_templates.insert(key.to_string(), value.to_string());
@@ -230,18 +239,27 @@ auto_typecast = { enabled = false, priority = 1 }
     fn get_english_error_templates(&self) -> HashMap<String, String> {
         let mut templates = HashMap::new();
         
-        templates.insert("unexpected_character".to_string(), 
-            "Unexpected character '{0}' found".to_string());
-        templates.insert("unterminated_string".to_string(), 
-            "Unterminated string - missing quote mark".to_string());
-        templates.insert("type_mismatch".to_string(), 
-            "Type mismatch - expected '{0}' but got '{1}'".to_string());
-        templates.insert("undefined_variable".to_string(), 
-            "Undefined variable '{0}' - declare it first".to_string());
-        templates.insert("division_by_zero".to_string(), 
-            "Cannot divide by zero".to_string());
-        // More templates can be added here as needed
-        
+        templates.insert("unexpected_character".to_string(), "Unexpected character '{0}' found".to_string());
+        templates.insert("unterminated_string".to_string(), "Unterminated string - missing quote mark".to_string());
+        templates.insert("unterminated_comment".to_string(), "Unterminated comment - missing closing mark".to_string());
+        templates.insert("invalid_number".to_string(), "Invalid number '{0}'".to_string());
+        templates.insert("unexpected_token".to_string(), "Expected '{1}' but found '{0}'".to_string());
+        templates.insert("missing_token".to_string(), "Missing token '{0}' - please add it".to_string());
+        templates.insert("invalid_expression".to_string(), "Invalid expression: {0}".to_string());
+        templates.insert("invalid_statement".to_string(), "Invalid statement: {0}".to_string());
+        templates.insert("type_mismatch".to_string(), "Type mismatch - expected '{0}' but got '{1}'".to_string());
+        templates.insert("undefined_variable".to_string(), "Undefined variable '{0}' - declare it first".to_string());
+        templates.insert("undefined_function".to_string(), "Undefined function '{0}'".to_string());
+        templates.insert("wrong_argument_count".to_string(), "Wrong number of arguments - expected {0}, got {1}".to_string());
+        templates.insert("division_by_zero".to_string(), "Cannot divide by zero".to_string());
+        templates.insert("index_out_of_bounds".to_string(), "Index {0} out of bounds (maximum {1})".to_string());
+        templates.insert("file_not_found".to_string(), "File '{0}' not found".to_string());
+        templates.insert("permission_denied".to_string(), "Permission denied for '{0}'".to_string());
+        templates.insert("network_error".to_string(), "Network error: {0}".to_string());
+        templates.insert("out_of_memory".to_string(), "Out of memory".to_string());
+        templates.insert("stack_overflow".to_string(), "Stack overflow - too many recursive calls".to_string());
+        templates.insert("internal_error".to_string(), "Internal error: {0}".to_string());
+
         templates
     }
     
@@ -274,7 +292,7 @@ auto_typecast = { enabled = false, priority = 1 }
             if in_language_packs && line.contains("enabled = true") {
                 if let Some(eq_pos) = line.find('=') {
                     let pack_name = line[..eq_pos].trim();
-                    if self.language_packs.contains_key(pack_name) {
+                    if self.available_language_packs().iter().any(|name| name == pack_name) {
                         // Activate the language pack and stop searching
                         self.activate_language_pack(pack_name)?;
                         break;
@@ -286,12 +304,40 @@ auto_typecast = { enabled = false, priority = 1 }
         Ok(())
     }
     
-    // Activate a language pack by name, updating error manager and state
+    // Names of all packs that can be activated: built-in ones and those loaded from disk
+    pub fn available_language_packs(&self) -> Vec<String> {
+        let mut names: Vec<String> = BUILTIN_LANGUAGE_PACKS.iter().map(|name| name.to_string()).collect();
+        names.extend(self.language_packs.keys().cloned());
+        names.sort();
+        names.dedup();
+        names
+    }
+    
+    // Build one of the built-in language packs by name
+    fn create_builtin_language_pack(&self, pack_name: &str) -> Option<LanguagePack> {
+        match pack_name {
+            "english" => Some(self.create_english_language_pack()),
+            "bangla-unicode" => Some(self.create_bangla_unicode_language_pack()),
+            "hindi" => Some(self.create_hindi_language_pack()),
+            _ => None,
+        }
+    }
+    
+    // Activate a language pack by name, updating error manager and state.
+    // A pack loaded from disk takes precedence over the built-in pack of the same name.
     pub fn activate_language_pack(&mut self, pack_name: &str) -> Result<(), String> {
+        if !self.language_packs.contains_key(pack_name) {
+            if let Some(pack) = self.create_builtin_language_pack(pack_name) {
+                self.language_packs.insert(pack_name.to_string(), pack);
+            }
+        }
+        
         if let Some(pack) = self.language_packs.get(pack_name) {
             self.active_language_pack = Some(pack_name.to_string());
-            self.error_manager = ErrorManager::with_language_pack(pack);
-            println!("Activated language pack: {}", pack_name);
+            // Keep the color and JSON settings made on the command line
+            self.error_manager.set_language_pack(pack);
+            // Logged rather than printed, so --ast and -e output stays just the program's
+            log::info!("Activated language pack: {}", pack_name);
             Ok(())
         } else {
             Err(format!("Language pack '{}' not found", pack_name))
@@ -303,6 +349,11 @@ auto_typecast = { enabled = false, priority = 1 }
         &self.error_manager
     }
     
+    // Get mutable reference to current error manager, e.g. to change its output format
+    pub fn get_error_manager_mut(&mut self) -> &mut ErrorManager {
+        &mut self.error_manager
+    }
+    
     // Get currently active language pack if any
     pub fn get_active_language_pack(&self) -> Option<&LanguagePack> {
         if let Some(ref pack_name) = self.active_language_pack {
@@ -330,6 +381,25 @@ auto_typecast = { enabled = false, priority = 1 }
         keyword.to_string()
     }
     
+    // Words of the active language pack that stand for B+ keywords, ready for the lexer.
+    // Packs map in either direction ("যদি => jodi" or "jodi => if"), so each pair is
+    // turned around as needed; pairs where both or neither side is a keyword are left out.
+    pub fn keyword_aliases(&self) -> HashMap<String, String> {
+        let mut aliases = HashMap::new();
+        if let Some(pack) = self.get_active_language_pack() {
+            for (from, to) in &pack.keyword_mappings {
+                let from_is_keyword = lookup_ident(from) != TokenType::Ident;
+                let to_is_keyword = lookup_ident(to) != TokenType::Ident;
+                if to_is_keyword && !from_is_keyword {
+                    aliases.insert(from.clone(), to.clone());
+                } else if from_is_keyword && !to_is_keyword {
+                    aliases.insert(to.clone(), from.clone());
+                }
+            }
+        }
+        aliases
+    }
+
     // Check if a keyword is valid under the active language pack
     pub fn is_valid_keyword(&self, keyword: &str) -> bool {
         if let Some(pack) = self.get_active_language_pack() {
@@ -391,6 +461,172 @@ auto_typecast = { enabled = false, priority = 1 }
         }
     }
     
+    // Create the Bengali-script language pack: keywords written in Bengali map to B+ keywords
+    fn create_bangla_unicode_language_pack(&self) -> LanguagePack {
+        let mut keyword_mappings = HashMap::new();
+
+        // Bengali keywords and the B+ keywords they stand for
+        keyword_mappings.insert("যদি".to_string(), "jodi".to_string());
+        keyword_mappings.insert("তাহলে".to_string(), "tahole".to_string());
+        keyword_mappings.insert("নাহয়".to_string(), "nahoy".to_string());
+        keyword_mappings.insert("ধরো".to_string(), "dhoro".to_string());
+        keyword_mappings.insert("কাজ".to_string(), "kaj".to_string());
+        keyword_mappings.insert("দেখাও".to_string(), "dekhao".to_string());
+        keyword_mappings.insert("ফেরত".to_string(), "ferot".to_string());
+        keyword_mappings.insert("হ্যাঁ".to_string(), "ha".to_string());
+        keyword_mappings.insert("না".to_string(), "na".to_string());
+        keyword_mappings.insert("এবং".to_string(), "ebong".to_string());
+        keyword_mappings.insert("অথবা".to_string(), "othoba".to_string());
+        keyword_mappings.insert("যতক্ষণ".to_string(), "jotokhon".to_string());
+        keyword_mappings.insert("আগে করো".to_string(), "age koro".to_string());
+        keyword_mappings.insert("প্রতিটার জন্য".to_string(), "protitar jonno".to_string());
+        keyword_mappings.insert("যেখানে".to_string(), "jekhane".to_string());
+        keyword_mappings.insert("থামো".to_string(), "thamo".to_string());
+        keyword_mappings.insert("চলুক".to_string(), "choluk".to_string());
+        keyword_mappings.insert("ইনপুট".to_string(), "input".to_string());
+
+        // UI messages in Bengali
+        keyword_mappings.insert("welcome_message".to_string(),
+            "বি+ এ আপনাকে স্বাগতম! বাংলা ইউনিকোড ভাষা প্যাক চালু আছে।".to_string());
+        keyword_mappings.insert("example_usage".to_string(),
+            "চেষ্টা করুন: যদি (10 > 5) { দেখাও(\"10 is greater than 5!\") }".to_string());
+        keyword_mappings.insert("extension_init_error".to_string(),
+            "এক্সটেনশন সিস্টেম চালু করতে সমস্যা".to_string());
+        keyword_mappings.insert("fallback_mode".to_string(),
+            "ডিফল্ট বাংলিশ মোডে চলছে...".to_string());
+        keyword_mappings.insert("repl_start".to_string(),
+            "REPL মোড শুরু হলো। বের হতে 'prosthan' লিখুন।".to_string());
+        keyword_mappings.insert("langpack_activated".to_string(),
+            "ভাষা প্যাক '{0}' চালু করা হলো".to_string());
+        keyword_mappings.insert("langpack_error".to_string(),
+            "ভাষা প্যাক চালু করা যায়নি: {0}".to_string());
+        keyword_mappings.insert("langpack_usage".to_string(),
+            "ব্যবহার: langpack <নাম>\nউদাহরণ: langpack bangla-unicode".to_string());
+        keyword_mappings.insert("available_packs".to_string(),
+            "উপলব্ধ ভাষা প্যাক:".to_string());
+        keyword_mappings.insert("goodbye_message".to_string(),
+            "বিদায়! বি+ ব্যবহার করার জন্য ধন্যবাদ!".to_string());
+
+        LanguagePack {
+            language: "Bengali Unicode".to_string(),
+            version: "1.0".to_string(),
+            author: "B+ Language Team".to_string(),
+            keyword_mappings,
+            error_templates: self.get_bengali_error_templates(),
+        }
+    }
+
+    // Bengali error message templates, one per error code
+    fn get_bengali_error_templates(&self) -> HashMap<String, String> {
+        let mut templates = HashMap::new();
+
+        templates.insert("unexpected_character".to_string(), "অপ্রত্যাশিত অক্ষর '{0}' পাওয়া গেছে".to_string());
+        templates.insert("unterminated_string".to_string(), "স্ট্রিং শেষ হয়নি - উদ্ধৃতি চিহ্ন নেই".to_string());
+        templates.insert("unterminated_comment".to_string(), "মন্তব্য শেষ হয়নি - বন্ধ করার চিহ্ন নেই".to_string());
+        templates.insert("invalid_number".to_string(), "ভুল সংখ্যা '{0}'".to_string());
+        templates.insert("unexpected_token".to_string(), "প্রত্যাশিত ছিল '{1}' কিন্তু পাওয়া গেল '{0}'".to_string());
+        templates.insert("missing_token".to_string(), "'{0}' টোকেন নেই - যোগ করুন".to_string());
+        templates.insert("invalid_expression".to_string(), "ভুল এক্সপ্রেশন: {0}".to_string());
+        templates.insert("invalid_statement".to_string(), "ভুল স্টেটমেন্ট: {0}".to_string());
+        templates.insert("type_mismatch".to_string(), "টাইপ মেলে না - প্রত্যাশিত '{0}' কিন্তু পাওয়া গেল '{1}'".to_string());
+        templates.insert("undefined_variable".to_string(), "অজানা ভেরিয়েবল '{0}' - আগে ঘোষণা করুন".to_string());
+        templates.insert("undefined_function".to_string(), "অজানা ফাংশন '{0}'".to_string());
+        templates.insert("wrong_argument_count".to_string(), "ভুল আর্গুমেন্ট সংখ্যা - দরকার {0}টি, দেওয়া হয়েছে {1}টি".to_string());
+        templates.insert("division_by_zero".to_string(), "শূন্য দিয়ে ভাগ করা যায় না".to_string());
+        templates.insert("index_out_of_bounds".to_string(), "ইনডেক্স {0} সীমার বাইরে (সর্বোচ্চ {1})".to_string());
+        templates.insert("file_not_found".to_string(), "ফাইল '{0}' পাওয়া যায়নি".to_string());
+        templates.insert("permission_denied".to_string(), "'{0}' এ প্রবেশের অনুমতি নেই".to_string());
+        templates.insert("network_error".to_string(), "নেটওয়ার্ক ত্রুটি: {0}".to_string());
+        templates.insert("out_of_memory".to_string(), "মেমরি শেষ হয়ে গেছে".to_string());
+        templates.insert("stack_overflow".to_string(), "স্ট্যাক ওভারফ্লো - অতিরিক্ত রিকার্সিভ কল".to_string());
+        templates.insert("internal_error".to_string(), "অভ্যন্তরীণ ত্রুটি: {0}".to_string());
+
+        templates
+    }
+
+    // Create the Hindi language pack: keywords written in Devanagari map to B+ keywords
+    fn create_hindi_language_pack(&self) -> LanguagePack {
+        let mut keyword_mappings = HashMap::new();
+
+        // Hindi keywords and the B+ keywords they stand for
+        keyword_mappings.insert("यदि".to_string(), "jodi".to_string());
+        keyword_mappings.insert("तो".to_string(), "tahole".to_string());
+        keyword_mappings.insert("वरना".to_string(), "nahoy".to_string());
+        keyword_mappings.insert("मानो".to_string(), "dhoro".to_string());
+        keyword_mappings.insert("कार्य".to_string(), "kaj".to_string());
+        keyword_mappings.insert("दिखाओ".to_string(), "dekhao".to_string());
+        keyword_mappings.insert("लौटाओ".to_string(), "ferot".to_string());
+        keyword_mappings.insert("हाँ".to_string(), "ha".to_string());
+        keyword_mappings.insert("नहीं".to_string(), "na".to_string());
+        keyword_mappings.insert("और".to_string(), "ebong".to_string());
+        keyword_mappings.insert("या".to_string(), "othoba".to_string());
+        keyword_mappings.insert("जबतक".to_string(), "jotokhon".to_string());
+        keyword_mappings.insert("पहले करो".to_string(), "age koro".to_string());
+        keyword_mappings.insert("हर एक के लिए".to_string(), "protitar jonno".to_string());
+        keyword_mappings.insert("जिसमें".to_string(), "jekhane".to_string());
+        keyword_mappings.insert("रुको".to_string(), "thamo".to_string());
+        keyword_mappings.insert("जारी".to_string(), "choluk".to_string());
+        keyword_mappings.insert("इनपुट".to_string(), "input".to_string());
+
+        // UI messages in Hindi
+        keyword_mappings.insert("welcome_message".to_string(),
+            "बी+ में आपका स्वागत है! हिंदी भाषा पैक सक्रिय है।".to_string());
+        keyword_mappings.insert("example_usage".to_string(),
+            "आज़माएँ: यदि (10 > 5) { दिखाओ(\"10 is greater than 5!\") }".to_string());
+        keyword_mappings.insert("extension_init_error".to_string(),
+            "एक्सटेंशन सिस्टम शुरू करने में समस्या".to_string());
+        keyword_mappings.insert("fallback_mode".to_string(),
+            "डिफ़ॉल्ट बांग्लिश मोड में चल रहा है...".to_string());
+        keyword_mappings.insert("repl_start".to_string(),
+            "REPL मोड शुरू हुआ। बाहर निकलने के लिए 'prosthan' लिखें।".to_string());
+        keyword_mappings.insert("langpack_activated".to_string(),
+            "भाषा पैक '{0}' सक्रिय किया गया".to_string());
+        keyword_mappings.insert("langpack_error".to_string(),
+            "भाषा पैक सक्रिय नहीं हो सका: {0}".to_string());
+        keyword_mappings.insert("langpack_usage".to_string(),
+            "उपयोग: langpack <नाम>\nउदाहरण: langpack hindi".to_string());
+        keyword_mappings.insert("available_packs".to_string(),
+            "उपलब्ध भाषा पैक:".to_string());
+        keyword_mappings.insert("goodbye_message".to_string(),
+            "अलविदा! बी+ का उपयोग करने के लिए धन्यवाद!".to_string());
+
+        LanguagePack {
+            language: "Hindi".to_string(),
+            version: "1.0".to_string(),
+            author: "B+ Language Team".to_string(),
+            keyword_mappings,
+            error_templates: self.get_hindi_error_templates(),
+        }
+    }
+
+    // Hindi error message templates, one per error code
+    fn get_hindi_error_templates(&self) -> HashMap<String, String> {
+        let mut templates = HashMap::new();
+
+        templates.insert("unexpected_character".to_string(), "अप्रत्याशित अक्षर '{0}' मिला".to_string());
+        templates.insert("unterminated_string".to_string(), "स्ट्रिंग समाप्त नहीं हुई - उद्धरण चिह्न नहीं है".to_string());
+        templates.insert("unterminated_comment".to_string(), "टिप्पणी समाप्त नहीं हुई - बंद करने का चिह्न नहीं है".to_string());
+        templates.insert("invalid_number".to_string(), "गलत संख्या '{0}'".to_string());
+        templates.insert("unexpected_token".to_string(), "अपेक्षित था '{1}' लेकिन मिला '{0}'".to_string());
+        templates.insert("missing_token".to_string(), "'{0}' टोकन नहीं है - जोड़ें".to_string());
+        templates.insert("invalid_expression".to_string(), "गलत एक्सप्रेशन: {0}".to_string());
+        templates.insert("invalid_statement".to_string(), "गलत स्टेटमेंट: {0}".to_string());
+        templates.insert("type_mismatch".to_string(), "टाइप मेल नहीं खाता - अपेक्षित '{0}' लेकिन मिला '{1}'".to_string());
+        templates.insert("undefined_variable".to_string(), "अज्ञात वेरिएबल '{0}' - पहले घोषित करें".to_string());
+        templates.insert("undefined_function".to_string(), "अज्ञात फ़ंक्शन '{0}'".to_string());
+        templates.insert("wrong_argument_count".to_string(), "गलत आर्गुमेंट संख्या - चाहिए {0}, दिए गए {1}".to_string());
+        templates.insert("division_by_zero".to_string(), "शून्य से भाग नहीं दिया जा सकता".to_string());
+        templates.insert("index_out_of_bounds".to_string(), "इंडेक्स {0} सीमा से बाहर (अधिकतम {1})".to_string());
+        templates.insert("file_not_found".to_string(), "फ़ाइल '{0}' नहीं मिली".to_string());
+        templates.insert("permission_denied".to_string(), "'{0}' तक पहुँचने की अनुमति नहीं है".to_string());
+        templates.insert("network_error".to_string(), "नेटवर्क त्रुटि: {0}".to_string());
+        templates.insert("out_of_memory".to_string(), "मेमोरी समाप्त हो गई".to_string());
+        templates.insert("stack_overflow".to_string(), "स्टैक ओवरफ़्लो - बहुत अधिक रिकर्सिव कॉल".to_string());
+        templates.insert("internal_error".to_string(), "आंतरिक त्रुटि: {0}".to_string());
+
+        templates
+    }
+
     // Create a default Banglish language pack with keyword mappings and messages
     fn create_default_banglish_pack(&self) -> LanguagePack {
         let mut keyword_mappings = HashMap::new();
@@ -454,3 +690,176 @@ impl Default for ExtensionManager {
         Self::new("extensions")
     }
 }
+
+// === COMPILED LANGUAGE PACK FORMAT (.bplp) ===
+// "BPLP" magic, a format version byte, then the language, version and author
+// strings followed by the keyword mappings and error templates as counted lists
+// of string pairs. Strings and counts are little-endian u32 lengths; strings are
+// UTF-8. Pairs are written sorted so a source always compiles to the same bytes.
+const BPLP_MAGIC: &[u8; 4] = b"BPLP";
+const BPLP_FORMAT_VERSION: u8 = 1;
+
+// Serialize a language pack into the .bplp format
+pub fn encode_language_pack(pack: &LanguagePack) -> Vec<u8> {
+    fn write_str(out: &mut Vec<u8>, text: &str) {
+        out.extend_from_slice(&(text.len() as u32).to_le_bytes());
+        out.extend_from_slice(text.as_bytes());
+    }
+    
+    fn write_pairs(out: &mut Vec<u8>, pairs: &HashMap<String, String>) {
+        let mut sorted: Vec<_> = pairs.iter().collect();
+        sorted.sort();
+        out.extend_from_slice(&(sorted.len() as u32).to_le_bytes());
+        for (key, value) in sorted {
+            write_str(out, key);
+            write_str(out, value);
+        }
+    }
+    
+    let mut out = Vec::new();
+    out.extend_from_slice(BPLP_MAGIC);
+    out.push(BPLP_FORMAT_VERSION);
+    write_str(&mut out, &pack.language);
+    write_str(&mut out, &pack.version);
+    write_str(&mut out, &pack.author);
+    write_pairs(&mut out, &pack.keyword_mappings);
+    write_pairs(&mut out, &pack.error_templates);
+    out
+}
+
+// Deserialize a .bplp file, rejecting anything that isn't a complete pack of a known version
+pub fn decode_language_pack(bytes: &[u8]) -> Result<LanguagePack, String> {
+    let mut reader = PackReader { bytes, position: 0 };
+    
+    if reader.take(BPLP_MAGIC.len())? != BPLP_MAGIC {
+        return Err("Invalid language pack format: not a .bplp file".to_string());
+    }
+    let format_version = reader.take(1)?[0];
+    if format_version != BPLP_FORMAT_VERSION {
+        return Err(format!("Unsupported language pack format version {}", format_version));
+    }
+    
+    let pack = LanguagePack {
+        language: reader.read_str()?,
+        version: reader.read_str()?,
+        author: reader.read_str()?,
+        keyword_mappings: reader.read_pairs()?,
+        error_templates: reader.read_pairs()?,
+    };
+    
+    if reader.position != bytes.len() {
+        return Err("Corrupt language pack: unexpected data after the end".to_string());
+    }
+    Ok(pack)
+}
+
+// Cursor over the bytes of a compiled language pack
+struct PackReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PackReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        let end = self.position.checked_add(count)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| "Corrupt language pack: file is truncated".to_string())?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+    
+    fn read_u32(&mut self) -> Result<usize, String> {
+        let raw = self.take(4)?;
+        Ok(u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]) as usize)
+    }
+    
+    fn read_str(&mut self) -> Result<String, String> {
+        let len = self.read_u32()?;
+        let raw = self.take(len)?;
+        String::from_utf8(raw.to_vec())
+            .map_err(|_| "Corrupt language pack: text is not valid UTF-8".to_string())
+    }
+    
+    fn read_pairs(&mut self) -> Result<HashMap<String, String>, String> {
+        let count = self.read_u32()?;
+        let mut pairs = HashMap::new();
+        for _ in 0..count {
+            let key = self.read_str()?;
+            let value = self.read_str()?;
+            pairs.insert(key, value);
+        }
+        Ok(pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("bplus_langpack_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+    
+    #[test]
+    fn test_bplp_round_trip() {
+        let manager = ExtensionManager::new("test_extensions");
+        let pack = manager.create_bangla_unicode_language_pack();
+        
+        let bytes = encode_language_pack(&pack);
+        assert_eq!(&bytes[..4], b"BPLP");
+        assert_eq!(decode_language_pack(&bytes).unwrap(), pack);
+        // Sorted pairs make compilation deterministic
+        assert_eq!(encode_language_pack(&pack.clone()), bytes);
+    }
+    
+    #[test]
+    fn test_compile_language_pack_from_source() {
+        let dir = temp_dir("compile");
+        let source_path = dir.join("test.bplpsrc");
+        let output_path = dir.join("test.bplp");
+        fs::write(&source_path, "[metadata]\nlanguage = Test\nversion = 2.0\nauthor = Keu\n\n[mapping]\njodi => if\nnahoy jodi => else if\n\n[error_messages]\ndivision_by_zero = No dividing by zero\n").unwrap();
+        
+        let manager = ExtensionManager::new(dir.to_str().unwrap());
+        let compiled = manager.compile_language_pack(&source_path, &output_path).unwrap();
+        assert_eq!(compiled.language, "Test");
+        assert_eq!(compiled.keyword_mappings.get("nahoy jodi").map(String::as_str), Some("else if"));
+        assert_eq!(compiled.error_templates.len(), 1);
+        
+        let loaded = manager.load_compiled_language_pack(&output_path).unwrap();
+        assert_eq!(loaded, compiled);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[test]
+    fn test_activate_builtin_hindi_pack() {
+        let mut manager = ExtensionManager::new("test_extensions");
+        assert!(manager.available_language_packs().contains(&"hindi".to_string()));
+        
+        manager.activate_language_pack("hindi").unwrap();
+        assert_eq!(manager.get_active_language_pack().unwrap().language, "Hindi");
+        assert_eq!(manager.get_message("goodbye_message"), "अलविदा! बी+ का उपयोग करने के लिए धन्यवाद!");
+        assert_eq!(manager.keyword_aliases().get("यदि").map(String::as_str), Some("jodi"));
+        assert_eq!(manager.get_error_manager().get_current_language(), "Hindi");
+        
+        assert!(manager.activate_language_pack("klingon").is_err());
+    }
+    
+    #[test]
+    fn test_invalid_bplp_is_rejected() {
+        let bytes = encode_language_pack(&ExtensionManager::new("test_extensions").create_english_language_pack());
+        
+        assert!(decode_language_pack(b"// Compiled Binery File for B Plus Language").is_err());
+        assert!(decode_language_pack(&bytes[..bytes.len() - 1]).is_err());
+        
+        let mut newer = bytes.clone();
+        newer[4] = BPLP_FORMAT_VERSION + 1;
+        assert!(decode_language_pack(&newer).unwrap_err().contains("version"));
+        
+        let mut trailing = bytes;
+        trailing.push(0);
+        assert!(decode_language_pack(&trailing).is_err());
+    }
+}
@@ -1,4 +1,12 @@
-use crate::token::{lookup_ident, Token, TokenType};
+use crate::token::{continues_to_keyword, lookup_ident, Interner, Token, TokenType};
+use std::collections::{HashMap, HashSet};
+
+// A raw piece of a `dekhao { ... }` template: literal text or the source of an interpolation
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateSegment {
+    Text(String),
+    Code(String),
+}
 
 pub struct Lexer {
     input: String,
@@ -9,6 +17,10 @@ pub struct Lexer {
     column: usize,        // Current column number
     token_start_line: usize,   // Track token start position (line)
     token_start_column: usize, // Track token start position (column)
+    keep_comments: bool,       // Emit comment tokens instead of skipping comments
+    keyword_aliases: HashMap<String, String>, // Language-pack words standing for B+ keywords
+    alias_prefixes: HashSet<String>, // Leading words of the multi-word aliases
+    symbols: Interner,         // Token texts seen so far, shared by every token with that text
 }
 
 impl Lexer {
@@ -22,11 +34,84 @@ impl Lexer {
             column: 0,
             token_start_line: 1,
             token_start_column: 0,
+            keep_comments: false,
+            keyword_aliases: HashMap::new(),
+            alias_prefixes: HashSet::new(),
+            symbols: Interner::default(),
         };
         l.read_char(); // Initialize first char
         l
     }
 
+    // Lexer that returns comments as EkLineMontobbo (single-line) and
+    // BohuLineMontobboShuru (whole multi-line comment) tokens carrying the comment text,
+    // for tools that need to keep them. Normal evaluation uses `new`, which skips comments.
+    pub fn with_comments(input: String) -> Self {
+        let mut l = Lexer::new(input);
+        l.keep_comments = true;
+        l
+    }
+
+    // Lets source use a language pack's words for keywords, e.g. "যদি" => "jodi".
+    // Aliased keywords are emitted with the B+ keyword as their literal.
+    pub fn with_keyword_aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        // Leading words of multi-word aliases, so "हर" and "हर एक" keep the lexer reading
+        // until "हर एक के लिए" is complete
+        self.alias_prefixes = aliases
+            .keys()
+            .flat_map(|alias| {
+                let words: Vec<&str> = alias.split(' ').collect();
+                (1..words.len()).map(move |end| words[..end].join(" "))
+            })
+            .collect();
+        self.keyword_aliases = aliases;
+        self
+    }
+
+    // Whether more words after `words` could complete a built-in multi-word keyword or an alias
+    fn continues_to_keyword(&self, words: &str) -> bool {
+        continues_to_keyword(words) || self.alias_prefixes.contains(words)
+    }
+
+    // Position to come back to after reading ahead: position, read_position, ch, line, column
+    fn save_position(&self) -> (usize, usize, u8, usize, usize) {
+        (self.position, self.read_position, self.ch, self.line, self.column)
+    }
+
+    fn restore_position(&mut self, (position, read_position, ch, line, column): (usize, usize, u8, usize, usize)) {
+        self.position = position;
+        self.read_position = read_position;
+        self.ch = ch;
+        self.line = line;
+        self.column = column;
+    }
+
+    // Keyword lookup that consults the active aliases first
+    fn lookup_word(&self, word: &str) -> (TokenType, String) {
+        if let Some(keyword) = self.keyword_aliases.get(word) {
+            let token_type = lookup_ident(keyword);
+            if token_type != TokenType::Ident {
+                return (token_type, keyword.clone());
+            }
+        }
+        (lookup_ident(word), word.to_string())
+    }
+
+    // Token starting where the current one started, sharing its text with earlier tokens
+    fn token(&mut self, token_type: TokenType, literal: &str) -> Token {
+        let literal = self.symbols.intern(literal);
+        Token::with_symbol(token_type, literal, self.token_start_line, self.token_start_column)
+    }
+
+    // Returns the comment as a token in comment mode, otherwise moves on to the next real token
+    fn comment_token(&mut self, token_type: TokenType, text: String) -> Token {
+        if self.keep_comments {
+            self.token(token_type, &text)
+        } else {
+            self.next_token()
+        }
+    }
+
     fn read_char_literal(&mut self) -> Result<String, String> {
         // Assumes current char is starting `'`
         self.read_char(); // consume opening '
@@ -34,21 +119,18 @@ impl Lexer {
         let mut char_literal = String::new();
 
         if self.ch == b'\\' {
-            // Escape sequence
-            self.read_char();
-            let escaped_char = match self.ch {
-                b'n' => '\n',
-                b't' => '\t',
-                b'r' => '\r',
-                b'\'' => '\'',
-                b'\\' => '\\',
-                other => other as char,
-            };
-            char_literal.push(escaped_char);
-            self.read_char();
+            match self.read_escape() {
+                Ok(c) => char_literal.push(c),
+                Err(e) => {
+                    if self.ch == b'\'' {
+                        self.read_char(); // consume closing ' so lexing carries on after the literal
+                    }
+                    return Err(e);
+                }
+            }
         } else if self.ch != 0 && self.ch != b'\'' {
-            char_literal.push(self.ch as char);
-            self.read_char();
+            char_literal.push(self.current_char());
+            self.read_unicode_char();
         } else {
             return Err("Empty or invalid char literal".to_string());
         }
@@ -74,11 +156,27 @@ impl Lexer {
         if self.ch == b'\n' {
             self.line += 1;
             self.column = 0;
-        } else {
+        } else if !(0x80..0xC0).contains(&self.ch) {
+            // UTF-8 continuation bytes don't start a new column
             self.column += 1;
         }
     }
 
+    // Full Unicode character starting at the current position ('\0' at EOF)
+    fn current_char(&self) -> char {
+        self.input
+            .get(self.position..)
+            .and_then(|rest| rest.chars().next())
+            .unwrap_or('\0')
+    }
+
+    // Advance past the current character, however many UTF-8 bytes it spans
+    fn read_unicode_char(&mut self) {
+        for _ in 0..self.current_char().len_utf8() {
+            self.read_char();
+        }
+    }
+
     fn peek_char(&self) -> u8 {
         if self.read_position >= self.input.len() {
             0
@@ -99,58 +197,58 @@ impl Lexer {
             if self.peek_char() == b'/' {
                 self.read_char();
                 self.read_char();
-                self.skip_single_line_comment();
-                return self.next_token();
+                let text = self.skip_single_line_comment();
+                return self.comment_token(TokenType::EkLineMontobbo, text);
             } else if self.peek_char() == b'*' {
                 self.read_char();
                 self.read_char();
-                if let Err(err) = self.skip_multi_line_comment("/*", "*/") {
-                    return Token::new(TokenType::Illegal, &err, self.token_start_line, self.token_start_column);
+                match self.skip_multi_line_comment("/*", "*/") {
+                    Ok(text) => return self.comment_token(TokenType::BohuLineMontobboShuru, text),
+                    Err(err) => return self.token(TokenType::Illegal, &err),
                 }
-                return self.next_token();
             }
         } else if self.ch == b'#' {
             self.read_char();
-            self.skip_single_line_comment();
-            return self.next_token();
-        } else if self.ch == b'-' && self.peek_char() == b'-' {
+            let text = self.skip_single_line_comment();
+            return self.comment_token(TokenType::EkLineMontobbo, text);
+        } else if self.ch == b'-' && self.peek_char() == b'-' && !self.starts_double_negation() {
             self.read_char();
             self.read_char();
-            self.skip_single_line_comment();
-            return self.next_token();
+            let text = self.skip_single_line_comment();
+            return self.comment_token(TokenType::EkLineMontobbo, text);
         } else if self.ch == b'=' {
             let lookahead = self.peek_n_chars(5);
             if lookahead == "begin" {
                 for _ in 0..6 { self.read_char(); }
-                if let Err(err) = self.skip_multi_line_comment("=begin", "=end") {
-                    return Token::new(TokenType::Illegal, &err, self.token_start_line, self.token_start_column);
+                match self.skip_multi_line_comment("=begin", "=end") {
+                    Ok(text) => return self.comment_token(TokenType::BohuLineMontobboShuru, text),
+                    Err(err) => return self.token(TokenType::Illegal, &err),
                 }
-                return self.next_token();
             }
         } else if self.ch == b'{' && self.peek_char() == b'-' {
             self.read_char();
             self.read_char();
-            if let Err(err) = self.skip_multi_line_comment("{-", "-}") {
-                return Token::new(TokenType::Illegal, &err, self.token_start_line, self.token_start_column);
+            match self.skip_multi_line_comment("{-", "-}") {
+                Ok(text) => return self.comment_token(TokenType::BohuLineMontobboShuru, text),
+                Err(err) => return self.token(TokenType::Illegal, &err),
             }
-            return self.next_token();
         } else if self.ch == b'(' && self.peek_char() == b'*' {
             self.read_char();
             self.read_char();
-            if let Err(err) = self.skip_multi_line_comment("(*", "*)") {
-                return Token::new(TokenType::Illegal, &err, self.token_start_line, self.token_start_column);
+            match self.skip_multi_line_comment("(*", "*)") {
+                Ok(text) => return self.comment_token(TokenType::BohuLineMontobboShuru, text),
+                Err(err) => return self.token(TokenType::Illegal, &err),
             }
-            return self.next_token();
         } else if self.ch == b'"' {
             let lookahead = self.peek_n_chars(2);
             if lookahead == "\"\"" {
                 self.read_char();
                 self.read_char();
                 self.read_char();
-                if let Err(err) = self.skip_multi_line_comment("\"\"\"", "\"\"\"") {
-                    return Token::new(TokenType::Illegal, &err, self.token_start_line, self.token_start_column);
+                match self.skip_multi_line_comment("\"\"\"", "\"\"\"") {
+                    Ok(text) => return self.comment_token(TokenType::BohuLineMontobboShuru, text),
+                    Err(err) => return self.token(TokenType::Illegal, &err),
                 }
-                return self.next_token();
             }
         } else if self.ch == b'\'' {
             let lookahead = self.peek_n_chars(2);
@@ -158,10 +256,10 @@ impl Lexer {
                 self.read_char();
                 self.read_char();
                 self.read_char();
-                if let Err(err) = self.skip_multi_line_comment("'''", "'''") {
-                    return Token::new(TokenType::Illegal, &err, self.token_start_line, self.token_start_column);
+                match self.skip_multi_line_comment("'''", "'''") {
+                    Ok(text) => return self.comment_token(TokenType::BohuLineMontobboShuru, text),
+                    Err(err) => return self.token(TokenType::Illegal, &err),
                 }
-                return self.next_token();
             }
         }
 
@@ -169,124 +267,272 @@ impl Lexer {
             b'=' => {
                 if self.peek_char() == b'=' {
                     self.read_char();
-                    Token::new(TokenType::Eq, "==", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::Eq, "==")
+                } else {
+                    self.token(TokenType::Assign, "=")
+                }
+            }
+            b';' => self.token(TokenType::Semicolon, ";"),
+            b'(' => self.token(TokenType::LParen, "("),
+            b')' => self.token(TokenType::RParen, ")"),
+            b',' => self.token(TokenType::Comma, ","),
+            b'+' => {
+                if self.peek_char() == b'=' {
+                    self.read_char();
+                    self.token(TokenType::PlusEq, "+=")
+                } else {
+                    self.token(TokenType::Plus, "+")
+                }
+            }
+            b'-' => {
+                if self.peek_char() == b'=' {
+                    self.read_char();
+                    self.token(TokenType::MinusEq, "-=")
                 } else {
-                    Token::new(TokenType::Assign, "=", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::Minus, "-")
                 }
             }
-            b';' => Token::new(TokenType::Semicolon, ";", self.token_start_line, self.token_start_column),
-            b'(' => Token::new(TokenType::LParen, "(", self.token_start_line, self.token_start_column),
-            b')' => Token::new(TokenType::RParen, ")", self.token_start_line, self.token_start_column),
-            b',' => Token::new(TokenType::Comma, ",", self.token_start_line, self.token_start_column),
-            b'+' => Token::new(TokenType::Plus, "+", self.token_start_line, self.token_start_column),
-            b'-' => Token::new(TokenType::Minus, "-", self.token_start_line, self.token_start_column),
             b'!' => {
                 if self.peek_char() == b'=' {
                     self.read_char();
-                    Token::new(TokenType::NotEq, "!=", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::NotEq, "!=")
                 } else {
-                    Token::new(TokenType::Bang, "!", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::Bang, "!")
+                }
+            }
+            b'/' => {
+                if self.peek_char() == b'=' {
+                    self.read_char();
+                    self.token(TokenType::SlashEq, "/=")
+                } else {
+                    self.token(TokenType::Slash, "/")
+                }
+            }
+            b'*' => {
+                if self.peek_char() == b'*' {
+                    self.read_char();
+                    self.token(TokenType::DoubleStar, "**")
+                } else if self.peek_char() == b'=' {
+                    self.read_char();
+                    self.token(TokenType::StarEq, "*=")
+                } else {
+                    self.token(TokenType::Asterisk, "*")
                 }
             }
-            b'/' => Token::new(TokenType::Slash, "/", self.token_start_line, self.token_start_column),
-            b'*' => Token::new(TokenType::Asterisk, "*", self.token_start_line, self.token_start_column),
 
             b'\'' => {
                 match self.read_char_literal() {
-                    Ok(lit) => return Token::new(TokenType::Char, &lit, self.token_start_line, self.token_start_column),
-                    Err(e) => return Token::new(TokenType::Illegal, &e, self.token_start_line, self.token_start_column),
+                    Ok(lit) => return self.token(TokenType::Char, &lit),
+                    Err(e) => return self.token(TokenType::Illegal, &e),
                 }
             }
 
             b'<' => {
                 if self.peek_char() == b'<' {
                     self.read_char();
-                    Token::new(TokenType::ShiftLeft, "<<", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::ShiftLeft, "<<")
+                } else if self.peek_char() == b'=' {
+                    self.read_char();
+                    self.token(TokenType::LtEq, "<=")
                 } else {
-                    Token::new(TokenType::Lt, "<", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::Lt, "<")
                 }
             }
             b'>' => {
                 if self.peek_char() == b'>' {
                     self.read_char();
-                    Token::new(TokenType::ShiftRight, ">>", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::ShiftRight, ">>")
+                } else if self.peek_char() == b'=' {
+                    self.read_char();
+                    self.token(TokenType::GtEq, ">=")
+                } else {
+                    self.token(TokenType::Gt, ">")
+                }
+            }
+            // && and || are ASCII spellings of ebong and othoba; single & and | stay bitwise
+            b'&' => {
+                if self.peek_char() == b'&' {
+                    self.read_char();
+                    self.token(TokenType::Ebong, "&&")
+                } else {
+                    self.token(TokenType::Ampersand, "&")
+                }
+            }
+            b'|' => {
+                if self.peek_char() == b'|' {
+                    self.read_char();
+                    self.token(TokenType::Othoba, "||")
                 } else {
-                    Token::new(TokenType::Gt, ">", self.token_start_line, self.token_start_column)
+                    self.token(TokenType::Pipe, "|")
                 }
             }
-            b'{' => Token::new(TokenType::LBrace, "{", self.token_start_line, self.token_start_column),
-            b'}' => Token::new(TokenType::RBrace, "}", self.token_start_line, self.token_start_column),
+            b'{' => self.token(TokenType::LBrace, "{"),
+            b'}' => self.token(TokenType::RBrace, "}"),
             b'"' => {
                 match self.read_string() {
-                    Ok(lit) => return Token::new(TokenType::String, &lit, self.token_start_line, self.token_start_column),
-                    Err(e) => return Token::new(TokenType::Illegal, &e, self.token_start_line, self.token_start_column),
+                    Ok(lit) => return self.token(TokenType::String, &lit),
+                    Err(e) => return self.token(TokenType::Illegal, &e),
                 }
             }
-            b'.' => Token::new(TokenType::Fullstop, ".", self.token_start_line, self.token_start_column),
+            b'.' => {
+                if self.peek_n_chars(2) == ".." {
+                    self.read_char();
+                    self.read_char();
+                    self.token(TokenType::Ellipsis, "...")
+                } else {
+                    self.token(TokenType::Fullstop, ".")
+                }
+            }
+            b':' => self.token(TokenType::Colon, ":"),
+            b'?' => self.token(TokenType::Question, "?"),
+            b'[' => self.token(TokenType::LBracket, "["),
+            b']' => self.token(TokenType::RBracket, "]"),
 
-_ if self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_bengali_letter() => {
+_ if is_bengali_digit(self.current_char()) => {
+    let (literal, token_type) = self.read_number();
+    return self.token(token_type, &literal);
+}
+
+_ if self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_indic_letter() => {
     // প্রথম word পড়া
-    let first_word = self.read_identifier();
-    let mut literal = first_word.clone();
-    let mut token_type = lookup_ident(&literal);
+    let start = self.position;
+    self.skip_identifier();
 
-    // multi-word keywords handle করার জন্য loop
-    loop {
-        let saved_pos = self.position;
-        let saved_read = self.read_position;
-        let saved_ch = self.ch;
-        let saved_line = self.line;
-        let saved_column = self.column;
+    // Most words can't start a multi-word keyword: take them straight from the source
+    let word = &self.input[start..self.position];
+    if self.keyword_aliases.is_empty() && !continues_to_keyword(word) {
+        let token_type = lookup_ident(word);
+        let literal = self.symbols.intern(word);
+        return Token::with_symbol(token_type, literal, self.token_start_line, self.token_start_column);
+    }
 
+    let mut words = word.to_string();
+    let (mut token_type, mut literal) = self.lookup_word(&words);
+    // Where the longest keyword read so far ends; the lexer goes back there once no
+    // longer keyword can follow (e.g. after "हर एक" that never becomes "हर एक के लिए")
+    let mut matched_end = self.save_position();
+
+    // multi-word keywords handle করার জন্য loop
+    // Only read ahead while the words so far start a multi-word keyword or alias
+    while self.continues_to_keyword(&words) {
         self.skip_whitespace();
 
         // পরের word পড়া
-        if self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_bengali_letter() {
-            let next_word = self.read_identifier();
-            let candidate = format!("{} {}", literal, next_word);
-            let candidate_type = lookup_ident(&candidate);
-
-            // যদি lookup match না করে, rewind
-            if candidate_type != TokenType::Ident {
-                literal = candidate;
-                token_type = candidate_type;
-            } else {
-                self.position = saved_pos;
-                self.read_position = saved_read;
-                self.ch = saved_ch;
-                self.line = saved_line;
-                self.column = saved_column;
-                break;
-            }
-        } else {
+        if !(self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_indic_letter()) {
             break;
         }
+        let next_start = self.position;
+        self.skip_identifier();
+        words = format!("{} {}", words, &self.input[next_start..self.position]);
+
+        let (candidate_type, candidate_literal) = self.lookup_word(&words);
+        if candidate_type != TokenType::Ident {
+            literal = candidate_literal;
+            token_type = candidate_type;
+            matched_end = self.save_position();
+        }
     }
 
-    Token::new(token_type, &literal, self.token_start_line, self.token_start_column)
+    // No longer keyword: rewind to just after the last matched word
+    self.restore_position(matched_end);
+
+    // skip_identifier already advanced past the word, so skip the trailing read_char
+    return self.token(token_type, &literal);
 }
 
 
             b'0'..=b'9' => {
                 let (literal, token_type) = self.read_number();
-                return Token::new(token_type, &literal, self.token_start_line, self.token_start_column);
+                return self.token(token_type, &literal);
             }
 
-            0 => Token::new(TokenType::Eof, "", self.token_start_line, self.token_start_column),
-            _ => Token::new(TokenType::Illegal, &(self.ch as char).to_string(), self.token_start_line, self.token_start_column),
+            0 => self.token(TokenType::Eof, ""),
+            _ => self.token(TokenType::Illegal, &(self.ch as char).to_string()),
         };
 
         self.read_char();
         tok
     }
 
-    fn skip_single_line_comment(&mut self) {
+    // Template scanning mode: called right after the opening '{' of a template was tokenized.
+    // Reads raw source up to the matching '}' so spaces and punctuation survive, splitting
+    // out every balanced `( ... )` group as code. Leading and trailing whitespace of the
+    // whole template is trimmed; everything in between is kept verbatim.
+    pub fn read_template_body(&mut self) -> Result<Vec<TemplateSegment>, String> {
+        let mut segments = Vec::new();
+        let mut text_start = self.position;
+
+        loop {
+            match self.ch {
+                0 => return Err("Unterminated template literal - missing '}'".to_string()),
+                b'}' => {
+                    let text = &self.input[text_start..self.position];
+                    if !text.is_empty() {
+                        segments.push(TemplateSegment::Text(text.to_string()));
+                    }
+                    self.read_char(); // consume closing '}'
+                    break;
+                }
+                b'(' => {
+                    let text = &self.input[text_start..self.position];
+                    if !text.is_empty() {
+                        segments.push(TemplateSegment::Text(text.to_string()));
+                    }
+                    self.read_char(); // consume '('
+                    let code_start = self.position;
+                    let mut depth = 1;
+                    while depth > 0 {
+                        match self.ch {
+                            0 => return Err("Unterminated interpolation in template literal - missing ')'".to_string()),
+                            b'(' => depth += 1,
+                            b')' => depth -= 1,
+                            b'"' => {
+                                // Skip over string contents so quoted parens don't count
+                                self.read_char();
+                                while self.ch != b'"' && self.ch != 0 {
+                                    if self.ch == b'\\' {
+                                        self.read_char();
+                                    }
+                                    self.read_char();
+                                }
+                            }
+                            _ => {}
+                        }
+                        if depth > 0 {
+                            self.read_char();
+                        }
+                    }
+                    segments.push(TemplateSegment::Code(self.input[code_start..self.position].to_string()));
+                    self.read_char(); // consume ')'
+                    text_start = self.position;
+                }
+                _ => self.read_char(),
+            }
+        }
+
+        if let Some(TemplateSegment::Text(first)) = segments.first_mut() {
+            *first = first.trim_start().to_string();
+        }
+        if let Some(TemplateSegment::Text(last)) = segments.last_mut() {
+            *last = last.trim_end().to_string();
+        }
+        segments.retain(|seg| seg != &TemplateSegment::Text(String::new()));
+
+        Ok(segments)
+    }
+
+    // Skips to the end of the line, returning the comment text
+    fn skip_single_line_comment(&mut self) -> String {
+        let start = self.position;
         while self.ch != b'\n' && self.ch != 0 {
             self.read_char();
         }
+        self.input[start..self.position].trim_end_matches('\r').to_string()
     }
 
-    fn skip_multi_line_comment(&mut self, start: &str, end: &str) -> Result<(), String> {
+    // Skips past the closing marker, returning the text between the markers
+    fn skip_multi_line_comment(&mut self, start: &str, end: &str) -> Result<String, String> {
+        let text_start = self.position;
         let mut end_matched = 0;
         let end_bytes = end.as_bytes();
         let end_len = end_bytes.len();
@@ -298,75 +544,112 @@ _ if self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_bengali
             if self.ch == end_bytes[end_matched] {
                 end_matched += 1;
                 if end_matched == end_len {
+                    let text = self.input[text_start..self.position + 1 - end_len].to_string();
                     self.read_char();
-                    break;
+                    return Ok(text);
                 }
             } else {
                 end_matched = 0;
             }
             self.read_char();
         }
-        Ok(())
     }
 
-    fn peek_n_chars(&self, n: usize) -> String {
+    // "--" right before a digit or '(' is two minus signs (--5), not a line comment
+    fn starts_double_negation(&self) -> bool {
+        let after = self.input.as_bytes().get(self.read_position + 1).copied().unwrap_or(0);
+        after.is_ascii_digit() || after == b'('
+    }
+
+    fn peek_n_chars(&self, n: usize) -> &str {
         let start = self.position + 1;
         let end = (start + n).min(self.input.len());
 
         if start >= self.input.len() {
-            return String::new();
+            return "";
         }
 
-        // Avoid allocation per char by using iterator
-        self.input[start..end].to_string()
+        // Byte window; a window that splits a multibyte char can't match "\"\"" or "''" anyway
+        self.input.get(start..end).unwrap_or_default()
     }
 
-    fn read_identifier(&mut self) -> String {
-        let start_pos = self.position;
-
-        while self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_bengali_letter() {
-            self.read_char();
+    // Advances past an identifier; the caller slices its text out of the input
+    fn skip_identifier(&mut self) {
+        while self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_indic_letter() {
+            self.read_unicode_char();
         }
-
-        self.input[start_pos..self.position].to_string()
     }
 
+    // Numbers may be written with ASCII or Bengali digits (০-৯), and the literal always
+    // comes back in ASCII. Each digit is translated on its own, so a literal mixing both
+    // scripts such as "১2" reads as 12. A single '_' may separate two digits (1_000_000);
+    // any other underscore makes the whole number an Illegal token.
     fn read_number(&mut self) -> (String, TokenType) {
         let start_pos = self.position;
+        let mut literal = String::new();
+        let mut bad_separator = false;
         let mut has_dot = false;
         let mut has_exp = false;
         let mut has_i = false;
         let mut token_type = TokenType::Int;
 
-        while {
-            let c = self.ch as char;
-            if c.is_ascii_digit() {
-                true
-            } else if c == '.' && !has_dot && !has_i {
+        loop {
+            let c = self.current_char();
+            let accepted = if let Some(digit) = ascii_digit(c) {
+                Some(digit)
+            } else if c == '.' && !has_dot && !has_exp && !has_i && self.peek_digit() {
                 has_dot = true;
                 token_type = TokenType::Float;
-                true
+                Some(c)
             } else if (c == 'e' || c == 'E') && !has_exp && !has_i {
                 has_exp = true;
                 token_type = TokenType::Double;
-                true
-            } else if (c == '+' || c == '-') && has_exp {
-                true
+                Some(c)
+            } else if (c == '+' || c == '-') && has_exp && literal.ends_with(['e', 'E']) {
+                // Exponent sign is only valid right after the 'e'
+                Some(c)
             } else if c == 'i' && !has_i {
                 has_i = true;
                 token_type = TokenType::Complex;
-                true
+                Some(c)
             } else if c == 'm' || c == 'M' {
                 token_type = TokenType::Decimal;
-                true
+                Some(c)
+            } else if c == '_' && !literal.is_empty() {
+                // Separators are dropped from the literal
+                if !literal.ends_with(|c: char| c.is_ascii_digit()) || !self.peek_digit() {
+                    bad_separator = true;
+                }
+                self.read_char();
+                continue;
             } else {
-                false
+                None
+            };
+
+            match accepted {
+                Some(c) => {
+                    literal.push(c);
+                    self.read_unicode_char();
+                }
+                None => break,
             }
-        } {
-            self.read_char();
         }
 
-        (self.input[start_pos..self.position].to_string(), token_type)
+        if bad_separator {
+            let source = &self.input[start_pos..self.position];
+            return (format!("Invalid digit separator in number {}", source), TokenType::Illegal);
+        }
+        (literal, token_type)
+    }
+
+    // Whether the character after the current one is an ASCII or Bengali digit
+    fn peek_digit(&self) -> bool {
+        let next = self.current_char().len_utf8();
+        self.input
+            .get(self.position + next..)
+            .and_then(|rest| rest.chars().next())
+            .and_then(ascii_digit)
+            .is_some()
     }
 
     fn read_string(&mut self) -> Result<String, String> {
@@ -374,50 +657,416 @@ _ if self.ch.is_ascii_alphabetic() || self.ch == b'_' || self.is_unicode_bengali
 
         let mut result = String::new();
 
+        let mut error = None;
+
         while self.ch != b'"' && self.ch != 0 {
             if self.ch == b'\\' {
-                self.read_char();
-                let escaped_char = match self.ch {
-                    b'n' => '\n',
-                    b't' => '\t',
-                    b'r' => '\r',
-                    b'"' => '"',
-                    b'\\' => '\\',
-                    other => other as char,
-                };
-                result.push(escaped_char);
+                // Keep going after a bad escape so the rest of the string isn't lexed as code
+                match self.read_escape() {
+                    Ok(c) => result.push(c),
+                    Err(e) => error = error.or(Some(e)),
+                }
             } else {
-                result.push(self.ch as char);
+                result.push(self.current_char());
+                self.read_unicode_char();
             }
-            self.read_char();
         }
 
         if self.ch == b'"' {
             self.read_char(); // consume closing quote
-            Ok(result)
+            match error {
+                Some(e) => Err(e),
+                None => Ok(result),
+            }
         } else {
             Err("Unterminated string literal".to_string())
         }
     }
 
+    // Escape sequence starting at the current backslash: \n, \t, \r, \0, \\, \", \',
+    // \x41 or \u{09AC}. Leaves the lexer just past it, even when it's invalid.
+    fn read_escape(&mut self) -> Result<char, String> {
+        let (line, column) = (self.line, self.column);
+        self.read_char(); // consume '\'
+        if self.ch == 0 {
+            return Err(format!("Unfinished escape sequence at line {}, column {}", line, column));
+        }
+        let escaped = match self.current_char() {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            c @ ('\\' | '"' | '\'') => c,
+            'x' => return self.read_hex_escape(),
+            'u' if self.peek_char() == b'{' => return self.read_unicode_escape(),
+            other => {
+                self.read_unicode_char();
+                return Err(format!("Unknown escape sequence \\{} at line {}, column {}", other, line, column));
+            }
+        };
+        self.read_unicode_char();
+        Ok(escaped)
+    }
+
+    // The \xNN part of a byte escape: exactly 2 hex digits for an ASCII character
+    fn read_hex_escape(&mut self) -> Result<char, String> {
+        self.read_char(); // consume 'x'
+        let start = self.position;
+        while self.ch.is_ascii_hexdigit() && self.position - start < 2 {
+            self.read_char();
+        }
+        let digits = &self.input[start..self.position];
+        match u8::from_str_radix(digits, 16) {
+            Ok(byte) if digits.len() == 2 && byte.is_ascii() => Ok(byte as char),
+            Ok(_) if digits.len() == 2 => Err(format!("\\x{} is out of range, \\x escapes go up to \\x7F", digits)),
+            _ => Err(format!("Byte escape \\x{} must have exactly 2 hex digits", digits)),
+        }
+    }
+
+    // The \u{...} part of a Unicode escape: 1 to 6 hex digits naming a code point
+    fn read_unicode_escape(&mut self) -> Result<char, String> {
+        self.read_char(); // consume 'u'
+        self.read_char(); // consume '{'
+        let start = self.position;
+        while self.ch.is_ascii_hexdigit() {
+            self.read_char();
+        }
+        let digits = self.input[start..self.position].to_string();
+        if self.ch != b'}' {
+            return Err(format!("Unterminated Unicode escape \\u{{{}", digits));
+        }
+        self.read_char(); // consume '}'
+
+        if digits.is_empty() || digits.len() > 6 {
+            return Err(format!("Unicode escape \\u{{{}}} must have 1 to 6 hex digits", digits));
+        }
+        let code = u32::from_str_radix(&digits, 16).expect("hex digits were checked");
+        char::from_u32(code).ok_or_else(|| format!("\\u{{{}}} is not a valid Unicode character", digits))
+    }
+
     fn skip_whitespace(&mut self) {
         while self.ch.is_ascii_whitespace() {
             self.read_char();
         }
     }
 
-    fn is_unicode_bengali_letter(&self) -> bool {
-        if self.position >= self.input.len() {
-            return false;
+    fn is_unicode_indic_letter(&self) -> bool {
+        // Devanagari (U+0900 to U+097F) and Bengali (U+0980 to U+09FF) blocks, except
+        // the Bengali digits, which start numbers
+        let c = self.current_char();
+        ('\u{0900}'..='\u{09FF}').contains(&c) && !is_bengali_digit(c)
+    }
+}
+
+fn is_bengali_digit(c: char) -> bool {
+    ('\u{09E6}'..='\u{09EF}').contains(&c)
+}
+
+// The ASCII digit for an ASCII or Bengali digit character
+fn ascii_digit(c: char) -> Option<char> {
+    if c.is_ascii_digit() {
+        Some(c)
+    } else if is_bengali_digit(c) {
+        char::from_digit(c as u32 - 0x09E6, 10)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_token(input: &str) -> Token {
+        Lexer::new(input.to_string()).next_token()
+    }
+
+    #[test]
+    fn test_comments_skipped_by_default() {
+        let mut lexer = Lexer::new("// note\n/* block */ x # tail".to_string());
+        assert_eq!(lexer.next_token().token_type, TokenType::Ident);
+        assert_eq!(lexer.next_token().token_type, TokenType::Eof);
+    }
+
+    #[test]
+    fn test_comment_tokens() {
+        let mut lexer = Lexer::with_comments("// note\r\nx (* ek\ndui *) -- শেষ".to_string());
+
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::EkLineMontobbo);
+        assert_eq!(&*tok.literal, " note");
+
+        assert_eq!(lexer.next_token().token_type, TokenType::Ident);
+
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::BohuLineMontobboShuru);
+        assert_eq!(&*tok.literal, " ek\ndui ");
+
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::EkLineMontobbo);
+        assert_eq!(&*tok.literal, " শেষ");
+        assert_eq!(lexer.next_token().token_type, TokenType::Eof);
+    }
+
+    #[test]
+    fn test_bengali_digits() {
+        let mut lexer = Lexer::new("৫ + ৪২ * ৩.১৪ ১2 x৯".to_string());
+        let expected = [
+            (TokenType::Int, "5"),
+            (TokenType::Plus, "+"),
+            (TokenType::Int, "42"),
+            (TokenType::Asterisk, "*"),
+            (TokenType::Float, "3.14"),
+            (TokenType::Int, "12"),
+            (TokenType::Ident, "x"),
+            (TokenType::Int, "9"),
+            (TokenType::Eof, ""),
+        ];
+        for (token_type, literal) in expected {
+            let tok = lexer.next_token();
+            assert_eq!((tok.token_type, &*tok.literal), (token_type, literal));
+        }
+        assert_eq!(&*first_token("৩e২").literal, "3e2");
+    }
+
+    #[test]
+    fn test_double_star() {
+        let mut lexer = Lexer::new("2 ** 3 * 4".to_string());
+        let types: Vec<TokenType> = (0..6).map(|_| lexer.next_token().token_type).collect();
+        assert_eq!(
+            types,
+            vec![TokenType::Int, TokenType::DoubleStar, TokenType::Int, TokenType::Asterisk, TokenType::Int, TokenType::Eof]
+        );
+    }
+
+    #[test]
+    fn test_logical_operator_symbols() {
+        let mut lexer = Lexer::new("a && b || c & d | e".to_string());
+        let tokens: Vec<(TokenType, String)> = std::iter::from_fn(|| Some(lexer.next_token()))
+            .take_while(|tok| tok.token_type != TokenType::Eof)
+            .filter(|tok| tok.token_type != TokenType::Ident)
+            .map(|tok| (tok.token_type, tok.literal.to_string()))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenType::Ebong, "&&".to_string()),
+                (TokenType::Othoba, "||".to_string()),
+                (TokenType::Ampersand, "&".to_string()),
+                (TokenType::Pipe, "|".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compound_assignment_tokens() {
+        let mut lexer = Lexer::new("x += 1; x -= 2; x *= 3; x /= 4; x - -1".to_string());
+        let operators: Vec<TokenType> = std::iter::from_fn(|| Some(lexer.next_token()))
+            .take_while(|tok| tok.token_type != TokenType::Eof)
+            .map(|tok| tok.token_type)
+            .filter(|t| !matches!(t, TokenType::Ident | TokenType::Int | TokenType::Semicolon))
+            .collect();
+        assert_eq!(
+            operators,
+            vec![TokenType::PlusEq, TokenType::MinusEq, TokenType::StarEq, TokenType::SlashEq, TokenType::Minus, TokenType::Minus]
+        );
+    }
+
+    #[test]
+    fn test_double_minus() {
+        let mut lexer = Lexer::new("--5 --(x) -- note".to_string());
+        let types: Vec<TokenType> = (0..9).map(|_| lexer.next_token().token_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                TokenType::Minus, TokenType::Minus, TokenType::Int,
+                TokenType::Minus, TokenType::Minus, TokenType::LParen, TokenType::Ident, TokenType::RParen,
+                TokenType::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_digit_separators() {
+        assert_eq!((first_token("1_000").token_type, first_token("1_000").literal.to_string()), (TokenType::Int, "1000".to_string()));
+        assert_eq!(&*first_token("3.14_15").literal, "3.1415");
+        assert_eq!(&*first_token("1_0e1_0").literal, "10e10");
+        assert_eq!(&*first_token("১_০০০").literal, "1000");
+
+        for bad in ["1__0", "1_", "1_.5", "2e_3"] {
+            let tok = first_token(bad);
+            assert_eq!(tok.token_type, TokenType::Illegal, "{}", bad);
+            assert_eq!(&*tok.literal, format!("Invalid digit separator in number {}", bad));
         }
+        // A leading underscore starts an identifier, not a number
+        assert_eq!(first_token("_5").token_type, TokenType::Ident);
+    }
 
-        let s = &self.input[self.position..];
-        if let Some(ch) = s.chars().next() {
-            // Bengali Unicode block range: U+0980 to U+09FF
-            ch >= '\u{0980}' && ch <= '\u{09FF}'
-        } else {
-            false
+    #[test]
+    fn test_bengali_identifier() {
+        let mut lexer = Lexer::new("নাম = 'ক'".to_string());
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::Ident);
+        assert_eq!(&*tok.literal, "নাম");
+        assert_eq!(lexer.next_token().token_type, TokenType::Assign);
+    }
+
+    #[test]
+    fn test_large_source_tokens_unchanged() {
+        // Keywords in several spellings next to plain identifiers, repeated many times
+        let snippet = "dhoro x = 1; Protitar  Jonno (i jekhane xs) { age koro { x += i } jotokhon x < 5 }\n\
+                       JODI (x ebong y) { dekhao(x) } nahoy { ferot x_y; } import koro \"a\" ei hisebe a\n";
+        let tokens = |source: &str| -> Vec<(TokenType, String)> {
+            let mut lexer = Lexer::new(source.to_string());
+            std::iter::from_fn(|| Some(lexer.next_token()))
+                .take_while(|tok| tok.token_type != TokenType::Eof)
+                .map(|tok| (tok.token_type, tok.literal.to_string()))
+                .collect()
+        };
+
+        let once = tokens(snippet);
+        assert!(once.contains(&(TokenType::ProtitarJonno, "Protitar Jonno".to_string())));
+        assert!(once.contains(&(TokenType::AgeKoro, "age koro".to_string())));
+        assert!(once.contains(&(TokenType::EiHisebe, "ei hisebe".to_string())));
+        assert!(once.contains(&(TokenType::Ident, "x_y".to_string())));
+
+        let repeated = tokens(&snippet.repeat(2000));
+        assert_eq!(repeated.len(), once.len() * 2000);
+        assert!(repeated.chunks(once.len()).all(|chunk| chunk == once.as_slice()));
+    }
+
+    #[test]
+    fn test_keyword_aliases() {
+        let aliases = HashMap::from([
+            ("যদি".to_string(), "jodi".to_string()),
+            ("প্রতিটার জন্য".to_string(), "protitar jonno".to_string()),
+        ]);
+        let mut lexer = Lexer::new("যদি প্রতিটার জন্য যদি_না".to_string()).with_keyword_aliases(aliases);
+
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::Jodi);
+        assert_eq!(&*tok.literal, "jodi");
+
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::ProtitarJonno);
+        assert_eq!(&*tok.literal, "protitar jonno");
+
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::Ident);
+        assert_eq!(&*tok.literal, "যদি_না");
+
+        // Aliases longer than two words are read to the end, and their leading words
+        // stay identifiers when the rest doesn't follow
+        let aliases = HashMap::from([("हर एक के लिए".to_string(), "protitar jonno".to_string())]);
+        let mut lexer = Lexer::new("हर एक के लिए हर एक".to_string()).with_keyword_aliases(aliases);
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::ProtitarJonno);
+        assert_eq!(&*tok.literal, "protitar jonno");
+        for word in ["हर", "एक"] {
+            let tok = lexer.next_token();
+            assert_eq!((tok.token_type, &*tok.literal), (TokenType::Ident, word));
         }
+        assert_eq!(lexer.next_token().token_type, TokenType::Eof);
+
+        // Without aliases Bengali words are plain identifiers
+        assert_eq!(first_token("যদি").token_type, TokenType::Ident);
+    }
+
+    #[test]
+    fn test_bengali_string_literal() {
+        let tok = first_token("\"বাংলা\"");
+        assert_eq!(tok.token_type, TokenType::String);
+        assert_eq!(&*tok.literal, "বাংলা");
+
+        let tok = first_token("\"আমি \\\"B+\\\" ভালোবাসি\\n\"");
+        assert_eq!(&*tok.literal, "আমি \"B+\" ভালোবাসি\n");
+    }
+
+    #[test]
+    fn test_bengali_char_literal() {
+        let tok = first_token("'আ'");
+        assert_eq!(tok.token_type, TokenType::Char);
+        assert_eq!(&*tok.literal, "আ");
+
+        let tok = first_token("'\\n'");
+        assert_eq!(&*tok.literal, "\n");
     }
-}
 
+    #[test]
+    fn test_unicode_escapes() {
+        let tok = first_token("\"\\u{09AC}\"");
+        assert_eq!(tok.token_type, TokenType::String);
+        assert_eq!(&*tok.literal, "ব");
+
+        let tok = first_token("\"\\u{9AC}\\u{9be}\\u{982}la\\u{1F600}\"");
+        assert_eq!(&*tok.literal, "বাংla😀");
+
+        let tok = first_token("'\\u{09AC}'");
+        assert_eq!(tok.token_type, TokenType::Char);
+        assert_eq!(&*tok.literal, "ব");
+    }
+
+    #[test]
+    fn test_byte_and_null_escapes() {
+        assert_eq!(&*first_token("\"\\x41\\x62c\"").literal, "Abc");
+        assert_eq!(&*first_token("'\\x41'").literal, "A");
+        assert_eq!(&*first_token("\"a\\0b\"").literal, "a\0b");
+        assert_eq!(&*first_token("'\\0'").literal, "\0");
+        assert_eq!(&*first_token("'\\''").literal, "'");
+        assert_eq!(&*first_token("\"\\\\\"").literal, "\\");
+
+        for source in ["\"\\x4\"", "\"\\xZZ\"", "\"\\x80\"", "'\\x'"] {
+            assert_eq!(first_token(source).token_type, TokenType::Illegal, "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_unknown_escape_is_rejected() {
+        let mut lexer = Lexer::new("dhoro s = \"a\\qb\"; 'x'".to_string());
+        for _ in 0..3 {
+            lexer.next_token();
+        }
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::Illegal);
+        assert_eq!(&*tok.literal, "Unknown escape sequence \\q at line 1, column 13");
+        assert_eq!(lexer.next_token().token_type, TokenType::Semicolon);
+        assert_eq!(lexer.next_token().token_type, TokenType::Char);
+
+        let mut lexer = Lexer::new("'\\q' + 1".to_string());
+        assert_eq!(lexer.next_token().token_type, TokenType::Illegal);
+        assert_eq!(lexer.next_token().token_type, TokenType::Plus);
+    }
+
+    #[test]
+    fn test_invalid_unicode_escapes() {
+        for source in [
+            "\"\\u{}\"",
+            "\"\\u{110000}\"",
+            "\"\\u{D800}\"",
+            "\"\\u{1234567}\"",
+            "\"\\u{12x}\"",
+            "'\\u{110000}'",
+            "\"\\u09AC\"",
+        ] {
+            assert_eq!(first_token(source).token_type, TokenType::Illegal, "{}", source);
+        }
+
+        // The rest of a string with a bad escape is still part of that string
+        let mut lexer = Lexer::new("\"\\u{110000} a b\" + 1".to_string());
+        let tok = lexer.next_token();
+        assert_eq!(tok.token_type, TokenType::Illegal);
+        assert!(tok.literal.contains("110000"), "{}", tok.literal);
+        assert_eq!(lexer.next_token().token_type, TokenType::Plus);
+    }
+
+    #[test]
+    fn test_tokens_after_unicode_literal() {
+        let mut lexer = Lexer::new("\"বা\" + 'ং'".to_string());
+        assert_eq!(&*lexer.next_token().literal, "বা");
+        let plus = lexer.next_token();
+        assert_eq!(plus.token_type, TokenType::Plus);
+        assert_eq!(plus.column, 6);
+        assert_eq!(&*lexer.next_token().literal, "ং");
+        assert_eq!(lexer.next_token().token_type, TokenType::Eof);
+    }
+}
@@ -0,0 +1,188 @@
+// compiler/src/lib.rs
+
+//! The B+ interpreter as a library, for embedding B+ in other Rust programs.
+//!
+//! ```
+//! let value = bplus_compiler::run("dhoro x = 2; x + 3").unwrap();
+//! assert_eq!(value, bplus_compiler::Object::Integer(5));
+//! ```
+
+// Interpreter modules, shared with the bplus-compiler binary
+pub mod stdlib;
+pub mod ast;
+pub mod environment;
+pub mod evaluator;
+pub mod lexer;
+pub mod object;
+pub mod parser;
+pub mod token;
+pub mod error;
+pub mod config;
+pub mod type_checker;
+pub mod codegen;
+pub mod compiler;
+pub mod vm;
+#[path = "extension-manager.rs"]
+pub mod extension_manager;
+
+pub use environment::Environment;
+pub use error::BPlusError;
+pub use evaluator::{run_capturing, Evaluator};
+pub use lexer::Lexer;
+pub use object::Object;
+pub use parser::Parser;
+
+use error::{ErrorManager, ErrorPosition, ErrorType};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Name of this crate, as printed by `--version`.
+pub const NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Version of this crate, as printed by `--version`.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Call depth limit for [`run`], [`eval_str`] and [`run_capturing`], which evaluate on the
+/// caller's stack: low enough that a debug build on an 8 MB main thread reports a
+/// "stack overflow" error before the real stack runs out. Hosts that need deeper
+/// recursion can run an [`Evaluator`] on a thread with a bigger stack and raise the
+/// limit with `set_max_call_depth`, as the command line interpreter does.
+pub const LIBRARY_MAX_CALL_DEPTH: usize = 150;
+
+/// Start of the message for a panic caught by [`catch_panic`].
+pub const PANIC_ERROR: &str = "interpreter panicked";
+
+/// Runs B+ source in a fresh environment and returns the value of its last statement.
+/// Booleans come back as the strings "Ha"/"Na", the same way the REPL shows them.
+/// A program with syntax errors fails with the first one; a runtime error carries
+/// the line and column where it was raised. Calls nest at most [`LIBRARY_MAX_CALL_DEPTH`] deep.
+pub fn run(source: &str) -> Result<Object, BPlusError> {
+    let mut parser = Parser::new(Lexer::new(source.to_string()));
+    let program = parser.parse_program();
+    if let Some(syntax_error) = parser.errors.first() {
+        return Err(BPlusError::syntax(syntax_error, None));
+    }
+
+    let mut evaluator = Evaluator::new();
+    evaluator.set_max_call_depth(LIBRARY_MAX_CALL_DEPTH);
+    let evaluated = catch_panic(|| evaluator.eval(program, &mut Environment::new()))?;
+    if let Object::Error(message) = &evaluated {
+        return Err(runtime_error(&evaluator, &evaluated, message, None));
+    }
+    Ok(evaluated)
+}
+
+/// Runs B+ source like [`run`] and renders the outcome as text: the resulting
+/// value, or the error message the command line interpreter would print.
+pub fn eval_str(source: &str) -> String {
+    match run(source) {
+        Ok(value) => value.to_string(),
+        Err(error) => {
+            let mut error_manager = ErrorManager::new();
+            error_manager.set_color(false);
+            error_manager.format_error(&error)
+        }
+    }
+}
+
+/// Runs a B+ file in an existing environment, so that what it defines stays
+/// available afterwards. The REPL's `load` command uses this, passing the active
+/// language pack's `keyword_aliases` so the file can use the same keywords as the session.
+pub fn load_file(
+    evaluator: &mut Evaluator,
+    env: &mut Environment,
+    path: &Path,
+    keyword_aliases: HashMap<String, String>,
+) -> Result<Object, BPlusError> {
+    let file = path.display().to_string();
+    let source = std::fs::read_to_string(path).map_err(|_| BPlusError::new(ErrorType::FileNotFound(file.clone())))?;
+
+    let mut parser = Parser::new(Lexer::new(source).with_keyword_aliases(keyword_aliases));
+    let program = parser.parse_program();
+    if let Some(syntax_error) = parser.errors.first() {
+        return Err(BPlusError::syntax(syntax_error, Some(&file)));
+    }
+
+    let evaluated = catch_panic(|| evaluator.eval_file(program, path, env))?;
+    if let Object::Error(message) = &evaluated {
+        return Err(runtime_error(evaluator, &evaluated, message, Some(&file)));
+    }
+    Ok(evaluated)
+}
+
+/// Runs `f`, turning a panic inside it into an internal error, so that a bug in the
+/// interpreter fails the program being run instead of the REPL or host application.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, BPlusError> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+        .map_err(|payload| BPlusError::new(ErrorType::InternalError(panic_message(payload.as_ref()))))
+}
+
+/// Message for a caught panic: [`PANIC_ERROR`] followed by what the panic said.
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let reason = payload
+        .downcast_ref::<&str>()
+        .map(|reason| reason.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_string());
+    format!("{}: {}", PANIC_ERROR, reason)
+}
+
+// The counts in "wrong number of arguments: expected 2, got 3"; variadic
+// functions ("expected at least 2") don't fit WrongArgumentCount and stay as they are
+fn argument_counts(message: &str) -> Option<(usize, usize)> {
+    let counts = message.strip_prefix(evaluator::WRONG_ARGUMENT_COUNT_ERROR)?.strip_prefix(": expected ")?;
+    let (expected, got) = counts.split_once(", got ")?;
+    Some((expected.parse().ok()?, got.parse().ok()?))
+}
+
+/// Turns an evaluation error into a BPlusError, positioned where `evaluator` recorded it
+pub fn runtime_error(evaluator: &Evaluator, evaluated: &Object, message: &str, file: Option<&str>) -> BPlusError {
+    let error_type = if message.starts_with(evaluator::STACK_OVERFLOW_ERROR) {
+        ErrorType::StackOverflow
+    } else if let Some((expected, got)) = argument_counts(message) {
+        ErrorType::WrongArgumentCount(expected, got)
+    } else {
+        ErrorType::InternalError(message.to_string())
+    };
+    match evaluator.error_span(evaluated) {
+        Some(span) => {
+            let position = match file {
+                Some(file) => ErrorPosition::with_file(span.line, span.column, file.to_string()),
+                None => ErrorPosition::new(span.line, span.column),
+            };
+            BPlusError::with_position(error_type, position)
+        }
+        None => BPlusError::new(error_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panics_become_internal_errors() {
+        let error = run("dhoro x = 1;\n__panic()").unwrap_err();
+        assert_eq!(
+            error.error_type,
+            ErrorType::InternalError(format!("{}: __panic called", PANIC_ERROR))
+        );
+
+        // The evaluator can still be used after a panic was caught
+        let mut evaluator = Evaluator::new();
+        let mut env = Environment::new();
+        let program = Parser::new(Lexer::new("__panic()".to_string())).parse_program();
+        assert!(catch_panic(|| evaluator.eval(program, &mut env)).is_err());
+        evaluator.recover_from_panic();
+        let program = Parser::new(Lexer::new("2 + 3".to_string())).parse_program();
+        assert_eq!(evaluator.eval(program, &mut env), Object::Integer(5));
+    }
+
+    #[test]
+    fn test_run_capturing_catches_panics() {
+        assert_eq!(
+            run_capturing("dekhao(1);\n__panic();\ndekhao(2);"),
+            format!("1\nError: {}: __panic called\n", PANIC_ERROR)
+        );
+    }
+}
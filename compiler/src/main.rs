@@ -1,74 +1,447 @@
 // compiler/src/main.rs
 
-// Import all necessary modules for the compiler
-mod stdlib;
-mod ast;
-mod environment;
-mod evaluator;
-mod lexer;
-mod object;
-mod parser;
-mod token;
-mod error;
-#[path = "extension-manager.rs"]
-mod extension_manager;
-
-use environment::Environment;
-use lexer::Lexer;
-use parser::Parser;
-use error::{BPlusError, ErrorType, ErrorManager};
-use extension_manager::ExtensionManager;
+// The interpreter lives in the bplus_compiler library; this binary is its command line front end
+use bplus_compiler::{catch_panic, load_file, object, panic_message, runtime_error, stdlib, token};
+use bplus_compiler::codegen::CodeGenerator;
+use bplus_compiler::compiler::Compiler;
+use bplus_compiler::config::Config;
+use bplus_compiler::environment::Environment;
+use bplus_compiler::evaluator::Evaluator;
+use bplus_compiler::lexer::Lexer;
+use bplus_compiler::parser::{split_error_position, Parser};
+use bplus_compiler::error::{BPlusError, ColorChoice, ErrorType};
+use bplus_compiler::extension_manager::ExtensionManager;
+use bplus_compiler::type_checker::TypeChecker;
+use bplus_compiler::vm::Vm;
 
+use std::collections::HashMap;
 use std::env;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
-use log::{error, info, warn};
-
-/// Function to check if all curly brackets in input are balanced
-fn brackets_balanced(input: &str) -> bool {
-    let mut count = 0;
-    for c in input.chars() {
-        if c == '{' {
-            count += 1;
-        } else if c == '}' {
-            if count == 0 {
-                return false;
+use log::{debug, error, info, warn};
+
+/// Where a BracketScanner stopped at the end of the text fed so far
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum ScanState {
+    #[default]
+    Code,
+    String { escaped: bool },        // Inside "...", after a backslash when escaped
+    BlockComment { end: char, previous: char }, // Inside /* */ or (* *); end is '/' or ')'
+    Mismatched,                      // A wrong or unopened closing bracket: complete, the parser reports it
+}
+
+/// Checks if the REPL input is complete: every (, [ and { is closed and no string or
+/// block comment is left open. Brackets inside strings, chars and comments don't count.
+/// A closing bracket of the wrong kind, or one with nothing open, counts as complete
+/// so the parser can report it instead of the REPL waiting for more lines. The REPL
+/// feeds it each line once as it is read, instead of rescanning the whole buffer
+/// after every line of a pasted block.
+#[derive(Debug, Default)]
+struct BracketScanner {
+    open: Vec<char>,
+    state: ScanState,
+}
+
+impl BracketScanner {
+    /// Scans more input; line comments end with the text or its first newline
+    fn feed(&mut self, text: &str) {
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match self.state {
+                ScanState::Mismatched => return,
+                ScanState::String { escaped: true } => self.state = ScanState::String { escaped: false },
+                ScanState::String { escaped: false } => match c {
+                    '\\' => self.state = ScanState::String { escaped: true },
+                    '"' => self.state = ScanState::Code,
+                    _ => {}
+                },
+                ScanState::BlockComment { end, previous } => {
+                    self.state = if previous == '*' && c == end {
+                        ScanState::Code
+                    } else {
+                        ScanState::BlockComment { end, previous: c }
+                    };
+                }
+                ScanState::Code => self.scan_code(c, &mut chars),
+            }
+        }
+    }
+
+    fn scan_code(&mut self, c: char, chars: &mut std::iter::Peekable<std::str::Chars>) {
+        match c {
+            '"' => self.state = ScanState::String { escaped: false },
+            '\'' => {
+                // Skip a char literal like '(' or '\n'; a lone apostrophe (it's) is just text
+                let mut lookahead = chars.clone();
+                let length = match lookahead.next() {
+                    Some('\\') => lookahead.nth(1).filter(|q| *q == '\'').map(|_| 3),
+                    Some(_) => lookahead.next().filter(|q| *q == '\'').map(|_| 2),
+                    None => None,
+                };
+                if let Some(length) = length {
+                    chars.nth(length - 1);
+                }
+            }
+            '/' if chars.peek() == Some(&c) => skip_line(chars),
+            // Same rule as the lexer: --5 and --(x) are double negation, not comments
+            '-' if chars.peek() == Some(&c)
+                && !chars.clone().nth(1).is_some_and(|after| after.is_ascii_digit() || after == '(') =>
+            {
+                skip_line(chars)
+            }
+            '#' => skip_line(chars),
+            '/' | '(' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let end = if c == '/' { '/' } else { ')' };
+                self.state = ScanState::BlockComment { end, previous: ' ' };
             }
-            count -= 1;
+            '(' | '[' | '{' => self.open.push(c),
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if self.open.pop() != Some(expected) {
+                    self.state = ScanState::Mismatched;
+                }
+            }
+            _ => {}
         }
     }
-    count == 0
+
+    /// Whether the input so far can be handed to the parser
+    fn is_complete(&self) -> bool {
+        match self.state {
+            ScanState::Code => self.open.is_empty(),
+            ScanState::Mismatched => true,
+            _ => false,
+        }
+    }
+}
+
+/// Lines typed into the REPL, collected until they form complete input. Each line is
+/// scanned once, so a complete line runs as soon as it is entered.
+#[derive(Debug, Default)]
+struct ReplBuffer {
+    source: String,
+    scanner: BracketScanner,
+}
+
+impl ReplBuffer {
+    /// Adds a line; returns the buffered source once it is complete, leaving the buffer empty
+    fn push_line(&mut self, line: &str) -> Option<String> {
+        self.source.push_str(line);
+        self.scanner.feed(line);
+        if !self.scanner.is_complete() {
+            return None;
+        }
+        self.scanner = BracketScanner::default();
+        Some(std::mem::take(&mut self.source))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+}
+
+// Skips the rest of a single-line comment
+fn skip_line(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    for c in chars.by_ref() {
+        if c == '\n' {
+            break;
+        }
+    }
+}
+
+/// One-line descriptions shown by the REPL's `help <name>`
+const HELP_TOPICS: &[(&str, &str)] = &[
+    ("dhoro", "dhoro x = 5; declares a variable"),
+    ("fn", "fn(a, b) { ferot a + b; } creates a function (also kaj)"),
+    ("ferot", "ferot value; returns from a function"),
+    ("jodi", "jodi (shorto) { ... } nahoy { ... } runs code when a condition holds"),
+    ("nahoy", "the else branch of jodi"),
+    ("jotokhon", "jotokhon (shorto) { ... } repeats while a condition holds"),
+    ("protitar jonno", "protitar jonno (x jekhane list) { ... } loops over an array or string"),
+    ("thamo", "thamo; leaves the current loop"),
+    ("choluk", "choluk; skips to the next loop iteration"),
+    ("ebong", "logical and, also written &&"),
+    ("othoba", "logical or, also written ||"),
+    ("noy", "logical not, same as !"),
+    ("import koro", "import koro \"file.bp\" ei hisebe naam; binds a file's definitions as a hash"),
+    ("dekhao", "dekhao(a, b, ...) prints its arguments separated by spaces"),
+    ("input", "input(prompt) reads a line from the user"),
+    ("input_int", "input_int(prompt) reads a whole number, asking again until one is entered"),
+    ("input_float", "input_float(prompt) reads a number as a float, asking again until one is entered"),
+    ("assert", "assert(condition, message?) stops with an error unless the condition holds"),
+    ("assert_eq", "assert_eq(a, b) stops with an error unless a and b are equal"),
+    ("range", "range(end), range(start, end) or range(start, end, step) gives an array of integers"),
+];
+
+/// Text printed by the REPL's help command: an overview, or the description of one name
+fn render_help(env: &Environment, topic: Option<&str>) -> String {
+    if let Some(name) = topic {
+        if let Some((_, description)) = HELP_TOPICS.iter().find(|(topic, _)| *topic == name) {
+            return format!("{}: {}", name, description);
+        }
+        if let Some(token_type) = token::KEYWORDS.get(name) {
+            return format!("{}: keyword ({:?})", name, token_type);
+        }
+        if env.builtin_names().iter().any(|builtin| builtin == name) {
+            return format!("{}: builtin function", name);
+        }
+        if stdlib::get_available_modules().contains(&name) {
+            return format!("{}: standard library module, load it with 'anyo {}'", name, name);
+        }
+        return format!("'{}' er jonno kono help nei", name);
+    }
+
+    // Comment markers like "//" are in KEYWORDS too, but aren't words to look up
+    let mut keywords: Vec<&str> = token::KEYWORDS
+        .keys()
+        .copied()
+        .filter(|keyword| keyword.starts_with(char::is_alphabetic))
+        .collect();
+    keywords.sort_unstable();
+    [
+        "Commands:".to_string(),
+        "  help [naam]          show this overview or describe one keyword or builtin".to_string(),
+        "  load <file.bp>       run a file in this session".to_string(),
+        "  anyo <module>        load a standard library module".to_string(),
+        "  modules              list the standard library modules".to_string(),
+        "  module info <name>   list the functions a module provides".to_string(),
+        "  prelude              load the default modules (as with --prelude)".to_string(),
+        "  langpack <name>      switch language pack (langpack list, langpack compile)".to_string(),
+        "  prosthan             leave the REPL".to_string(),
+        String::new(),
+        format!("Keywords: {}", keywords.join(", ")),
+        String::new(),
+        format!("Builtins: {}", env.builtin_names().join(", ")),
+        String::new(),
+        format!("Modules: {}", stdlib::get_available_modules().join(", ")),
+    ]
+    .join("\n")
+}
+
+/// Command-line options: flags come before the script name, everything after it goes to the script
+#[derive(Debug, Default, PartialEq)]
+struct CliOptions {
+    check: bool,              // --check: run the type checker before evaluating
+    ast: bool,                // --ast: print the parsed program instead of running it
+    json_errors: bool,        // --json-errors: print errors as JSON objects for tools
+    prelude: Option<bool>,    // --prelude/--no-prelude: whether to load the default stdlib modules before running
+    version: bool,            // -V/--version: print the version and exit
+    time: bool,               // --time: report lex, parse and eval durations on stderr
+    vm: bool,                 // --vm: run on the bytecode VM instead of the tree-walking evaluator
+    color: Option<ColorChoice>, // --color=auto|always|never (--no-color): when to colorize errors
+    language: Option<String>, // --lang NAME: language pack to activate on startup
+    max_call_depth: Option<usize>, // --max-call-depth N: limit on nested function calls
+    eval: Option<String>,     // -e/--eval: source given inline instead of a script file
+    script: Option<String>,   // Script to run; REPL mode when absent
+    script_args: Vec<String>, // Arguments passed through to the script's args()
+}
+
+/// Function to parse command line arguments (without the program name)
+fn parse_cli_args(args: &[String]) -> CliOptions {
+    let mut options = CliOptions::default();
+    let mut rest = args.iter();
+
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--check" => options.check = true,
+            "--ast" => options.ast = true,
+            "--json-errors" => options.json_errors = true,
+            "--prelude" => options.prelude = Some(true),
+            "--no-prelude" => options.prelude = Some(false),
+            "-V" | "--version" => options.version = true,
+            "--time" => options.time = true,
+            "--vm" => options.vm = true,
+            "-e" | "--eval" => match rest.next() {
+                Some(code) => options.eval = Some(code.clone()),
+                None => eprintln!("'{}' er pore code dite hobe", arg),
+            },
+            "--no-color" => options.color = Some(ColorChoice::Never),
+            "--color" => options.color = parse_color_choice(arg, rest.next().map(String::as_str)),
+            flag if flag.starts_with("--color=") => options.color = parse_color_choice("--color", flag.strip_prefix("--color=")),
+            "--lang" => match rest.next() {
+                Some(name) => options.language = Some(name.clone()),
+                None => eprintln!("'{}' er pore language pack er nam dite hobe", arg),
+            },
+            "--max-call-depth" => match rest.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(depth)) => options.max_call_depth = Some(depth),
+                _ => eprintln!("'{}' er pore ekti shonkha dite hobe", arg),
+            },
+            flag if flag.starts_with("--") => {
+                warn!("Unknown option ignored: {}", flag);
+                eprintln!("Ojana option '{}' - bad dewa holo", flag);
+            }
+            _ if options.eval.is_some() => {
+                // With inline code everything left over belongs to args()
+                options.script_args = std::iter::once(arg).chain(rest).cloned().collect();
+                break;
+            }
+            _ => {
+                options.script = Some(arg.clone());
+                options.script_args = rest.cloned().collect();
+                break;
+            }
+        }
+    }
+    options
+}
+
+/// Function to read the value of --color; an unknown value is reported and left unset
+fn parse_color_choice(flag: &str, value: Option<&str>) -> Option<ColorChoice> {
+    let choice = value.and_then(ColorChoice::from_name);
+    if choice.is_none() {
+        eprintln!("'{}' er man auto, always ba never hote hobe", flag);
+    }
+    choice
+}
+
+/// Function to fill in the options the command line left unset from the config file
+fn apply_config(options: &mut CliOptions, config: Config) {
+    options.prelude = options.prelude.or(config.prelude);
+    options.max_call_depth = options.max_call_depth.or(config.max_call_depth);
+    options.color = options.color.or(config.color);
+    options.language = options.language.take().or(config.language);
+}
+
+/// Function to parse source code and render it back as formatted B+ source
+fn render_ast(source: &str, keyword_aliases: HashMap<String, String>) -> Result<String, Vec<String>> {
+    // Comments are kept so the printed tree reflects the whole source
+    let lexer = Lexer::with_comments(source.to_string()).with_keyword_aliases(keyword_aliases);
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    if !parser.errors.is_empty() {
+        return Err(parser.errors);
+    }
+    Ok(CodeGenerator::new().generate(&program))
+}
+
+/// Function to print the parsed program (--ast mode)
+fn print_ast_with_error_manager(source: &str, extension_manager: &ExtensionManager) -> Result<(), ()> {
+    let error_manager = extension_manager.get_error_manager();
+    match render_ast(source, extension_manager.keyword_aliases()) {
+        Ok(rendered) => {
+            print!("{}", rendered);
+            Ok(())
+        }
+        Err(errors) => {
+            for rust_error in errors {
+                error_manager.print_error(&BPlusError::syntax(&rust_error, None));
+            }
+            Err(())
+        }
+    }
+}
+
+/// Function to create an evaluator configured from the command line options
+fn new_evaluator(options: &CliOptions, extension_manager: &ExtensionManager) -> Evaluator {
+    let mut evaluator = Evaluator::new();
+    evaluator.set_keyword_aliases(extension_manager.keyword_aliases());
+    if let Some(depth) = options.max_call_depth {
+        evaluator.set_max_call_depth(depth);
+    }
+    // Imports in a script are relative to the script; in -e code and the REPL, to the working directory
+    if let (Some(script), None) = (&options.script, &options.eval) {
+        evaluator.set_script_path(Path::new(script));
+    }
+    evaluator
 }
 
 /// Function to run source code with error management and evaluation
-fn run_source_with_error_manager(source: &str, error_manager: &ErrorManager) -> Result<(), ()> {
+fn run_source_with_error_manager(source: &str, options: &CliOptions, extension_manager: &ExtensionManager) -> Result<(), ()> {
+    let error_manager = extension_manager.get_error_manager();
     // Create a new environment for the program execution
     let mut env = Environment::new();
-    // Initialize lexer with source code
-    let lexer = Lexer::new(source.to_string());
-    // Create parser from lexer
-    let mut parser = Parser::new(lexer);
+    env.set_script_args(options.script_args.clone());
+    if options.prelude.unwrap_or(false) {
+        stdlib::load_default_modules(&mut env);
+    }
+    // Initialize lexer with source code, accepting the active language pack's keywords
+    let lexer = Lexer::new(source.to_string()).with_keyword_aliases(extension_manager.keyword_aliases());
+    // Create parser from lexer; with --time it also measures the lexer's share of parsing
+    let mut parser = if options.time { Parser::with_lex_timing(lexer) } else { Parser::new(lexer) };
     // Parse the entire program into AST
+    let parse_start = Instant::now();
     let program = parser.parse_program();
+    if options.time {
+        let lexing = parser.lexing_time().unwrap_or_default();
+        report_time("lex", lexing);
+        report_time("parse", parse_start.elapsed().saturating_sub(lexing));
+    }
 
     // If parser has errors, print them and return error
     if !parser.errors.is_empty() {
+        let file = options.script.as_deref().filter(|_| options.eval.is_none());
         for rust_error in parser.errors {
-            let bp_error = BPlusError::new(ErrorType::InvalidStatement(rust_error));
-            error_manager.print_error(&bp_error);
+            error_manager.print_error(&BPlusError::syntax(&rust_error, file));
         }
         return Err(());
     }
 
+    // With --check, refuse to run programs that fail type checking
+    if options.check {
+        if let Err(type_errors) = TypeChecker::from_environment(&env).check(&program) {
+            let file = options.script.as_deref().filter(|_| options.eval.is_none());
+            for type_error in type_errors {
+                let error = match type_error.span {
+                    Some(span) => BPlusError::at(type_error.error_type, span.line, span.column, file),
+                    None => BPlusError::new(type_error.error_type),
+                };
+                error_manager.print_error(&error);
+            }
+            return Err(());
+        }
+    }
+
     // Evaluate the parsed program and print result or errors
-    let evaluated = evaluator::eval(program, &mut env);
+    let mut evaluator = new_evaluator(options, extension_manager);
+    let eval_start = Instant::now();
+    // A panic inside the interpreter is reported like any other error
+    let evaluated = catch_panic(|| {
+        if options.vm {
+            // The program parsed, so what the VM can't run is a runtime error rather than a syntax one
+            let bytecode = Compiler::new().compile(&program).map_err(|message| {
+                let file = options.script.as_deref().filter(|_| options.eval.is_none());
+                match split_error_position(&message) {
+                    (Some((line, column)), rest) => {
+                        BPlusError::at(ErrorType::InternalError(rest.to_string()), line, column, file)
+                    }
+                    (None, _) => BPlusError::new(ErrorType::InternalError(message)),
+                }
+            })?;
+            let mut vm = Vm::new();
+            if let Some(depth) = options.max_call_depth {
+                vm.set_max_call_depth(depth);
+            }
+            Ok(vm.run(&bytecode, &env))
+        } else {
+            Ok(evaluator.eval(program, &mut env))
+        }
+    })
+    .and_then(|evaluated| evaluated);
+    let evaluated = match evaluated {
+        Ok(evaluated) => evaluated,
+        Err(error) => {
+            error_manager.print_error(&error);
+            return Err(());
+        }
+    };
+    if options.time {
+        report_time("eval", eval_start.elapsed());
+    }
     if evaluated != object::Object::Null {
         match &evaluated {
             object::Object::Error(msg) => {
-                let bp_error = BPlusError::new(ErrorType::InternalError(msg.clone()));
-                error_manager.print_error(&bp_error);
+                // Inline -e code has no file name to point at
+                let file = options.script.as_deref().filter(|_| options.eval.is_none());
+                error_manager.print_error(&runtime_error(&evaluator, &evaluated, msg, file));
                 return Err(());
             }
             _ => println!("{}", evaluated),
@@ -77,23 +450,77 @@ fn run_source_with_error_manager(source: &str, error_manager: &ErrorManager) ->
     Ok(())
 }
 
+/// Print how long one phase took (--time), on stderr so program output stays clean
+fn report_time(phase: &str, duration: Duration) {
+    eprintln!("{:<6} {:>10.3} ms", format!("{}:", phase), duration.as_secs_f64() * 1000.0);
+}
+
 /// Initialize logging for the compiler using env_logger
 fn init_logging() {
     env_logger::init();
 }
 
+/// Stack size for the interpreter thread: the tree-walking evaluator needs room for
+/// evaluator::DEFAULT_MAX_CALL_DEPTH nested B+ calls, well beyond the default main thread stack
+const INTERPRETER_STACK_SIZE: usize = 256 * 1024 * 1024;
+
 /// Main entry point of the compiler/interpreter executable
 fn main() {
+    // Panics are reported as B+ errors (see catch_panic), or below if one escapes, so the
+    // default hook's message and backtrace are only logged, visible with RUST_LOG=debug
+    std::panic::set_hook(Box::new(|info| debug!("{}", info)));
+
+    let interpreter = std::thread::Builder::new()
+        .name("bplus".to_string())
+        .stack_size(INTERPRETER_STACK_SIZE)
+        .spawn(run_cli)
+        .expect("failed to start interpreter thread");
+    if let Err(payload) = interpreter.join() {
+        eprintln!("{}", panic_message(payload.as_ref()));
+        std::process::exit(101);
+    }
+}
+
+/// Function to run the command line interface (file, -e, --ast or REPL mode)
+fn run_cli() {
     // Initialize logging system
     init_logging();
 
     info!("Starting B+ compiler/interpreter...");
 
+    // Collect command line arguments
+    let args: Vec<String> = env::args().collect();
+    let mut options = parse_cli_args(&args[1..]);
+
+    if options.version {
+        println!("{} {}", bplus_compiler::NAME, bplus_compiler::VERSION);
+        return;
+    }
+
+    // Defaults from bplus.toml or ~/.bplusrc, for whatever the flags didn't set
+    match Config::load() {
+        Ok(config) => apply_config(&mut options, config),
+        Err(e) => {
+            warn!("Config file ignored: {}", e);
+            eprintln!("Config file e bhul, bad dewa holo - {}", e);
+        }
+    }
+
     // Initialize the extension system to manage language packs
     let mut extension_manager = ExtensionManager::default();
+    let initialized = extension_manager.initialize();
 
-    // Attempt to initialize extensions and print welcome messages
-    match extension_manager.initialize() {
+    // A language chosen with --lang or in the config replaces the one from extensions.config
+    if let (Ok(()), Some(language)) = (&initialized, &options.language) {
+        if let Err(e) = extension_manager.activate_language_pack(language) {
+            eprintln!("{}", e);
+        }
+    }
+
+    // Print welcome messages, or report why extensions couldn't be initialized
+    match initialized {
+        // Keep --ast output limited to the program itself
+        Ok(()) if options.ast || options.eval.is_some() => {}
         Ok(()) => {
             // If active language pack present, print welcome message and example usage
             if let Some(pack) = extension_manager.get_active_language_pack() {
@@ -132,26 +559,58 @@ fn main() {
         }
     }
 
-    // Collect command line arguments
-    let args: Vec<String> = env::args().collect();
+    // Colorize errors as asked, by default only when stderr is a terminal
+    extension_manager
+        .get_error_manager_mut()
+        .set_color(options.color.unwrap_or_default().enabled(io::stderr().is_terminal()));
+
+    // Machine-readable diagnostics for editors and other tools
+    if options.json_errors {
+        extension_manager.get_error_manager_mut().set_json_output(true);
+    }
+
+    // Run inline code from -e and exit with its status
+    if let Some(code) = &options.eval {
+        let result = run_source_with_error_manager(code, &options, &extension_manager);
+        std::process::exit(if result.is_ok() { 0 } else { 1 });
+    }
+
+    if options.ast && options.script.is_none() {
+        eprintln!("Usage: bplus --ast <file>");
+        return;
+    }
 
     // If filename argument provided, run the file and exit
-    if args.len() > 1 {
-        let filename = &args[1];
+    if let Some(filename) = &options.script {
         let path = Path::new(filename);
 
-        match fs::read_to_string(path) {
+        let result = match fs::read_to_string(path) {
+            Ok(source) if options.ast => {
+                let result = print_ast_with_error_manager(&source, &extension_manager);
+                if result.is_err() {
+                    error!("Could not parse source file: {}", filename);
+                }
+                result
+            }
             Ok(source) => {
-                if let Err(_) = run_source_with_error_manager(&source, extension_manager.get_error_manager()) {
+                let result = run_source_with_error_manager(&source, &options, &extension_manager);
+                if result.is_err() {
                     error!("Error occurred while running source file: {}", filename);
                 }
+                result
             }
             Err(e) => {
                 // File read error handling
                 let bp_error = BPlusError::new(ErrorType::FileNotFound(filename.clone()));
                 extension_manager.get_error_manager().print_error(&bp_error);
                 error!("Failed to read file '{}': {}", filename, e);
+                Err(())
             }
+        };
+
+        // Non-zero exit status so shells, CI and Makefiles can see the failure
+        if result.is_err() {
+            std::process::exit(1);
         }
         return;
     }
@@ -165,9 +624,13 @@ fn main() {
 
     println!("{}", repl_start_msg);
 
-    // Initialize environment for REPL
+    // Initialize environment and evaluator for REPL
     let mut env = Environment::new();
-    let mut input_buffer = String::new();
+    if options.prelude.unwrap_or(false) {
+        stdlib::load_default_modules(&mut env);
+    }
+    let mut evaluator = new_evaluator(&options, &extension_manager);
+    let mut input_buffer = ReplBuffer::default();
 
     // Start REPL loop to read input lines until exit command
     loop {
@@ -207,12 +670,12 @@ fn main() {
             break;
         }
 
-        // Handle import command inside REPL: anyo or import
-        if trimmed_line.starts_with("anyo ") || trimmed_line.starts_with("import ") {
+        // Handle import command inside REPL: anyo or import (file imports with a quoted path are B+ code)
+        if (trimmed_line.starts_with("anyo ") || trimmed_line.starts_with("import ")) && !trimmed_line.contains('"') {
             let parts: Vec<&str> = trimmed_line.split_whitespace().collect();
             if parts.len() >= 2 {
                 let module_name = parts[1];
-                match crate::stdlib::load_stdlib_module(&mut env, module_name) {
+                match stdlib::load_stdlib_module(&mut env, module_name) {
                     Ok(()) => {
                         info!("Module '{}' loaded successfully", module_name);
                     }
@@ -225,6 +688,42 @@ fn main() {
             continue;
         }
 
+        // Show the command overview, or describe one name: help [name] or :help [name]
+        if let Some(rest) = trimmed_line.strip_prefix(':').unwrap_or(trimmed_line).strip_prefix("help") {
+            if rest.is_empty() || rest.starts_with(' ') {
+                let topic = Some(rest.trim()).filter(|topic| !topic.is_empty());
+                println!("{}", render_help(&env, topic));
+                continue;
+            }
+        }
+
+        // Run a file in the current session: load <path> or :load <path>
+        if let Some(path) = trimmed_line.strip_prefix("load ").or_else(|| trimmed_line.strip_prefix(":load ")) {
+            let path = path.trim().trim_matches('"');
+            match load_file(&mut evaluator, &mut env, Path::new(path), extension_manager.keyword_aliases()) {
+                Ok(object::Object::Null) => {}
+                Ok(value) => println!("{}", value),
+                Err(bp_error) => extension_manager.get_error_manager().print_error(&bp_error),
+            }
+            continue;
+        }
+
+        // Load the default modules into the session, like starting with --prelude
+        if trimmed_line == "prelude" || trimmed_line == ":prelude" {
+            stdlib::load_default_modules(&mut env);
+            println!("Prelude loaded: {}", stdlib::DEFAULT_MODULES.join(", "));
+            continue;
+        }
+
+        // Show what a module provides: module info <name>
+        if let Some(module_name) = trimmed_line.strip_prefix("module info ") {
+            match stdlib::module_functions(module_name.trim()) {
+                Ok(functions) => println!("{}: {}", module_name.trim(), functions.join(", ")),
+                Err(e) => println!("{}", e),
+            }
+            continue;
+        }
+
         // List available modules command
         if trimmed_line == "modules" || trimmed_line == "module list" {
             println!("Available modules:");
@@ -234,13 +733,43 @@ fn main() {
             continue;
         }
 
+        // Compile a language pack source file: langpack compile <file.bplpsrc> [output.bplp]
+        if trimmed_line.starts_with("langpack compile") {
+            let parts: Vec<&str> = trimmed_line.split_whitespace().collect();
+            if parts.len() == 3 || parts.len() == 4 {
+                let source_path = Path::new(parts[2]);
+                let output_path = parts.get(3)
+                    .map(|out| Path::new(out).to_path_buf())
+                    .unwrap_or_else(|| source_path.with_extension("bplp"));
+                match extension_manager.compile_language_pack(source_path, &output_path) {
+                    Ok(pack) => println!("Language pack '{}' compile kora holo: {}", pack.language, output_path.display()),
+                    Err(e) => println!("Language pack compile korte parini: {}", e),
+                }
+            } else {
+                println!("Usage: langpack compile <file.bplpsrc> [output.bplp]");
+            }
+            continue;
+        }
+
+        // List available language packs
+        if trimmed_line == "langpack list" {
+            println!("Available language packs:");
+            for pack_name in extension_manager.available_language_packs() {
+                println!("- {}", pack_name);
+            }
+            continue;
+        }
+
         // Language pack activation command inside REPL
         if trimmed_line.starts_with("langpack ") {
             let parts: Vec<&str> = trimmed_line.split_whitespace().collect();
             if parts.len() == 2 {
                 let pack_name = parts[1];
                 match extension_manager.activate_language_pack(pack_name) {
-                    Ok(()) => println!("Language pack '{}' activate kora holo", pack_name),
+                    Ok(()) => {
+                        evaluator.set_keyword_aliases(extension_manager.keyword_aliases());
+                        println!("Language pack '{}' activate kora holo", pack_name)
+                    }
                     Err(e) => println!("Language pack activate korte parini: {}", e),
                 }
             } else {
@@ -250,45 +779,38 @@ fn main() {
             continue;
         }
 
-        // List available language packs
-        if trimmed_line == "langpack list" {
-            println!("Available language packs:");
-            println!("- english");
-            println!("- bangla-unicode");
-            continue;
-        }
-
-        // Append current input line to buffer
-        input_buffer.push_str(&line);
-
-        // Parse and evaluate when brackets balanced
-        if brackets_balanced(&input_buffer) {
-            let lexer = Lexer::new(input_buffer.clone());
+        // Append current input line to buffer; parse and evaluate once the input is complete
+        if let Some(source) = input_buffer.push_line(&line) {
+            let lexer = Lexer::new(source).with_keyword_aliases(extension_manager.keyword_aliases());
             let mut parser = Parser::new(lexer);
             let program = parser.parse_program();
 
             // Handle parsing errors if any
             if !parser.errors.is_empty() {
                 for rust_error in parser.errors {
-                    let bp_error = BPlusError::new(ErrorType::InvalidStatement(rust_error));
-                    extension_manager.get_error_manager().print_error(&bp_error);
+                    extension_manager.get_error_manager().print_error(&BPlusError::syntax(&rust_error, None));
                 }
-                input_buffer.clear();
                 continue;
             }
 
-            // Evaluate program and print results or errors
-            let evaluated = evaluator::eval(program, &mut env);
+            // Evaluate program and print results or errors; a panic ends only this input
+            let evaluated = match catch_panic(|| evaluator.eval(program, &mut env)) {
+                Ok(evaluated) => evaluated,
+                Err(error) => {
+                    evaluator.recover_from_panic();
+                    extension_manager.get_error_manager().print_error(&error);
+                    continue;
+                }
+            };
             if evaluated != object::Object::Null {
                 match &evaluated {
                     object::Object::Error(msg) => {
-                        let bp_error = BPlusError::new(ErrorType::InternalError(msg.clone()));
+                        let bp_error = runtime_error(&evaluator, &evaluated, msg, None);
                         extension_manager.get_error_manager().print_error(&bp_error);
                     }
                     _ => println!("{}", evaluated),
                 }
             }
-            input_buffer.clear();
         }
     }
 
@@ -297,6 +819,7 @@ fn main() {
         match pack.language.as_str() {
             "English" => println!("Goodbye! Thanks for using B+!"),
             "Bengali Unicode" => println!("বিদায়! বি+ ব্যবহার করার জন্য ধন্যবাদ!"),
+            "Hindi" => println!("अलविदा! बी+ का उपयोग करने के लिए धन्यवाद!"),
             _ => println!("Dhonnobad! B+ bebhar korar jonno!"),
         }
     } else {
@@ -309,12 +832,172 @@ fn main() {
 mod tests {
     use super::*;
 
+    // Whether `input` is complete REPL input, scanned in one go
+    fn brackets_balanced(input: &str) -> bool {
+        let mut scanner = BracketScanner::default();
+        scanner.feed(input);
+        scanner.is_complete()
+    }
+
     #[test]
     fn test_brackets_balanced() {
         // Test balanced brackets function for various inputs
         assert!(brackets_balanced("{ test }"));
         assert!(!brackets_balanced("{ test"));
-        assert!(!brackets_balanced("test }"));
+        assert!(brackets_balanced("test }"));
+
+        // Parentheses and square brackets keep the REPL reading too
+        assert!(!brackets_balanced("dekhao(\n"));
+        assert!(!brackets_balanced("dekhao(1,\n2"));
+        assert!(brackets_balanced("dekhao(1,\n2)\n"));
+        assert!(!brackets_balanced("dhoro a = [1,\n"));
+        assert!(!brackets_balanced("dhoro a = [1,\n[2, 3],\n"));
+        assert!(brackets_balanced("dhoro a = [1,\n[2, 3],\n4];\n"));
+
+        // Brackets in strings, chars and comments are ignored
+        assert!(brackets_balanced("dekhao(\")(\", ']', \"\\\"(\")"));
+        assert!(brackets_balanced("dekhao(1) // (\n"));
+        assert!(brackets_balanced("dekhao(1) # [\n-- {\n"));
+        assert!(brackets_balanced("/* ( */ (* [ *) x"));
+        assert!(!brackets_balanced("/* (\n"));
+        assert!(!brackets_balanced("dekhao(\"abc"));
+        assert!(brackets_balanced("dekhao {it's (x)}"));
+        assert!(brackets_balanced("['\\'', ')']"));
+        assert!(!brackets_balanced("dekhao(--(5\n"));
+
+        // A wrong or unopened closing bracket is left for the parser to report
+        assert!(brackets_balanced("dekhao(1]"));
+        assert!(brackets_balanced("dekhao(1))"));
+    }
+
+    #[test]
+    fn test_repl_buffer_runs_complete_lines_immediately() {
+        let mut buffer = ReplBuffer::default();
+        let mut evaluator = Evaluator::new();
+        let mut env = Environment::new();
+        let mut run_line = |line: &str| {
+            buffer.push_line(line).map(|source| {
+                let program = Parser::new(Lexer::new(source)).parse_program();
+                evaluator.eval(program, &mut env).to_string()
+            })
+        };
+
+        // Each single-line statement runs as soon as it is entered
+        assert_eq!(run_line("dhoro x = 1;\n"), Some("null".to_string()));
+        assert_eq!(run_line("x + 1\n"), Some("2".to_string()));
+        assert_eq!(run_line("x * 10\n"), Some("10".to_string()));
+
+        // A block waits for its closing line, then runs as a whole
+        assert_eq!(run_line("jodi (x > 0) {\n"), None);
+        assert_eq!(run_line("\"(\" + \"/*\"\n"), None);
+        assert_eq!(run_line("}\n"), Some("(/*".to_string()));
+        assert_eq!(run_line("x\n"), Some("1".to_string()));
+
+        // A stray closing bracket doesn't leave the REPL waiting for more lines
+        assert!(run_line("dekhao(1))\n").is_some());
+        assert_eq!(run_line("x\n"), Some("1".to_string()));
+    }
+
+    #[test]
+    fn test_bracket_scanner_across_lines() {
+        let mut scanner = BracketScanner::default();
+        for (line, complete) in [("dekhao(\"a\n", false), ("b)\", ", false), ("'}')\n", true)] {
+            scanner.feed(line);
+            assert_eq!(scanner.is_complete(), complete, "after {:?}", line);
+        }
+
+        let mut scanner = BracketScanner::default();
+        for (line, complete) in [("/* (\n", false), ("*/ [\n", false), ("]\n", true)] {
+            scanner.feed(line);
+            assert_eq!(scanner.is_complete(), complete, "after {:?}", line);
+        }
+    }
+
+    #[test]
+    fn test_render_help() {
+        let mut env = Environment::new();
+        stdlib::load_stdlib_module(&mut env, "math").unwrap();
+        let overview = render_help(&env, None);
+        assert!(overview.contains("dekhao"));
+        assert!(overview.contains("Modules: time, file"));
+        assert!(overview.contains("sqrt"));
+
+        assert!(render_help(&env, Some("dekhao")).starts_with("dekhao: dekhao(a, b, ...) prints"));
+        assert_eq!(render_help(&env, Some("sqrt")), "sqrt: builtin function");
+        assert_eq!(render_help(&env, Some("tobe")), "tobe: keyword (Tahole)");
+        assert!(render_help(&env, Some("json")).contains("anyo json"));
+        assert_eq!(render_help(&env, Some("ojana")), "'ojana' er jonno kono help nei");
+    }
+
+    #[test]
+    fn test_parse_cli_args() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let options = parse_cli_args(&args(&["--check", "--json-errors", "main.bp", "--check", "x"]));
+        assert!(options.check);
+        assert!(options.json_errors);
+        assert_eq!(options.script.as_deref(), Some("main.bp"));
+        assert_eq!(options.script_args, args(&["--check", "x"]));
+
+        assert_eq!(parse_cli_args(&[]), CliOptions::default());
+        assert_eq!(parse_cli_args(&args(&["--prelude", "main.bp"])).prelude, Some(true));
+        assert_eq!(parse_cli_args(&args(&["--no-prelude", "main.bp"])).prelude, Some(false));
+        assert!(parse_cli_args(&args(&["-V"])).version);
+        assert!(parse_cli_args(&args(&["--time", "main.bp"])).time);
+        assert!(parse_cli_args(&args(&["--vm", "main.bp"])).vm);
+        assert_eq!(parse_cli_args(&args(&["--max-call-depth", "50"])).max_call_depth, Some(50));
+        assert_eq!(parse_cli_args(&args(&["--max-call-depth", "onek"])).max_call_depth, None);
+        assert_eq!(parse_cli_args(&args(&["--color=always", "main.bp"])).color, Some(ColorChoice::Always));
+        assert_eq!(parse_cli_args(&args(&["--color", "never", "main.bp"])).color, Some(ColorChoice::Never));
+        assert_eq!(parse_cli_args(&args(&["--no-color", "main.bp"])).color, Some(ColorChoice::Never));
+        assert_eq!(parse_cli_args(&args(&["--lang", "hindi"])).language.as_deref(), Some("hindi"));
+        let options = parse_cli_args(&args(&["--color=rongin", "main.bp"]));
+        assert_eq!(options.color, None);
+        assert_eq!(options.script.as_deref(), Some("main.bp"));
+
+        let options = parse_cli_args(&args(&["-e", "dekhao(1)", "a", "b"]));
+        assert_eq!(options.eval.as_deref(), Some("dekhao(1)"));
+        assert_eq!(options.script, None);
+        assert_eq!(options.script_args, args(&["a", "b"]));
+    }
+
+    #[test]
+    fn test_flags_override_config() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let config = Config {
+            language: Some("english".to_string()),
+            prelude: Some(true),
+            max_call_depth: Some(500),
+            color: Some(ColorChoice::Never),
+        };
+
+        let mut options = parse_cli_args(&args(&["main.bp"]));
+        apply_config(&mut options, config.clone());
+        assert_eq!(options.language.as_deref(), Some("english"));
+        assert_eq!(options.prelude, Some(true));
+        assert_eq!(options.max_call_depth, Some(500));
+        assert_eq!(options.color, Some(ColorChoice::Never));
+
+        let mut options = parse_cli_args(&args(&[
+            "--lang", "hindi", "--no-prelude", "--max-call-depth", "50", "--color=always", "main.bp",
+        ]));
+        apply_config(&mut options, config);
+        assert_eq!(options.language.as_deref(), Some("hindi"));
+        assert_eq!(options.prelude, Some(false));
+        assert_eq!(options.max_call_depth, Some(50));
+        assert_eq!(options.color, Some(ColorChoice::Always));
+
+        // Without a config file nothing changes
+        let mut options = parse_cli_args(&args(&["main.bp"]));
+        apply_config(&mut options, Config::default());
+        assert_eq!(options, parse_cli_args(&args(&["main.bp"])));
+    }
+
+    #[test]
+    fn test_render_ast() {
+        let rendered = render_ast("dhoro x = 5;\njodi (x > 3) { dekhao(x) }", HashMap::new()).unwrap();
+        assert_eq!(rendered, "dhoro x = 5;\njodi (x > 3) {\n    dekhao(x);\n}\n");
+        assert!(render_ast("dhoro = 5;", HashMap::new()).is_err());
     }
 
     #[test]
@@ -324,4 +1007,21 @@ mod tests {
         let error_manager = ext_manager.get_error_manager();
         assert_eq!(error_manager.get_current_language(), "banglish");
     }
+
+    #[test]
+    fn test_bangla_unicode_pack_keywords() {
+        let mut ext_manager = ExtensionManager::new("test_extensions");
+        assert!(ext_manager.keyword_aliases().is_empty());
+
+        ext_manager.activate_language_pack("bangla-unicode").unwrap();
+        let aliases = ext_manager.keyword_aliases();
+        assert_eq!(aliases.get("যদি").map(String::as_str), Some("jodi"));
+        assert!(!aliases.contains_key("welcome_message"));
+
+        let mut lexer = Lexer::new("যদি (সংখ্যা > 3)".to_string()).with_keyword_aliases(aliases.clone());
+        assert_eq!(lexer.next_token().token_type, token::TokenType::Jodi);
+
+        let rendered = render_ast("ধরো সংখ্যা = 5;\nযদি (সংখ্যা > 3) { দেখাও(হ্যাঁ) }", aliases).unwrap();
+        assert_eq!(rendered, "dhoro সংখ্যা = 5;\njodi (সংখ্যা > 3) {\n    dekhao(Ha);\n}\n");
+    }
 }
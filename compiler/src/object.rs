@@ -2,9 +2,12 @@
 
 // Import necessary modules and traits
 use crate::ast::{Expression, Statement};
+use crate::compiler::CompiledFunction;
 use crate::environment::Environment;
+use crate::token::Symbol;
 use std::fmt;
 use std::io::{self, Write};
+use std::rc::Rc;
 
 // Enum representing built-in functions available in the language
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -13,6 +16,17 @@ pub enum BuiltinFunction {
     Input,
     Shomoy,
     Print, // Built-in function for printing output
+    // Builtins that call B+ functions, so the evaluator runs them instead of a native fn
+    Map,
+    Filter,
+    Reduce,
+    Sort,
+    // Builtins that read from the evaluator's input source
+    InputInt,
+    InputFloat,
+    // Panics when called, so tests can reach the evaluator's panic boundary
+    #[cfg(test)]
+    Panic,
     // Future builtins can be added here like Tarikh, FileRead, SystemInfo, etc.
 }
 
@@ -20,18 +34,36 @@ pub enum BuiltinFunction {
 #[derive(Debug, PartialEq, Clone)]
 pub enum Object {
     Integer(i64),                  // Integer values
+    Float(f64),                    // Floating point values
     Boolean(bool),                 // Boolean true or false
     String(String),                // String literals
+    Char(char),                    // Single character values
     Null,                         // Null value
     ReturnValue(Box<Object>),     // Wrapper for return statements' values
+    Break(Option<Symbol>),        // Signal raised by thamo inside a loop, with the label of the loop to leave
+    Continue(Option<Symbol>),     // Signal raised by choluk inside a loop, with the label of the loop to go on with
     BuiltinFunction(BuiltinFunction),   // Builtin function variant
     BuiltinNative(fn(Vec<Object>) -> Object), // Native builtin function pointer
     Array(Vec<Object>),           // Handle Arrays
+    Hash(Vec<(Object, Object)>),  // Key/value map, keeps insertion order
     Error(String),                // Error object containing error message
     Function {                   // User-defined function object
         parameters: Vec<Expression>, // Function parameters as AST expressions
+        defaults: Vec<Option<Expression>>, // Default value per parameter, evaluated when its argument is missing
+        variadic: bool,               // Last parameter collects any extra arguments as an array
         body: Vec<Statement>,         // Function body statements
-        env: Environment,             // Closure environment capturing variables
+        env: Environment,             // Closure environment, shared with the scope that made it
+    },
+    CompiledFunction(Rc<CompiledFunction>), // Function compiled to bytecode, run by the VM
+    AsyncFunction(Box<Object>),   // shomoy niropekkho function: calls give a Future
+    Future(Box<Object>),          // Settled result of an async call (a value or an Error), read with opekkha koro
+    RecordType {                  // Type made by type banao, called to construct records
+        name: String,
+        fields: Vec<String>,
+    },
+    Record {                      // Instance of a record type: field/value pairs tagged with the type name
+        type_name: String,
+        fields: Vec<(Object, Object)>,
     },
 }
 
@@ -40,22 +72,50 @@ impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Object::Integer(i) => write!(f, "{}", i),
+            Object::Float(x) => {
+                // Keep a decimal point on whole floats so 2.0 doesn't look like an integer
+                if x.is_finite() && x.fract() == 0.0 {
+                    write!(f, "{:.1}", x)
+                } else {
+                    write!(f, "{}", x)
+                }
+            }
             Object::Boolean(true) => write!(f, "Ha"),    // True in Bangla
             Object::Boolean(false) => write!(f, "Na"),   // False in Bangla
             Object::String(s) => write!(f, "{}", s),
+            Object::Char(c) => write!(f, "{}", c),
             Object::Null => write!(f, "null"),
             Object::ReturnValue(obj) => write!(f, "{}", obj),
+            Object::Break(None) => write!(f, "thamo"),
+            Object::Break(Some(label)) => write!(f, "thamo {}", label),
+            Object::Continue(None) => write!(f, "choluk"),
+            Object::Continue(Some(label)) => write!(f, "choluk {}", label),
             Object::Error(msg) => write!(f, "Error: {}", msg),
-            Object::Function { parameters, .. } => {
-                let params: Vec<String> = parameters.iter().map(|p| format!("{}", p)).collect();
+            Object::Function { parameters, variadic, .. } => {
+                let mut params: Vec<String> = parameters.iter().map(|p| format!("{}", p)).collect();
+                if let (true, Some(last)) = (*variadic, params.last_mut()) {
+                    last.insert_str(0, "...");
+                }
                 write!(f, "fn({}) {{ ... }}", params.join(", "))
             }
+            Object::CompiledFunction(function) => write!(f, "fn({}) {{ ... }}", function.parameters().join(", ")),
+            Object::AsyncFunction(function) => write!(f, "shomoy niropekkho {}", function),
+            Object::Future(_) => write!(f, "[future]"),
+            Object::RecordType { name, fields } => write!(f, "type {} {{ {} }}", name, fields.join(", ")),
+            Object::Record { type_name, fields } => {
+                let elems: Vec<String> = fields.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                write!(f, "{} {{{}}}", type_name, elems.join(", "))
+            }
             Object::BuiltinFunction(name) => write!(f, "[builtin: {:?}]", name),
             Object::BuiltinNative(_) => write!(f, "[native builtin function]"),
             Object::Array(elements) => {
                 let elems: Vec<String> = elements.iter().map(|e| format!("{}", e)).collect();
                 write!(f, "[{}]", elems.join(", "))
             }
+            Object::Hash(pairs) => {
+                let elems: Vec<String> = pairs.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                write!(f, "{{{}}}", elems.join(", "))
+            }
         }
     }
 }
@@ -66,9 +126,51 @@ impl Object {
     pub fn is_error(&self) -> bool {
         matches!(self, Object::Error(_))
     }
+
+    // Whether the object can be called: B+ functions (compiled for the VM too),
+    // async functions, builtins, and record types, which construct their records
+    pub fn is_callable(&self) -> bool {
+        matches!(
+            self,
+            Object::Function { .. }
+                | Object::CompiledFunction(_)
+                | Object::AsyncFunction(_)
+                | Object::RecordType { .. }
+                | Object::BuiltinFunction(_)
+                | Object::BuiltinNative(_)
+        )
+    }
+
+    // Human readable name of the object's type, used in error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Object::Integer(_) => "integer",
+            Object::Float(_) => "float",
+            Object::Boolean(_) => "boolean",
+            Object::String(_) => "string",
+            Object::Char(_) => "char",
+            Object::Null => "null",
+            Object::ReturnValue(_) => "return value",
+            Object::Break(_) | Object::Continue(_) => "loop signal",
+            Object::BuiltinFunction(_) | Object::BuiltinNative(_) => "builtin function",
+            Object::Array(_) => "array",
+            Object::Hash(_) => "hash",
+            Object::Error(_) => "error",
+            Object::Function { .. } | Object::CompiledFunction(_) | Object::AsyncFunction(_) => "function",
+            Object::Future(_) => "future",
+            Object::RecordType { .. } => "type",
+            Object::Record { .. } => "record",
+        }
+    }
+
+    // Helper method to check if Object can be used as a hash key
+    pub fn is_hashable(&self) -> bool {
+        matches!(self, Object::String(_) | Object::Char(_) | Object::Integer(_) | Object::Boolean(_))
+    }
 }
 
-// Builtin native function for input: reads line from stdin and returns String object
+// Builtin native function for input: reads line from stdin and returns String object.
+// The evaluator intercepts calls to it and reads from its own input source instead.
 pub fn builtin_input(_args: Vec<Object>) -> Object {
     print!(">> ");
     io::stdout().flush().unwrap();
@@ -85,12 +187,98 @@ pub fn builtin_input(_args: Vec<Object>) -> Object {
 
 // Builtin native function for print: prints all arguments separated by space
 pub fn builtin_print(args: Vec<Object>) -> Object {
-    let output = args.iter()
+    println!("{}", format_print_args(&args));
+    Object::Null
+}
+
+// Line printed by builtin_print for the given arguments
+pub fn format_print_args(args: &[Object]) -> String {
+    args.iter()
         .map(|obj| format!("{}", obj))
         .collect::<Vec<String>>()
-        .join(" ");
-    println!("{}", output);
-    Object::Null
+        .join(" ")
+}
+
+// Builtin native function for assert: fails with an error unless the condition is truthy,
+// using the optional second argument as the failure message
+pub fn builtin_assert(args: Vec<Object>) -> Object {
+    if args.is_empty() || args.len() > 2 {
+        return Object::Error("assert() takes a condition and an optional message".to_string());
+    }
+
+    if crate::evaluator::is_truthy(&args[0]) {
+        Object::Null
+    } else if let Some(message) = args.get(1) {
+        Object::Error(format!("assertion failed: {}", message))
+    } else {
+        Object::Error(format!("assertion failed: condition was {}", describe_value(&args[0])))
+    }
+}
+
+// Builtin native function for assert_eq: fails with an error showing both values unless they are equal (as == sees it)
+pub fn builtin_assert_eq(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("assert_eq() takes exactly 2 arguments".to_string());
+    }
+
+    if crate::evaluator::values_equal(&args[0], &args[1]) {
+        Object::Null
+    } else {
+        Object::Error(format!(
+            "assertion failed: {} != {}",
+            describe_value(&args[0]),
+            describe_value(&args[1])
+        ))
+    }
+}
+
+// Builtin native function for range: range(end), range(start, end) or range(start, end, step)
+// gives the integers from start (default 0) up to but not including end
+pub fn builtin_range(args: Vec<Object>) -> Object {
+    let mut bounds = Vec::with_capacity(args.len());
+    for arg in &args {
+        match arg {
+            Object::Integer(i) => bounds.push(*i),
+            other => return Object::Error(format!("range() requires integer arguments, got {}", other.type_name())),
+        }
+    }
+
+    let (start, end, step) = match bounds[..] {
+        [end] => (0, end, 1),
+        [start, end] => (start, end, 1),
+        [start, end, step] => (start, end, step),
+        _ => return Object::Error("range() takes 1 to 3 arguments".to_string()),
+    };
+    if step == 0 {
+        return Object::Error("range() step must not be zero".to_string());
+    }
+
+    let mut elements = Vec::new();
+    let mut current = Some(start);
+    while let Some(value) = current.filter(|v| if step > 0 { *v < end } else { *v > end }) {
+        elements.push(Object::Integer(value));
+        current = value.checked_add(step);
+    }
+    Object::Array(elements)
+}
+
+// Builtin native function for dhoroner (typeof): the name of a value's type,
+// or the declared name for records made from a type banao type
+pub fn builtin_type_of(args: Vec<Object>) -> Object {
+    match args.as_slice() {
+        [Object::Record { type_name, .. }] => Object::String(type_name.clone()),
+        [value] => Object::String(value.type_name().to_string()),
+        _ => Object::Error("dhoroner() takes exactly one argument".to_string()),
+    }
+}
+
+// Value as shown in assertion messages: strings are quoted so "1" and 1 can be told apart
+fn describe_value(value: &Object) -> String {
+    match value {
+        Object::String(s) => format!("{:?}", s),
+        Object::Char(c) => format!("{:?}", c),
+        other => other.to_string(),
+    }
 }
 
 impl Object {
@@ -99,6 +287,10 @@ impl Object {
         match name {
             "input" => Some(Object::BuiltinNative(builtin_input)),
             "dekhao" => Some(Object::BuiltinNative(builtin_print)),
+            "assert" => Some(Object::BuiltinNative(builtin_assert)),
+            "assert_eq" => Some(Object::BuiltinNative(builtin_assert_eq)),
+            "range" => Some(Object::BuiltinNative(builtin_range)),
+            "dhoroner" => Some(Object::BuiltinNative(builtin_type_of)),
             _ => None,
         }
     }
@@ -112,6 +304,12 @@ impl BuiltinFunction {
             "input" => Some(BuiltinFunction::Input),
             "shomoy" => Some(BuiltinFunction::Shomoy),
             "print" => Some(BuiltinFunction::Print),
+            "map" => Some(BuiltinFunction::Map),
+            "filter" => Some(BuiltinFunction::Filter),
+            "reduce" => Some(BuiltinFunction::Reduce),
+            "sort" => Some(BuiltinFunction::Sort),
+            "input_int" => Some(BuiltinFunction::InputInt),
+            "input_float" => Some(BuiltinFunction::InputFloat),
             _ => None,
         }
     }
@@ -1,28 +1,38 @@
 // compiler/src/parser.rs
 
 // Import necessary modules and types from lexer, AST, and token definitions
-use crate::ast::{Expression, Program, Statement};
-use crate::lexer::Lexer;
-use crate::token::{Token, TokenType};
+use crate::ast::{Expression, Program, Span, Statement};
+use crate::lexer::{Lexer, TemplateSegment};
+use crate::token::{is_reserved_keyword, Symbol, Token, TokenType};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use std::io::{self, Write};
 
 // Precedence levels for parsing expressions with correct operator binding
-#[derive(PartialEq, PartialOrd, Debug)]
-enum Precedence {
+#[derive(PartialEq, PartialOrd, Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) enum Precedence {
     LOWEST,
+    CONDITIONAL, // cond ? a : b
+    LOGICALOR,   // othoba, ||
+    LOGICALAND,  // ebong, &&
     EQUALS,      // == operator
     LESSGREATER, // > or < operators
     SUM,         // + operator
     PRODUCT,     // * operator
     PREFIX,      // -X or !X prefix operators
+    POWER,       // ** operator, binds tighter than prefix minus like in Python
     CALL,        // Function call like myFunction(X)
+    INDEX,       // Index access like array[X] or hash["key"]
 }
 
 // Type aliases for prefix and infix parsing function signatures
 type PrefixParseFn = fn(&mut Parser) -> Option<Expression>;
 type InfixParseFn = fn(&mut Parser, Expression) -> Option<Expression>;
 
+// Names, type annotations and defaults of a parameter list, and whether the last one is variadic
+type FunctionParameters = (Vec<Expression>, Vec<Option<String>>, Vec<Option<Expression>>, bool);
+
 // Parser struct holds lexer, current and peek tokens, errors and registered parse functions
 pub struct Parser {
     lexer: Lexer,
@@ -31,11 +41,28 @@ pub struct Parser {
     pub errors: Vec<String>,
     prefix_parse_fns: HashMap<TokenType, PrefixParseFn>,
     infix_parse_fns: HashMap<TokenType, InfixParseFn>,
+    pending_comments: Vec<Statement>, // Comments before cur_token, waiting for the next statement slot
+    peek_comments: Vec<Statement>,    // Comments before peek_token
+    lex_time: Option<Duration>,       // Time spent in the lexer, when measuring (--time)
 }
 
 impl Parser {
     // Create a new Parser instance and register prefix and infix parse functions
     pub fn new(lexer: Lexer) -> Self {
+        Self::build(lexer, None)
+    }
+
+    // Same as new, also adding up the time spent lexing so it can be reported apart from parsing
+    pub fn with_lex_timing(lexer: Lexer) -> Self {
+        Self::build(lexer, Some(Duration::ZERO))
+    }
+
+    // Time spent in the lexer so far, if the parser was made with with_lex_timing
+    pub fn lexing_time(&self) -> Option<Duration> {
+        self.lex_time
+    }
+
+    fn build(lexer: Lexer, lex_time: Option<Duration>) -> Self {
         let mut p = Parser {
             lexer,
 cur_token: Token::new(TokenType::Illegal, "", 0, 0),
@@ -44,12 +71,18 @@ peek_token: Token::new(TokenType::Illegal, "", 0, 0),
             errors: Vec::new(),
             prefix_parse_fns: HashMap::new(),
             infix_parse_fns: HashMap::new(),
+            pending_comments: Vec::new(),
+            peek_comments: Vec::new(),
+            lex_time,
         };
 
         // Register prefix parsing functions for different token types
         p.register_prefix(TokenType::Ident, Self::parse_identifier);
         p.register_prefix(TokenType::Int, Self::parse_integer_literal);
+        p.register_prefix(TokenType::Float, Self::parse_float_literal);
+        p.register_prefix(TokenType::Double, Self::parse_float_literal);
         p.register_prefix(TokenType::String, Self::parse_string_literal);
+        p.register_prefix(TokenType::Char, Self::parse_char_literal);
         p.register_prefix(TokenType::Bang, Self::parse_prefix_expression);
         p.register_prefix(TokenType::Minus, Self::parse_prefix_expression);
         p.register_prefix(TokenType::Ha, Self::parse_boolean);
@@ -59,19 +92,30 @@ peek_token: Token::new(TokenType::Illegal, "", 0, 0),
         p.register_prefix(TokenType::LParen, Self::parse_grouped_expression);
         p.register_prefix(TokenType::Function, Self::parse_function_literal);
         p.register_prefix(TokenType::InputNao, Self::parse_input_expression);
+        p.register_prefix(TokenType::LBrace, Self::parse_hash_literal);
+        p.register_prefix(TokenType::LBracket, Self::parse_array_literal);
+        p.register_prefix(TokenType::ShomoyNiropekho, Self::parse_async_function);
+        p.register_prefix(TokenType::OpekkhaKoro, Self::parse_await_expression);
+        p.register_prefix(TokenType::Dhoroner, Self::parse_type_of);
 
         // Register infix parsing functions for operators and calls
         p.register_infix(TokenType::Plus, Self::parse_infix_expression);
         p.register_infix(TokenType::Minus, Self::parse_infix_expression);
         p.register_infix(TokenType::Slash, Self::parse_infix_expression);
         p.register_infix(TokenType::Asterisk, Self::parse_infix_expression);
+        p.register_infix(TokenType::DoubleStar, Self::parse_power_expression);
+        p.register_infix(TokenType::Question, Self::parse_conditional_expression);
+        p.register_infix(TokenType::Fullstop, Self::parse_member_expression);
         p.register_infix(TokenType::Eq, Self::parse_infix_expression);
         p.register_infix(TokenType::NotEq, Self::parse_infix_expression);
         p.register_infix(TokenType::Lt, Self::parse_infix_expression);
         p.register_infix(TokenType::Gt, Self::parse_infix_expression);
+        p.register_infix(TokenType::LtEq, Self::parse_infix_expression);
+        p.register_infix(TokenType::GtEq, Self::parse_infix_expression);
         p.register_infix(TokenType::Ebong, Self::parse_infix_expression); // Logical AND
         p.register_infix(TokenType::Othoba, Self::parse_infix_expression);    // Logical OR
         p.register_infix(TokenType::LParen, Self::parse_call_expression);
+        p.register_infix(TokenType::LBracket, Self::parse_index_expression);
 
         // Advance tokens twice to initialize cur_token and peek_token
         p.next_token();
@@ -82,53 +126,150 @@ peek_token: Token::new(TokenType::Illegal, "", 0, 0),
     // Parse input() function call expression
     fn parse_input_expression(&mut self) -> Option<Expression> {
         let function_name = self.cur_token.literal.clone();
+        let span = self.cur_span();
 
         if !self.expect_peek(TokenType::LParen) {
-            self.errors.push(format!("expected '(' after '{}'", function_name));
+            self.error(format!("expected '(' after '{}'", function_name));
             return None;
         }
 
         let args = self.parse_call_arguments()?;
 
         Some(Expression::Call {
-            function: Box::new(Expression::Identifier(function_name)),
+            function: Box::new(Expression::Identifier(function_name, span)),
             arguments: args,
+            span,
         })
     }
 
-    // Advance current and peek tokens from lexer
+    // Runs `read` on the lexer, adding its duration to lex_time when measuring
+    fn lex<T>(&mut self, read: impl FnOnce(&mut Lexer) -> T) -> T {
+        let Some(total) = self.lex_time else { return read(&mut self.lexer) };
+        let start = Instant::now();
+        let result = read(&mut self.lexer);
+        self.lex_time = Some(total + start.elapsed());
+        result
+    }
+
+    // Source position of the current token, for spans on expressions
+    fn cur_span(&self) -> Span {
+        Span { line: self.cur_token.line, column: self.cur_token.column }
+    }
+
+    // Advance current and peek tokens from lexer.
+    // Comment tokens (only produced by Lexer::with_comments) never become the peek token;
+    // they are held back as comment statements until the next statement boundary.
     fn next_token(&mut self) {
+        self.pending_comments.append(&mut self.peek_comments);
         self.cur_token = self.peek_token.clone();
-        self.peek_token = self.lexer.next_token();
+        self.peek_token = self.lex(Lexer::next_token);
+        loop {
+            let content = self.peek_token.literal.to_string();
+            let comment = match self.peek_token.token_type {
+                TokenType::EkLineMontobbo => Statement::CommentSingleLine { content },
+                TokenType::BohuLineMontobboShuru => Statement::CommentMultiLine { content },
+                _ => break,
+            };
+            self.peek_comments.push(comment);
+            self.peek_token = self.lex(Lexer::next_token);
+        }
+    }
+
+    // Moves held-back comments into the statement list being built
+    fn flush_comments(&mut self, statements: &mut Vec<Statement>) {
+        statements.append(&mut self.pending_comments);
     }
 
     // Parse the entire program (list of statements)
     pub fn parse_program(&mut self) -> Program {
         let mut program: Program = Vec::new();
         while self.cur_token.token_type != TokenType::Eof {
-            if let Some(stmt) = self.parse_statement() {
+            self.flush_comments(&mut program);
+            if let Some(stmt) = self.parse_statement_or_recover() {
                 program.push(stmt);
             }
             self.next_token();
         }
+        self.flush_comments(&mut program);
         program
     }
 
+    // Parse a statement; if it fails with an error, skip to the next statement boundary
+    // so the rest of the file is still parsed and every error is reported in one run
+    fn parse_statement_or_recover(&mut self) -> Option<Statement> {
+        let errors_before = self.errors.len();
+        let statement = self.parse_statement();
+        if statement.is_none() && self.errors.len() > errors_before {
+            self.synchronize();
+        }
+        statement
+    }
+
+    // Skip tokens until the current one ends a statement: a ';', the last token before a
+    // statement keyword, a closing '}' or a new line. Braces opened while skipping are
+    // skipped as a whole. The caller's next_token() then moves onto the next statement.
+    fn synchronize(&mut self) {
+        let mut depth = 0usize;
+        loop {
+            match self.cur_token.token_type {
+                TokenType::Eof => return,
+                TokenType::Semicolon if depth == 0 => return,
+                TokenType::LBrace => depth += 1,
+                TokenType::RBrace => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+
+            let at_boundary = self.peek_token_is(TokenType::RBrace)
+                || self.peek_token_is(TokenType::Eof)
+                || is_statement_start(&self.peek_token.token_type)
+                || self.peek_token.line > self.cur_token.line;
+            if depth == 0 && at_boundary {
+                return;
+            }
+            self.next_token();
+        }
+    }
+
     // Parse a statement depending on current token type
 fn parse_statement(&mut self) -> Option<Statement> {
+    // Assigning to a keyword (jodi = 5, time += 1) is reported before the keyword is parsed
+    let assigns = self.peek_token_is(TokenType::Assign) || compound_assign_operator(&self.peek_token.token_type).is_some();
+    if assigns && self.reject_reserved_name(&self.cur_token.clone(), "variable") {
+        return None;
+    }
+
     match self.cur_token.token_type {
         TokenType::Dhoro => self.parse_let_statement(),
         TokenType::ReturnKoro => self.parse_return_statement(),
+        TokenType::ProtitarJonno => self.parse_foreach_statement(),
+        TokenType::Jotokhon => self.parse_while_statement(),
+        TokenType::AgeKoro => self.parse_do_while_statement(),
+        TokenType::ImportKoro => self.parse_import_statement(),
+        TokenType::TypeBanao => self.parse_type_definition(),
+        TokenType::Thamo => {
+            let label = self.parse_loop_label();
+            self.skip_optional_semicolon();
+            Some(Statement::Break(label))
+        }
+        TokenType::Choluk => {
+            let label = self.parse_loop_label();
+            self.skip_optional_semicolon();
+            Some(Statement::Continue(label))
+        }
         TokenType::Dekhao => {
             // Handle dekhao as expression statement
             let expr = self.parse_expression_statement()?;
             Some(expr)
         }
+        TokenType::Ident if self.peek_token_is(TokenType::Colon) => self.parse_labeled_statement(),
         TokenType::Ident => {
-            // Check if next token is '='
+            // Check if next token is '=' or a compound assignment like '+='
             if self.peek_token_is(TokenType::Assign) {
-                let name = Expression::Identifier(self.cur_token.literal.clone());
+                let name = Expression::Identifier(self.cur_token.literal.clone(), self.cur_span());
                 self.parse_assign_statement(name)
+            } else if compound_assign_operator(&self.peek_token.token_type).is_some() {
+                let name = Expression::Identifier(self.cur_token.literal.clone(), self.cur_span());
+                self.parse_compound_assign_statement(name)
             } else {
                 self.parse_expression_statement()
             }
@@ -152,9 +293,11 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
         }
     }
 
+    if self.reject_reserved_name(&self.peek_token.clone(), "variable") { return None; }
     if !self.expect_peek(TokenType::Ident) { return None; }
 
-    let name = Expression::Identifier(self.cur_token.literal.clone());
+    let name = Expression::Identifier(self.cur_token.literal.clone(), self.cur_span());
+    let type_annotation = self.parse_optional_type_annotation()?;
 
     if !self.expect_peek(TokenType::Assign) { return None; }
 
@@ -167,13 +310,36 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
         self.next_token(); // Skip the semicolon for an immutable variable
     } else if mutable || !mutable {
         // If not all tokens are consumed, it might be an error in syntax
-        self.errors.push("missing ';' after declaration".to_string());
+        self.error_at_peek("missing ';' after declaration".to_string());
         return None;
     }
 
-    Some(Statement::Let { name, value, mutable })
+    Some(Statement::Let { name, type_annotation, value, mutable })
 }
 
+    // Reports `token` if it is a reserved keyword, which can't name a variable or parameter
+    fn reject_reserved_name(&mut self, token: &Token, kind: &str) -> bool {
+        if !is_reserved_keyword(&token.literal) {
+            return false;
+        }
+        let message = format!("'{}' is a reserved keyword and can't be used as a {} name", token.literal, kind);
+        self.error_at(token, message);
+        true
+    }
+
+    // Parse an optional `: type_name` after a declared name.
+    // Returns None on a syntax error, Some(None) when there is no annotation.
+    fn parse_optional_type_annotation(&mut self) -> Option<Option<String>> {
+        if !self.peek_token_is(TokenType::Colon) {
+            return Some(None);
+        }
+        self.next_token(); // consume ':'
+        if !self.expect_peek(TokenType::Ident) {
+            return None;
+        }
+        Some(Some(self.cur_token.literal.to_string()))
+    }
+
 
     // Asign statement
     fn parse_assign_statement(&mut self, name: Expression) -> Option<Statement> {
@@ -186,9 +352,149 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
         Some(Statement::Assign { name, value })
     }
 
+    // Compound assignment: `x += e` is parsed as `x = x + e`
+    fn parse_compound_assign_statement(&mut self, name: Expression) -> Option<Statement> {
+        self.next_token(); // move to the operator
+        let operator = compound_assign_operator(&self.cur_token.token_type)?.to_string();
+        let span = self.cur_span();
+        self.next_token(); // move to right-hand side expression
+        let right = self.parse_expression(Precedence::LOWEST)?;
+        if self.peek_token_is(TokenType::Semicolon) {
+            self.next_token();
+        }
+        let value = Expression::Infix {
+            left: Box::new(name.clone()),
+            operator,
+            right: Box::new(right),
+            span,
+        };
+        Some(Statement::Assign { name, value })
+    }
+
+
 
 
 
+    // Parse a while loop: jotokhon (cond) { ... }
+    fn parse_while_statement(&mut self) -> Option<Statement> {
+        self.next_token(); // move to the condition
+        let condition = self.parse_expression(Precedence::LOWEST)?;
+
+        if !self.expect_peek(TokenType::LBrace) { return None; }
+
+        let body = self.parse_block_statement()?;
+        self.skip_optional_semicolon();
+
+        Some(Statement::While { condition, body })
+    }
+
+    // Parse a do-while loop: age koro { ... } jotokhon (cond)
+    fn parse_do_while_statement(&mut self) -> Option<Statement> {
+        if !self.expect_peek(TokenType::LBrace) { return None; }
+
+        let body = self.parse_block_statement()?;
+
+        if !self.expect_peek(TokenType::Jotokhon) { return None; }
+
+        self.next_token(); // move to the condition
+        let condition = self.parse_expression(Precedence::LOWEST)?;
+        self.skip_optional_semicolon();
+
+        Some(Statement::DoWhile { body, condition })
+    }
+
+    // Parse a labeled loop: <label>: jotokhon/age koro/protitar jonno ...
+    fn parse_labeled_statement(&mut self) -> Option<Statement> {
+        let label = self.cur_token.literal.clone();
+        self.next_token(); // move to ':'
+        if !matches!(
+            self.peek_token.token_type,
+            TokenType::Jotokhon | TokenType::AgeKoro | TokenType::ProtitarJonno
+        ) {
+            self.error(format!("only loops can be labeled, '{}:' is followed by {:?}", label, self.peek_token.token_type));
+            return None;
+        }
+        self.next_token(); // move to the loop keyword
+        let body = self.parse_statement()?;
+        Some(Statement::Labeled { label, body: Box::new(body) })
+    }
+
+    // Label after thamo/choluk, when the next token is a name on the same line
+    fn parse_loop_label(&mut self) -> Option<Symbol> {
+        if self.peek_token_is(TokenType::Ident) && self.peek_token.line == self.cur_token.line {
+            self.next_token();
+            Some(self.cur_token.literal.clone())
+        } else {
+            None
+        }
+    }
+
+    // Parse an import: import koro "path.bp" ei hisebe name
+    fn parse_import_statement(&mut self) -> Option<Statement> {
+        if !self.expect_peek(TokenType::String) { return None; }
+        let path = self.cur_token.literal.to_string();
+
+        if !self.expect_peek(TokenType::EiHisebe) { return None; }
+        if !self.expect_peek(TokenType::Ident) { return None; }
+        let alias = self.cur_token.literal.to_string();
+        self.skip_optional_semicolon();
+
+        Some(Statement::Import { path, alias })
+    }
+
+    // Parse a record type: type banao Point { x, y }
+    fn parse_type_definition(&mut self) -> Option<Statement> {
+        if !self.expect_peek(TokenType::Ident) { return None; }
+        let name = self.cur_token.literal.to_string();
+        if !self.expect_peek(TokenType::LBrace) { return None; }
+
+        let mut fields: Vec<String> = Vec::new();
+        while !self.peek_token_is(TokenType::RBrace) {
+            if !self.expect_peek(TokenType::Ident) { return None; }
+            let field = self.cur_token.literal.to_string();
+            if fields.contains(&field) {
+                self.error(format!("field '{}' appears twice in type {}", field, name));
+                return None;
+            }
+            fields.push(field);
+            if !self.peek_token_is(TokenType::Comma) {
+                break;
+            }
+            self.next_token();
+        }
+
+        if !self.expect_peek(TokenType::RBrace) { return None; }
+        self.skip_optional_semicolon();
+        Some(Statement::TypeDefinition { name, fields })
+    }
+
+    // Consume a trailing ';' if one follows the current token
+    fn skip_optional_semicolon(&mut self) {
+        if self.peek_token_is(TokenType::Semicolon) {
+            self.next_token();
+        }
+    }
+
+    // Parse a for-each loop: protitar jonno (item jekhane list) { ... }
+    fn parse_foreach_statement(&mut self) -> Option<Statement> {
+        if !self.expect_peek(TokenType::LParen) { return None; }
+        if !self.expect_peek(TokenType::Ident) { return None; }
+
+        let var = Expression::Identifier(self.cur_token.literal.clone(), self.cur_span());
+
+        if !self.expect_peek(TokenType::Jekhane) { return None; }
+
+        self.next_token(); // move to the iterable expression
+        let iterable = self.parse_expression(Precedence::LOWEST)?;
+
+        if !self.expect_peek(TokenType::RParen) { return None; }
+        if !self.expect_peek(TokenType::LBrace) { return None; }
+
+        let body = self.parse_block_statement()?;
+        self.skip_optional_semicolon();
+
+        Some(Statement::ForEach { var, iterable, body })
+    }
 
     // Parse a return statement
     fn parse_return_statement(&mut self) -> Option<Statement> {
@@ -236,7 +542,12 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
 
     // Parse an identifier expression
     fn parse_identifier(&mut self) -> Option<Expression> {
-        Some(Expression::Identifier(self.cur_token.literal.clone()))
+        Some(Expression::Identifier(self.cur_token.literal.clone(), self.cur_span()))
+    }
+
+    // typeof and dhoron ber koro are keywords for the dhoroner builtin
+    fn parse_type_of(&mut self) -> Option<Expression> {
+        Some(Expression::Identifier("dhoroner".into(), self.cur_span()))
     }
 
     // Parse an integer literal expression
@@ -244,7 +555,18 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
         match self.cur_token.literal.parse::<i64>() {
             Ok(value) => Some(Expression::IntegerLiteral(value)),
             Err(_) => {
-                self.errors.push(format!("could not parse {} as integer", self.cur_token.literal));
+                self.error(format!("could not parse {} as integer", self.cur_token.literal));
+                None
+            }
+        }
+    }
+
+    // Parse a floating point literal like 3.14 or 1e-3
+    fn parse_float_literal(&mut self) -> Option<Expression> {
+        match self.cur_token.literal.parse::<f64>() {
+            Ok(value) => Some(Expression::FloatLiteral(value)),
+            Err(_) => {
+                self.error(format!("could not parse {} as float", self.cur_token.literal));
                 None
             }
         }
@@ -252,7 +574,19 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
 
     // Parse a string literal expression
     fn parse_string_literal(&mut self) -> Option<Expression> {
-        Some(Expression::StringLiteral(self.cur_token.literal.clone()))
+        Some(Expression::StringLiteral(self.cur_token.literal.to_string()))
+    }
+
+    // Parse a character literal expression like 'a' or '\n'
+    fn parse_char_literal(&mut self) -> Option<Expression> {
+        let mut chars = self.cur_token.literal.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(Expression::CharLiteral(c)),
+            _ => {
+                self.error(format!("invalid char literal '{}'", self.cur_token.literal));
+                None
+            }
+        }
     }
 
     // Parse a boolean literal expression (Ha or Na)
@@ -262,10 +596,12 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
 
     // Parse a prefix expression like !X or -X
     fn parse_prefix_expression(&mut self) -> Option<Expression> {
-        let operator = self.cur_token.literal.clone();
+        // noy is the keyword spelling of !
+        let operator = if self.cur_token_is(TokenType::Bang) { "!".to_string() } else { self.cur_token.literal.to_string() };
+        let span = self.cur_span();
         self.next_token();
         let right = self.parse_expression(Precedence::PREFIX)?;
-        Some(Expression::Prefix { operator, right: Box::new(right) })
+        Some(Expression::Prefix { operator, right: Box::new(right), span })
     }
 
     // Parse print (dekhao) expression
@@ -286,242 +622,126 @@ fn parse_let_statement(&mut self) -> Option<Statement> {
 
 
 fn parse_print_expression(&mut self) -> Option<Expression> {
-    let dekhao_token = self.cur_token.clone();
-    
-    // Move past 'dekhao'
-    self.next_token();
-    
-    let mut args = vec![];
+    let span = self.cur_span();
 
     // Handle template literal cases: dekhao{...} and dekhao {...}
-    if self.cur_token.token_type == TokenType::LBrace {
+    if self.peek_token_is(TokenType::LBrace) {
         let template_parts = self.parse_template_literal()?;
         return Some(Expression::Call {
-            function: Box::new(Expression::Identifier("dekhao".to_string())),
+            function: Box::new(Expression::Identifier("dekhao".into(), span)),
             arguments: vec![Expression::TemplateLiteral { parts: template_parts }],
+            span,
         });
     }
-    
-    // Handle parentheses cases: dekhao(...) and dekhao (...)
-    if self.cur_token.token_type == TokenType::LParen {
-        self.next_token(); // consume '('
-        
-        // Parse arguments inside parentheses
-        while self.cur_token.token_type != TokenType::RParen && self.cur_token.token_type != TokenType::Eof {
-            if let Some(arg) = self.parse_expression(Precedence::LOWEST) {
-                args.push(arg);
-            } else {
-                return None;
-            }
 
-            if self.cur_token.token_type == TokenType::Comma {
-                self.next_token();
-            } else {
-                break;
-            }
-        }
+    let mut args = vec![];
 
-        if self.cur_token.token_type != TokenType::RParen {
-            self.errors.push("Expected ')' after dekhao arguments".to_string());
-            return None;
-        }
-        self.next_token(); // consume ')'
-    } else {
-        // Handle direct string cases: dekhao"text" and dekhao "text"
-        // Parse until semicolon or end of line
-        while self.cur_token.token_type != TokenType::Semicolon && 
-              self.cur_token.token_type != TokenType::Eof &&
-              self.cur_token.token_type != TokenType::RBrace {
-            
-            if let Some(expr) = self.parse_expression(Precedence::LOWEST) {
-                args.push(expr);
-            } else {
-                return None;
-            }
+    // Handle parentheses cases: dekhao(a, b, c) and dekhao (a, b, c)
+    if self.peek_token_is(TokenType::LParen) {
+        self.next_token(); // move onto '('
+        args = self.parse_call_arguments()?;
+    } else if !self.peek_token_is(TokenType::Semicolon)
+        && !self.peek_token_is(TokenType::Eof)
+        && !self.peek_token_is(TokenType::RBrace)
+    {
+        // Handle direct cases: dekhao"text" and dekhao "text", 1
+        self.next_token();
+        args.push(self.parse_expression(Precedence::LOWEST)?);
 
-            // Allow comma separation for multiple arguments
-            if self.cur_token.token_type == TokenType::Comma {
-                self.next_token();
-            } else {
-                break;
-            }
+        // Allow comma separation for multiple arguments
+        while self.peek_token_is(TokenType::Comma) {
+            self.next_token();
+            self.next_token();
+            args.push(self.parse_expression(Precedence::LOWEST)?);
         }
     }
 
     Some(Expression::Call {
-        function: Box::new(Expression::Identifier("dekhao".to_string())),
+        function: Box::new(Expression::Identifier("dekhao".into(), span)),
         arguments: args,
+        span,
     })
 }
 
 fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
-    if !self.cur_token_is(TokenType::LBrace) {
+    if !self.peek_token_is(TokenType::LBrace) {
         return None;
     }
-    
-    self.next_token(); // consume '{'
-    let mut parts: Vec<Expression> = Vec::new();
-    let mut current_text = String::new();
 
-    let mut flush_text = |text: &mut String, parts: &mut Vec<Expression>| {
-        if !text.is_empty() {
-            parts.push(Expression::StringLiteral(text.clone()));
-            text.clear();
+    // With '{' as the peek token the lexer sits just past it, so scan the raw template text
+    let segments = match self.lex(Lexer::read_template_body) {
+        Ok(segments) => segments,
+        Err(e) => {
+            self.error(e);
+            return None;
         }
     };
 
-    while !self.cur_token_is(TokenType::RBrace) && !self.cur_token_is(TokenType::Eof) {
-        match self.cur_token.token_type {
-            TokenType::LParen => {
-                // Flush any accumulated text
-                flush_text(&mut current_text, &mut parts);
-                
-                // Parse expression inside parentheses
-                self.next_token(); // consume '('
-                if let Some(expr) = self.parse_expression(Precedence::LOWEST) {
-                    parts.push(expr);
-                }
-                
-                if !self.expect_peek(TokenType::RParen) {
-                    self.errors.push("Expected ')' in template literal".to_string());
-                    return None;
+    let mut parts: Vec<Expression> = Vec::new();
+    for segment in segments {
+        match segment {
+            TemplateSegment::Text(text) => parts.push(Expression::StringLiteral(text)),
+            TemplateSegment::Code(code) => {
+                let mut sub_parser = Parser::new(Lexer::new(code.clone()));
+                let expr = sub_parser.parse_expression(Precedence::LOWEST);
+                // Positions inside the template code are relative to it, so report them at the template
+                for error in sub_parser.errors {
+                    self.error(split_error_position(&error).1.to_string());
                 }
-            }
-            
-            TokenType::Ident => {
-                // Add space before identifier if needed
-                if !current_text.is_empty() && 
-                   !current_text.chars().last().unwrap_or(' ').is_whitespace() {
-                    current_text.push(' ');
+                match expr {
+                    Some(expr) => parts.push(expr),
+                    None => {
+                        self.error(format!("Invalid expression '{}' in template literal", code));
+                        return None;
+                    }
                 }
-                current_text.push_str(&self.cur_token.literal);
-            }
-            
-            TokenType::String => {
-                current_text.push_str(&self.cur_token.literal);
-            }
-            
-            TokenType::Int => {
-                current_text.push_str(&self.cur_token.literal);
-            }
-            
-            // Handle punctuation and spacing
-            TokenType::Comma => {
-                current_text.push_str(", ");
-            }
-            
-            TokenType::Fullstop => {
-                current_text.push('.');
-            }
-            
-            _ => {
-                // For any other token, just add its literal
-                current_text.push_str(&self.cur_token.literal);
             }
         }
-
-        self.next_token();
     }
 
-    // Flush any remaining text
-    flush_text(&mut current_text, &mut parts);
-
-    if !self.cur_token_is(TokenType::RBrace) {
-        self.errors.push("Expected '}' to close template literal".to_string());
-        return None;
-    }
+    // Leave the parser on the closing '}' like any other expression end
+    self.cur_token = Token::new(TokenType::RBrace, "}", self.peek_token.line, self.peek_token.column);
+    self.peek_token = self.lex(Lexer::next_token);
 
     Some(parts)
 }
 
 
-/**
-
-// Improved helper: parse template literals like
-// dekhao { Hi (name), your age is (age) }
-// Preserves spaces and punctuation between placeholders.
-fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
-    if !self.cur_token_is(TokenType::LBrace) {
-        return None;
+    // Parse array literal like [1, 2, 3]
+    fn parse_array_literal(&mut self) -> Option<Expression> {
+        let elements = self.parse_expression_list(TokenType::RBracket)?;
+        Some(Expression::ArrayLiteral { elements })
     }
-    
-    self.next_token(); // consume '{'
-    let mut parts: Vec<Expression> = Vec::new();
-    let mut current_text = String::new();
 
-    let mut flush_text = |text: &mut String, parts: &mut Vec<Expression>| {
-        if !text.is_empty() {
-            parts.push(Expression::StringLiteral(text.clone()));
-            text.clear();
-        }
-    };
+    // Parse hash literal like { "key": value, 1: other }
+    // A '{' only reaches here in expression position; blocks are parsed explicitly
+    fn parse_hash_literal(&mut self) -> Option<Expression> {
+        let mut pairs = Vec::new();
 
-    while !self.cur_token_is(TokenType::RBrace) && !self.cur_token_is(TokenType::Eof) {
-        match self.cur_token.token_type {
-            TokenType::LParen => {
-                // Flush any accumulated text
-                flush_text(&mut current_text, &mut parts);
-                
-                // Parse expression inside parentheses
-                self.next_token(); // consume '('
-                if let Some(expr) = self.parse_expression(Precedence::LOWEST) {
-                    parts.push(expr);
-                }
-                
-                if !self.expect_peek(TokenType::RParen) {
-                    self.errors.push("Expected ')' in template literal".to_string());
-                    return None;
-                }
-            }
-            
-            TokenType::Ident => {
-                // Add space before identifier if needed
-                if !current_text.is_empty() && 
-                   !current_text.chars().last().unwrap_or(' ').is_whitespace() {
-                    current_text.push(' ');
-                }
-                current_text.push_str(&self.cur_token.literal);
-            }
-            
-            TokenType::String => {
-                current_text.push_str(&self.cur_token.literal);
-            }
-            
-            TokenType::Int => {
-                current_text.push_str(&self.cur_token.literal);
-            }
-            
-            // Handle punctuation and spacing
-            TokenType::Comma => {
-                current_text.push(',');
-                current_text.push(' ');
-            }
-            
-            TokenType::Fullstop => {
-                current_text.push('.');
+        while !self.peek_token_is(TokenType::RBrace) {
+            self.next_token();
+            let key = self.parse_expression(Precedence::LOWEST)?;
+
+            if !self.expect_peek(TokenType::Colon) {
+                return None;
             }
-            
-            _ => {
-                // For any other token, just add its literal
-                current_text.push_str(&self.cur_token.literal);
+
+            self.next_token();
+            let value = self.parse_expression(Precedence::LOWEST)?;
+            pairs.push((key, value));
+
+            if !self.peek_token_is(TokenType::RBrace) && !self.expect_peek(TokenType::Comma) {
+                return None;
             }
         }
 
-        self.next_token();
-    }
-
-    // Flush any remaining text
-    flush_text(&mut current_text, &mut parts);
+        if !self.expect_peek(TokenType::RBrace) {
+            return None;
+        }
 
-    if !self.cur_token_is(TokenType::RBrace) {
-        self.errors.push("Expected '}' to close template literal".to_string());
-        return None;
+        Some(Expression::HashLiteral { pairs })
     }
 
-    Some(parts)
-}
-*/
-
     // Parse grouped expression like (expr)
     fn parse_grouped_expression(&mut self) -> Option<Expression> {
         self.next_token();
@@ -553,7 +773,7 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
         } else {
             self.next_token();
             let stmt = self.parse_statement().unwrap_or_else(|| {
-                self.errors.push("Expected statement after jodi consequence".to_string());
+                self.error("Expected statement after jodi consequence".to_string());
                 Statement::ExpressionStatement {
                     expression: Expression::Boolean(false),
                 }
@@ -580,7 +800,7 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
                 if let Some(expr) = self.parse_if_expression() {
                     alternative = Some(Box::new(expr));
                 } else {
-                    self.errors.push("Failed to parse else if expression".to_string());
+                    self.error("Failed to parse else if expression".to_string());
                     return None;
                 }
             } else if self.peek_token_is(TokenType::LBrace) {
@@ -593,7 +813,7 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
                             alternative = Some(Box::new(expression.clone()));
                         }
                         _ => {
-                            self.errors.push("Expected expression statement inside else block".to_string());
+                            self.error("Expected expression statement inside else block".to_string());
                             return None;
                         }
                     }
@@ -601,7 +821,7 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
             } else {
                 self.next_token();
                 let stmt = self.parse_statement().unwrap_or_else(|| {
-                    self.errors.push("Expected statement after else part".to_string());
+                    self.error("Expected statement after else part".to_string());
                     Statement::ExpressionStatement {
                         expression: Expression::Boolean(false),
                     }
@@ -609,7 +829,7 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
                 if let Statement::ExpressionStatement { expression } = stmt {
                     alternative = Some(Box::new(expression));
                 } else {
-                    self.errors.push("Expected expression statement in else part".to_string());
+                    self.error("Expected expression statement in else part".to_string());
                     return None;
                 }
             }
@@ -649,6 +869,7 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
                 && peek_type != TokenType::LtEq && peek_type != TokenType::GtEq
                 && peek_type != TokenType::Plus && peek_type != TokenType::Minus
                 && peek_type != TokenType::Asterisk && peek_type != TokenType::Slash
+                && peek_type != TokenType::DoubleStar
             {
                 break;
             }
@@ -668,11 +889,14 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
         self.next_token(); // consume '{'
 
         while !self.cur_token_is(TokenType::RBrace) && !self.cur_token_is(TokenType::Eof) {
-            if let Some(stmt) = self.parse_statement() {
+            self.flush_comments(&mut statements);
+            if let Some(stmt) = self.parse_statement_or_recover() {
                 statements.push(stmt);
             }
             self.next_token();
         }
+        // Comments just before the closing brace belong to this block
+        self.flush_comments(&mut statements);
         Some(statements)
     }
 
@@ -682,11 +906,8 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
             return None;
         }
 
-        let parameters = self.parse_function_parameters()?;
-
-        if !self.expect_peek(TokenType::RParen) {
-            return None;
-        }
+        // Consumes everything up to and including ')'
+        let (parameters, parameter_types, parameter_defaults, variadic) = self.parse_function_parameters()?;
 
         if !self.expect_peek(TokenType::LBrace) {
             return None;
@@ -694,40 +915,90 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
 
         let body = self.parse_block_statement()?;
 
-        Some(Expression::FunctionLiteral { parameters, body })
+        Some(Expression::FunctionLiteral { parameters, parameter_types, parameter_defaults, variadic, body })
     }
 
-    // Parse function parameters separated by commas
-    fn parse_function_parameters(&mut self) -> Option<Vec<Expression>> {
+    // Parse shomoy niropekkho fn(...) { ... }
+    fn parse_async_function(&mut self) -> Option<Expression> {
+        if !self.expect_peek(TokenType::Function) {
+            return None;
+        }
+        let function = self.parse_function_literal()?;
+        Some(Expression::AsyncFunction(Box::new(function)))
+    }
+
+    // Parse opekkha koro expr; binds like a prefix operator, so opekkha koro f() + 1 adds to the awaited value
+    fn parse_await_expression(&mut self) -> Option<Expression> {
+        self.next_token();
+        let value = self.parse_expression(Precedence::PREFIX)?;
+        Some(Expression::Await(Box::new(value)))
+    }
+
+    // Parse parameter names with their optional types and defaults (b = 10);
+    // the last one may be variadic (...rest)
+    fn parse_function_parameters(&mut self) -> Option<FunctionParameters> {
         let mut identifiers = Vec::new();
+        let mut types = Vec::new();
+        let mut defaults: Vec<Option<Expression>> = Vec::new();
+        let mut variadic = false;
 
         if self.peek_token_is(TokenType::RParen) {
             self.next_token();
-            return Some(identifiers);
+            return Some((identifiers, types, defaults, variadic));
         }
 
-        self.next_token();
+        loop {
+            self.next_token();
+            if self.cur_token_is(TokenType::Ellipsis) {
+                variadic = true;
+                self.next_token();
+            }
+            if self.reject_reserved_name(&self.cur_token.clone(), "parameter") {
+                return None;
+            }
+            identifiers.push(Expression::Identifier(self.cur_token.literal.clone(), self.cur_span()));
+            types.push(self.parse_optional_type_annotation()?);
 
-        identifiers.push(Expression::Identifier(self.cur_token.literal.clone()));
+            let default = if self.peek_token_is(TokenType::Assign) {
+                self.next_token(); // consume '='
+                self.next_token();
+                Some(self.parse_expression(Precedence::LOWEST)?)
+            } else {
+                None
+            };
+            if default.is_some() && variadic {
+                self.error("a variadic parameter (...) can't have a default value".to_string());
+                return None;
+            }
+            if default.is_none() && !variadic && defaults.iter().any(Option::is_some) {
+                self.error("parameters with default values must come after those without".to_string());
+                return None;
+            }
+            defaults.push(default);
 
-        while self.peek_token_is(TokenType::Comma) {
-            self.next_token();
+            if !self.peek_token_is(TokenType::Comma) {
+                break;
+            }
+            if variadic {
+                self.error("a variadic parameter (...) must be the last parameter".to_string());
+                return None;
+            }
             self.next_token();
-            identifiers.push(Expression::Identifier(self.cur_token.literal.clone()));
         }
 
         if !self.expect_peek(TokenType::RParen) {
             return None;
         }
 
-        Some(identifiers)
+        Some((identifiers, types, defaults, variadic))
     }
 
     // Infix parsing functions
 
     // Parse infix expressions like 1 + 2 or a == b
     fn parse_infix_expression(&mut self, left: Expression) -> Option<Expression> {
-        let operator = self.cur_token.literal.clone();
+        let operator = self.cur_token.literal.to_string();
+        let span = self.cur_span();
         let precedence = self.cur_precedence();
         self.next_token();
         let right = self.parse_expression(precedence)?;
@@ -735,46 +1006,117 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
             left: Box::new(left),
             operator,
             right: Box::new(right),
+            span,
+        })
+    }
+
+    // Parse a ** b; the right side is parsed one level lower so that
+    // 2 ** 3 ** 2 groups as 2 ** (3 ** 2)
+    fn parse_power_expression(&mut self, left: Expression) -> Option<Expression> {
+        let operator = self.cur_token.literal.to_string();
+        let span = self.cur_span();
+        self.next_token();
+        let right = self.parse_expression(Precedence::PREFIX)?;
+        Some(Expression::Infix {
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+            span,
+        })
+    }
+
+    // Parse cond ? a : b into an if expression with both branches. The branches are
+    // parsed at the lowest precedence, so a ? b : c ? d : e nests to the right.
+    fn parse_conditional_expression(&mut self, condition: Expression) -> Option<Expression> {
+        self.next_token(); // consume '?'
+        let consequence = self.parse_expression(Precedence::LOWEST)?;
+        if !self.expect_peek(TokenType::Colon) {
+            return None;
+        }
+        self.next_token(); // consume ':'
+        let alternative = self.parse_expression(Precedence::LOWEST)?;
+        Some(Expression::If {
+            condition: Box::new(condition),
+            consequence: vec![Statement::ExpressionStatement { expression: consequence }],
+            alternative: Some(Box::new(alternative)),
         })
     }
 
     // Parse function call expression with arguments
     fn parse_call_expression(&mut self, function: Expression) -> Option<Expression> {
+        let span = self.cur_span();
         let arguments = self.parse_call_arguments()?;
         Some(Expression::Call {
             function: Box::new(function),
             arguments,
+            span,
+        })
+    }
+
+    // Parse index expression like arr[0] or hash["key"]
+    fn parse_index_expression(&mut self, left: Expression) -> Option<Expression> {
+        let span = self.cur_span();
+        self.next_token();
+        let index = self.parse_expression(Precedence::LOWEST)?;
+
+        if !self.expect_peek(TokenType::RBracket) {
+            return None;
+        }
+
+        Some(Expression::Index {
+            left: Box::new(left),
+            index: Box::new(index),
+            span,
+        })
+    }
+
+    // Parse member access obj.field, which is sugar for obj["field"]
+    fn parse_member_expression(&mut self, left: Expression) -> Option<Expression> {
+        let span = self.cur_span();
+        if !self.peek_token_is(TokenType::Ident) {
+            self.error_at_peek(format!(
+                "expected a field name after '.', got {:?} instead",
+                self.peek_token.token_type
+            ));
+            return None;
+        }
+        self.next_token();
+
+        Some(Expression::Index {
+            left: Box::new(left),
+            index: Box::new(Expression::StringLiteral(self.cur_token.literal.to_string())),
+            span,
         })
     }
 
     // Parse list of call arguments separated by commas
     fn parse_call_arguments(&mut self) -> Option<Vec<Expression>> {
-        let mut args = Vec::new();
+        self.parse_expression_list(TokenType::RParen)
+    }
 
-        if self.peek_token_is(TokenType::RParen) {
+    // Parse comma separated expressions up to the given closing token
+    fn parse_expression_list(&mut self, end: TokenType) -> Option<Vec<Expression>> {
+        let mut list = Vec::new();
+
+        if self.peek_token_is(end) {
             self.next_token();
-            return Some(args);
+            return Some(list);
         }
 
         self.next_token();
-
-        if let Some(exp) = self.parse_expression(Precedence::LOWEST) {
-            args.push(exp);
-        }
+        list.push(self.parse_expression(Precedence::LOWEST)?);
 
         while self.peek_token_is(TokenType::Comma) {
             self.next_token();
             self.next_token();
-            if let Some(exp) = self.parse_expression(Precedence::LOWEST) {
-                args.push(exp);
-            }
+            list.push(self.parse_expression(Precedence::LOWEST)?);
         }
 
-        if !self.expect_peek(TokenType::RParen) {
+        if !self.expect_peek(end) {
             return None;
         }
 
-        Some(args)
+        Some(list)
     }
 
     // Helper methods for token checks and errors
@@ -798,29 +1140,50 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
         }
     }
 
+    // Record an error at `token`, prefixed with its line:column
+    fn error_at(&mut self, token: &Token, message: String) {
+        self.errors.push(format!("{}:{}: {}", token.line, token.column, message));
+    }
+
+    // Record an error at the current token
+    fn error(&mut self, message: String) {
+        self.error_at(&self.cur_token.clone(), message);
+    }
+
+    // Record an error at the peek token, for errors about what should have come next
+    fn error_at_peek(&mut self, message: String) {
+        self.error_at(&self.peek_token.clone(), message);
+    }
+
     // Record an error for unexpected peek token
     fn peek_error(&mut self, t: TokenType) {
-        self.errors.push(format!(
-            "expected next token to be {:?}, got {:?} instead",
-            t, self.peek_token.token_type
-        ));
+        let message = format!("expected next token to be {:?}, got {:?} instead", t, self.peek_token.token_type);
+        self.error_at_peek(message);
     }
 
     // Record error for missing prefix parse function for token
     fn no_prefix_parse_fn_error(&mut self, t: TokenType) {
-        self.errors.push(format!("no prefix parse function for {:?} found", t));
+        // The lexer puts its own message in an Illegal token's literal
+        if t == TokenType::Illegal && self.cur_token.literal.chars().count() > 1 {
+            self.error(self.cur_token.literal.to_string());
+            return;
+        }
+        self.error(format!("no prefix parse function for {:?} found", t));
     }
 
     // Map token type to its parsing precedence level
     fn get_precedence(&self, t: &TokenType) -> Precedence {
         match t {
             TokenType::Eq | TokenType::NotEq => Precedence::EQUALS,
-            TokenType::Lt | TokenType::Gt => Precedence::LESSGREATER,
+            TokenType::Lt | TokenType::Gt | TokenType::LtEq | TokenType::GtEq => Precedence::LESSGREATER,
             TokenType::Plus | TokenType::Minus => Precedence::SUM,
             TokenType::Slash | TokenType::Asterisk => Precedence::PRODUCT,
+            TokenType::DoubleStar => Precedence::POWER,
+            TokenType::Question => Precedence::CONDITIONAL,
             TokenType::LParen => Precedence::CALL,
-            TokenType::Ebong => Precedence::EQUALS, // logical AND
-            TokenType::Othoba => Precedence::EQUALS,    // logical OR
+            TokenType::LBracket | TokenType::Fullstop => Precedence::INDEX,
+            TokenType::Ebong => Precedence::LOGICALAND,  // logical AND
+            TokenType::Othoba => Precedence::LOGICALOR,   // logical OR
             _ => Precedence::LOWEST,
         }
     }
@@ -878,3 +1241,415 @@ fn parse_template_literal(&mut self) -> Option<Vec<Expression>> {
         // TODO: implement code execution here
     }
 }
+
+// Splits the line:column prefix off a parser error message: "1:8: expected ..." gives
+// (Some((1, 8)), "expected ..."); a message without one comes back unchanged
+pub fn split_error_position(message: &str) -> (Option<(usize, usize)>, &str) {
+    let mut parts = message.splitn(3, ':');
+    if let (Some(line), Some(column), Some(rest)) = (parts.next(), parts.next(), parts.next()) {
+        if let (Ok(line), Ok(column), Some(rest)) = (line.parse(), column.parse(), rest.strip_prefix(' ')) {
+            return (Some((line, column)), rest);
+        }
+    }
+    (None, message)
+}
+
+// The arithmetic operator behind a compound assignment token
+fn compound_assign_operator(token_type: &TokenType) -> Option<&'static str> {
+    match token_type {
+        TokenType::PlusEq => Some("+"),
+        TokenType::MinusEq => Some("-"),
+        TokenType::StarEq => Some("*"),
+        TokenType::SlashEq => Some("/"),
+        _ => None,
+    }
+}
+
+// Tokens that can only begin a statement, used to resynchronize after a syntax error
+fn is_statement_start(token_type: &TokenType) -> bool {
+    matches!(
+        token_type,
+        TokenType::Dhoro
+            | TokenType::ReturnKoro
+            | TokenType::ProtitarJonno
+            | TokenType::Jotokhon
+            | TokenType::AgeKoro
+            | TokenType::Thamo
+            | TokenType::Choluk
+            | TokenType::Dekhao
+            | TokenType::Jodi
+            | TokenType::ImportKoro
+            | TokenType::TypeBanao
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Parse a snippet and fail the test on any parser error
+    fn parse(input: &str) -> Program {
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+        program
+    }
+
+    // Extract the template parts of a `dekhao { ... }` statement
+    fn template_parts(stmt: &Statement) -> Vec<Expression> {
+        match stmt {
+            Statement::ExpressionStatement { expression: Expression::Call { arguments, .. } } => {
+                match &arguments[0] {
+                    Expression::TemplateLiteral { parts } => parts.clone(),
+                    other => panic!("expected template literal, got {:?}", other),
+                }
+            }
+            other => panic!("expected dekhao call, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_template_literal_preserves_spacing() {
+        let program = parse("dekhao {Hello (name) welcome}");
+        assert_eq!(
+            template_parts(&program[0]),
+            vec![
+                Expression::StringLiteral("Hello ".to_string()),
+                Expression::Identifier("name".into(), Span::default()),
+                Expression::StringLiteral(" welcome".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_literal_preserves_punctuation() {
+        let program = parse("dekhao { Hi (name),  your age is (age + 1)! } dhoro x = 1;");
+        assert_eq!(program.len(), 2);
+        assert_eq!(
+            template_parts(&program[0]),
+            vec![
+                Expression::StringLiteral("Hi ".to_string()),
+                Expression::Identifier("name".into(), Span::default()),
+                Expression::StringLiteral(",  your age is ".to_string()),
+                Expression::Infix {
+                    left: Box::new(Expression::Identifier("age".into(), Span::default())),
+                    operator: "+".to_string(),
+                    right: Box::new(Expression::IntegerLiteral(1)),
+                    span: Span::default(),
+                },
+                Expression::StringLiteral("!".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_template_literal() {
+        let mut parser = Parser::new(Lexer::new("dekhao {Hello (name".to_string()));
+        parser.parse_program();
+        assert!(!parser.errors.is_empty());
+    }
+
+    #[test]
+    fn test_let_type_annotation() {
+        let program = parse("dhoro x: purno_songkha = 5; dhoro y = 6;");
+        match &program[0] {
+            Statement::Let { type_annotation, .. } => {
+                assert_eq!(type_annotation.as_deref(), Some("purno_songkha"))
+            }
+            other => panic!("expected let, got {:?}", other),
+        }
+        match &program[1] {
+            Statement::Let { type_annotation, .. } => assert_eq!(type_annotation, &None),
+            other => panic!("expected let, got {:?}", other),
+        }
+        assert_eq!(program[0].to_string(), "dhoro x: purno_songkha = 5;");
+    }
+
+    #[test]
+    fn test_function_parameter_annotations() {
+        let program = parse("fn(a: shobdo, b, c: purno_songkha) { a }");
+        match &program[0] {
+            Statement::ExpressionStatement { expression: Expression::FunctionLiteral { parameters, parameter_types, .. } } => {
+                assert_eq!(parameters.len(), 3);
+                assert_eq!(
+                    parameter_types,
+                    &vec![Some("shobdo".to_string()), None, Some("purno_songkha".to_string())]
+                );
+            }
+            other => panic!("expected function literal, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_missing_type_name_is_an_error() {
+        let mut parser = Parser::new(Lexer::new("dhoro x: = 5;".to_string()));
+        parser.parse_program();
+        assert!(!parser.errors.is_empty());
+    }
+
+    #[test]
+    fn test_comments_kept_with_comment_lexer() {
+        let input = "// shuru\ndhoro x = 1; /* majhe\nlekha */\njotokhon x < 3 { x = x + 1; # bhitore\n}\n// shesh";
+        let mut parser = Parser::new(Lexer::with_comments(input.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+
+        assert_eq!(program.len(), 5);
+        assert_eq!(program[0], Statement::CommentSingleLine { content: " shuru".to_string() });
+        assert!(matches!(program[1], Statement::Let { .. }));
+        assert_eq!(program[2], Statement::CommentMultiLine { content: " majhe\nlekha ".to_string() });
+        match &program[3] {
+            Statement::While { body, .. } => {
+                assert_eq!(body.len(), 2);
+                assert_eq!(body[1], Statement::CommentSingleLine { content: " bhitore".to_string() });
+            }
+            other => panic!("expected while loop, got {:?}", other),
+        }
+        assert_eq!(program[4], Statement::CommentSingleLine { content: " shesh".to_string() });
+    }
+
+    #[test]
+    fn test_comments_dropped_by_default() {
+        let program = parse("// shuru\ndhoro x = 1; /* majhe */");
+        assert_eq!(program.len(), 1);
+    }
+
+    #[test]
+    fn test_reports_every_syntax_error() {
+        let input = "dhoro = 5;\ndhoro x = 1;\ndhoro y 10;\ndekhao(x +)\njodi (x > 0) { dekhao(x) }\ndhoro z = ;";
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let program = parser.parse_program();
+
+        assert_eq!(parser.errors.len(), 4, "errors: {:?}", parser.errors);
+        // The valid statements between the errors are still parsed
+        assert!(matches!(program[0], Statement::Let { .. }));
+        assert!(matches!(program[1], Statement::ExpressionStatement { expression: Expression::If { .. } }));
+    }
+
+    #[test]
+    fn test_recovers_inside_blocks() {
+        let input = "jotokhon (Ha) {\n    dhoro = 1;\n    dekhao(2)\n}\ndekhao(3 +)\ndekhao(4)";
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let program = parser.parse_program();
+
+        assert_eq!(parser.errors.len(), 2, "errors: {:?}", parser.errors);
+        assert_eq!(program.len(), 2);
+        match &program[0] {
+            Statement::While { body, .. } => assert_eq!(body.len(), 1),
+            other => panic!("expected while loop, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_import_statement() {
+        let program = parse("import koro \"lib/gonit.bp\" ei hisebe gonit;\namdani koro \"a.bp\" as a");
+        assert_eq!(
+            program,
+            vec![
+                Statement::Import { path: "lib/gonit.bp".to_string(), alias: "gonit".to_string() },
+                Statement::Import { path: "a.bp".to_string(), alias: "a".to_string() },
+            ]
+        );
+        assert_eq!(program[0].to_string(), "import koro \"lib/gonit.bp\" ei hisebe gonit;");
+
+        for input in ["import koro gonit", "import koro \"gonit.bp\"", "import koro \"gonit.bp\" ei hisebe 5"] {
+            let mut parser = Parser::new(Lexer::new(input.to_string()));
+            parser.parse_program();
+            assert!(!parser.errors.is_empty(), "expected an error for {}", input);
+        }
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let program = parse("x += 1; y *= a + 2");
+        assert_eq!(program[0].to_string(), "x = (x + 1);");
+        assert_eq!(program[1].to_string(), "y = (y * (a + 2));");
+    }
+
+    #[test]
+    fn test_conditional_expression() {
+        assert_eq!(parse("x > 1 ? a : b")[0].to_string(), "jodi (x > 1) { a } nahoy { b }");
+        assert_eq!(
+            parse("a ? 1 : b ? 2 : 3")[0].to_string(),
+            "jodi a { 1 } nahoy jodi b { 2 } nahoy { 3 }"
+        );
+        assert_eq!(parse("a ? b ? 1 : 2 : 3")[0].to_string(), "jodi a { jodi b { 1 } nahoy { 2 } } nahoy { 3 }");
+        assert_eq!(parse("dhoro y = a == b ? x + 1 : 0;")[0].to_string(), "dhoro y = jodi (a == b) { (x + 1) } nahoy { 0 };");
+
+        let mut parser = Parser::new(Lexer::new("a ? 1".to_string()));
+        parser.parse_program();
+        assert!(!parser.errors.is_empty());
+    }
+
+    #[test]
+    fn test_async_and_await() {
+        let program = parse("dhoro f = async fn(x) { ferot x; }; opekkha koro f(1) + 2");
+        assert_eq!(program[0].to_string(), "dhoro f = shomoy niropekkho fn(x) { return x; };");
+        assert_eq!(program[1].to_string(), "((opekkha koro f(1)) + 2)");
+
+        let mut parser = Parser::new(Lexer::new("shomoy niropekkho 5".to_string()));
+        parser.parse_program();
+        assert!(!parser.errors.is_empty());
+    }
+
+    #[test]
+    fn test_default_parameters() {
+        let program = parse("fn(a, b = 10, c: shobdo = \"x\") { ferot a; }");
+        match &program[0] {
+            Statement::ExpressionStatement { expression: Expression::FunctionLiteral { parameter_defaults, .. } } => {
+                assert_eq!(
+                    parameter_defaults,
+                    &vec![None, Some(Expression::IntegerLiteral(10)), Some(Expression::StringLiteral("x".to_string()))]
+                );
+            }
+            other => panic!("expected function literal, got {:?}", other),
+        }
+        assert_eq!(program[0].to_string(), "fn(a, b = 10, c: shobdo = \"x\") { return a; }");
+
+        for (input, error) in [
+            ("fn(a = 1, b) { ferot b; }", "1:11: parameters with default values must come after those without"),
+            ("fn(a, ...rest = 1) { ferot a; }", "1:17: a variadic parameter (...) can't have a default value"),
+        ] {
+            let mut parser = Parser::new(Lexer::new(input.to_string()));
+            parser.parse_program();
+            assert_eq!(parser.errors.first().map(String::as_str), Some(error), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_variadic_parameter() {
+        let program = parse("fn(a, ...rest) { ferot rest; }");
+        match &program[0] {
+            Statement::ExpressionStatement { expression: Expression::FunctionLiteral { parameters, variadic, .. } } => {
+                assert_eq!(parameters.len(), 2);
+                assert!(*variadic);
+            }
+            other => panic!("expected function literal, got {:?}", other),
+        }
+        assert_eq!(program[0].to_string(), "fn(a, ...rest) { return rest; }");
+
+        let mut parser = Parser::new(Lexer::new("fn(...rest, a) { ferot a; }".to_string()));
+        parser.parse_program();
+        assert_eq!(parser.errors[0], "1:7: a variadic parameter (...) must be the last parameter");
+    }
+
+    #[test]
+    fn test_lex_timing() {
+        let source = "dhoro x = 1; dekhao {x holo (x)}";
+        let mut timed = Parser::with_lex_timing(Lexer::new(source.to_string()));
+        assert_eq!(timed.parse_program(), parse(source));
+        assert!(timed.lexing_time().is_some());
+        assert_eq!(Parser::new(Lexer::new(source.to_string())).lexing_time(), None);
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let mut parser = Parser::new(Lexer::new("dhoro x = 1;\ndhoro y 2;".to_string()));
+        parser.parse_program();
+        assert_eq!(parser.errors[0], "2:9: expected next token to be Assign, got Int instead");
+        assert_eq!(
+            split_error_position(&parser.errors[0]),
+            (Some((2, 9)), "expected next token to be Assign, got Int instead")
+        );
+        assert_eq!(split_error_position("no position: here"), (None, "no position: here"));
+    }
+
+    #[test]
+    fn test_reserved_keywords_as_names() {
+        let cases = [
+            ("dhoro jodi = 5;", "1:7: 'jodi' is a reserved keyword and can't be used as a variable name"),
+            ("dhoro input = 5;", "1:7: 'input' is a reserved keyword and can't be used as a variable name"),
+            ("time = 5;", "1:1: 'time' is a reserved keyword and can't be used as a variable name"),
+            ("none += 1;", "1:1: 'none' is a reserved keyword and can't be used as a variable name"),
+            ("fn(a, nil) { a }", "1:7: 'nil' is a reserved keyword and can't be used as a parameter name"),
+        ];
+        for (input, expected) in cases {
+            let mut parser = Parser::new(Lexer::new(input.to_string()));
+            parser.parse_program();
+            assert_eq!(parser.errors.first().map(String::as_str), Some(expected), "for {}", input);
+        }
+
+        // Ordinary names, and reserved words used as values, are fine
+        parse("dhoro boyosh = 5; boyosh = 6; boyosh += 1; fn(a, b) { a }; dhoro naam = input();");
+    }
+
+    #[test]
+    fn test_type_definition() {
+        let program = parse("type banao Point { x, y } type banao Khali {}");
+        assert_eq!(
+            program[0],
+            Statement::TypeDefinition { name: "Point".to_string(), fields: vec!["x".to_string(), "y".to_string()] }
+        );
+        assert_eq!(program[0].to_string(), "type banao Point { x, y }");
+        assert_eq!(program[1], Statement::TypeDefinition { name: "Khali".to_string(), fields: vec![] });
+
+        for input in ["type banao { x }", "type banao Point x, y", "type banao Point { x, x }"] {
+            let mut parser = Parser::new(Lexer::new(input.to_string()));
+            parser.parse_program();
+            assert!(!parser.errors.is_empty(), "expected an error for {}", input);
+        }
+    }
+
+    #[test]
+    fn test_loop_labels() {
+        let program = parse("bahir: jotokhon (Ha) { thamo bahir; choluk bahir; thamo; }");
+        let Statement::Labeled { label, body } = &program[0] else {
+            panic!("expected a labeled loop, got {:?}", program[0]);
+        };
+        assert_eq!(&**label, "bahir");
+        let Statement::While { body, .. } = &**body else {
+            panic!("expected a while loop, got {:?}", body);
+        };
+        assert_eq!(
+            body,
+            &vec![Statement::Break(Some(label.clone())), Statement::Continue(Some(label.clone())), Statement::Break(None)]
+        );
+
+        // The label must sit on the same line as thamo
+        let program = parse("jotokhon (Ha) { thamo\n bahir }");
+        let Statement::While { body, .. } = &program[0] else { unreachable!() };
+        assert_eq!(body[0], Statement::Break(None));
+
+        let mut parser = Parser::new(Lexer::new("bahir: dhoro x = 1;".to_string()));
+        parser.parse_program();
+        assert!(!parser.errors.is_empty());
+    }
+
+    #[test]
+    fn test_loops_take_a_trailing_semicolon() {
+        let program = parse(r#"jotokhon (Na) { }; age koro { } jotokhon (Na); protitar jonno (c jekhane "ab") { }; dekhao("done")"#);
+        assert_eq!(program.len(), 4);
+        assert!(matches!(program[0], Statement::While { .. }));
+        assert!(matches!(program[1], Statement::DoWhile { .. }));
+        assert!(matches!(program[2], Statement::ForEach { .. }));
+        assert!(matches!(program[3], Statement::ExpressionStatement { .. }));
+    }
+
+    #[test]
+    fn test_member_access() {
+        let program = parse("person.name; a.b.c; gonit.jog(1, 2)");
+        assert_eq!(program[0], parse("person[\"name\"]")[0]);
+        assert_eq!(program[1], parse("a[\"b\"][\"c\"]")[0]);
+        assert_eq!(program[2], parse("gonit[\"jog\"](1, 2)")[0]);
+
+        for input in ["person.", "person.5"] {
+            let mut parser = Parser::new(Lexer::new(input.to_string()));
+            parser.parse_program();
+            assert!(!parser.errors.is_empty(), "expected an error for {}", input);
+        }
+    }
+
+    #[test]
+    fn test_power_operator_precedence() {
+        let cases = [
+            ("2 ** 3 ** 2", "(2 ** (3 ** 2))"),
+            ("2 * 3 ** 2", "(2 * (3 ** 2))"),
+            ("-2 ** 2", "(-(2 ** 2))"),
+            ("2 ** -1", "(2 ** (-1))"),
+            ("a ** b + c", "((a ** b) + c)"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse(input)[0].to_string(), expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,397 @@
+// compiler/src/stdlib/collections.rs
+
+use crate::environment::Environment;
+use crate::evaluator::{as_float, is_truthy, values_equal};
+use crate::object::{BuiltinFunction, Object};
+use std::cmp::Ordering;
+
+// Arrays are values in B+: passing one to a function passes a copy. So none of
+// these functions change the array they are given; each returns a new array
+// and the result is assigned back, e.g. `list = push(list, 4);`.
+
+/// Load all array functions into environment
+pub fn load_collection_functions(env: &mut Environment) {
+    env.add_builtin("push".to_string(), Object::BuiltinNative(array_push));
+    env.add_builtin("pop".to_string(), Object::BuiltinNative(array_pop));
+    env.add_builtin("slice".to_string(), Object::BuiltinNative(array_slice));
+    env.add_builtin("concat".to_string(), Object::BuiltinNative(array_concat));
+    // Shared with the string module, so either can be loaded first
+    env.add_builtin("contains".to_string(), Object::BuiltinNative(super::string::contains));
+    env.add_builtin("index_of".to_string(), Object::BuiltinNative(super::string::index_of));
+
+    // These call back into B+ functions, so the evaluator applies them (see array_map below)
+    env.add_builtin("map".to_string(), Object::BuiltinFunction(BuiltinFunction::Map));
+    env.add_builtin("filter".to_string(), Object::BuiltinFunction(BuiltinFunction::Filter));
+    env.add_builtin("reduce".to_string(), Object::BuiltinFunction(BuiltinFunction::Reduce));
+    env.add_builtin("sort".to_string(), Object::BuiltinFunction(BuiltinFunction::Sort));
+
+    // Bangla variants
+    env.add_builtin("ongsho".to_string(), Object::BuiltinNative(array_slice)); // part in Bangla
+    env.add_builtin("songjog".to_string(), Object::BuiltinNative(array_concat)); // joining in Bangla
+}
+
+/// New array with the value appended: push(arr, x)
+fn array_push(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("push() takes exactly two arguments (array, value)".to_string());
+    }
+
+    match &args[0] {
+        Object::Array(elements) => {
+            let mut elements = elements.clone();
+            elements.push(args[1].clone());
+            Object::Array(elements)
+        }
+        other => Object::Error(format!("push() requires an array, got {}", other.type_name())),
+    }
+}
+
+/// New array without its last element; popping an empty array is an error
+fn array_pop(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("pop() takes exactly one argument".to_string());
+    }
+
+    match &args[0] {
+        Object::Array(elements) if elements.is_empty() => Object::Error("pop() on an empty array".to_string()),
+        Object::Array(elements) => Object::Array(elements[..elements.len() - 1].to_vec()),
+        other => Object::Error(format!("pop() requires an array, got {}", other.type_name())),
+    }
+}
+
+/// Elements [start, end) of an array: slice(arr, start, end).
+/// Indices are clamped to the array, so out-of-range or reversed bounds give a shorter (or empty) array.
+fn array_slice(args: Vec<Object>) -> Object {
+    if args.len() != 3 {
+        return Object::Error("slice() takes exactly three arguments (array, start, end)".to_string());
+    }
+
+    match (&args[0], &args[1], &args[2]) {
+        (Object::Array(elements), Object::Integer(start), Object::Integer(end)) => {
+            let clamp = |index: i64| index.clamp(0, elements.len() as i64) as usize;
+            let (start, end) = (clamp(*start), clamp(*end));
+            if start >= end {
+                return Object::Array(Vec::new());
+            }
+            Object::Array(elements[start..end].to_vec())
+        }
+        _ => Object::Error("slice() requires an array and two integers".to_string()),
+    }
+}
+
+/// New array with the elements of both arrays in order: concat(arr1, arr2)
+fn array_concat(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("concat() takes exactly two arguments".to_string());
+    }
+
+    match (&args[0], &args[1]) {
+        (Object::Array(first), Object::Array(second)) => {
+            Object::Array(first.iter().chain(second).cloned().collect())
+        }
+        _ => Object::Error("concat() requires two arrays".to_string()),
+    }
+}
+
+/// Whether any element equals the value, comparing arrays and hashes by contents: contains(arr, x)
+pub(crate) fn array_contains(args: Vec<Object>) -> Object {
+    match array_position("contains", &args) {
+        Ok(position) => Object::Boolean(position.is_some()),
+        Err(error) => error,
+    }
+}
+
+/// Index of the first element equal to the value, or -1: index_of(arr, x)
+pub(crate) fn array_index_of(args: Vec<Object>) -> Object {
+    match array_position("index_of", &args) {
+        Ok(Some(index)) => Object::Integer(index as i64),
+        Ok(None) => Object::Integer(-1),
+        Err(error) => error,
+    }
+}
+
+fn array_position(name: &str, args: &[Object]) -> Result<Option<usize>, Object> {
+    match args {
+        [Object::Array(elements), value] => Ok(elements.iter().position(|element| values_equal(element, value))),
+        [other, _] => Err(Object::Error(format!("{}() requires an array, got {}", name, other.type_name()))),
+        _ => Err(Object::Error(format!("{}() takes exactly two arguments", name))),
+    }
+}
+
+// Higher-order functions. `apply` calls a B+ function (user-defined or builtin)
+// with arguments; the evaluator passes its own apply_function here.
+
+/// New array with the function applied to each element: map(arr, fn)
+pub fn array_map(args: Vec<Object>, apply: &mut dyn FnMut(Object, Vec<Object>) -> Object) -> Object {
+    let (elements, function) = match array_and_function("map", args) {
+        Ok(found) => found,
+        Err(error) => return error,
+    };
+
+    let mut mapped = Vec::with_capacity(elements.len());
+    for element in elements {
+        let value = apply(function.clone(), vec![element]);
+        if value.is_error() {
+            return value;
+        }
+        mapped.push(value);
+    }
+    Object::Array(mapped)
+}
+
+/// New array with the elements the function returns a true value for: filter(arr, fn)
+pub fn array_filter(args: Vec<Object>, apply: &mut dyn FnMut(Object, Vec<Object>) -> Object) -> Object {
+    let (elements, function) = match array_and_function("filter", args) {
+        Ok(found) => found,
+        Err(error) => return error,
+    };
+
+    let mut kept = Vec::new();
+    for element in elements {
+        let keep = apply(function.clone(), vec![element.clone()]);
+        if keep.is_error() {
+            return keep;
+        }
+        if is_truthy(&keep) {
+            kept.push(element);
+        }
+    }
+    Object::Array(kept)
+}
+
+/// Folds the array from the left: reduce(arr, fn(acc, x), init)
+pub fn array_reduce(mut args: Vec<Object>, apply: &mut dyn FnMut(Object, Vec<Object>) -> Object) -> Object {
+    if args.len() != 3 {
+        return Object::Error("reduce() takes exactly three arguments (array, function, initial value)".to_string());
+    }
+    let mut accumulator = args.pop().unwrap();
+    let (elements, function) = match array_and_function("reduce", args) {
+        Ok(found) => found,
+        Err(error) => return error,
+    };
+
+    for element in elements {
+        accumulator = apply(function.clone(), vec![accumulator, element]);
+        if accumulator.is_error() {
+            return accumulator;
+        }
+    }
+    accumulator
+}
+
+/// New array sorted ascending: sort(arr) for numbers, strings or chars (one kind per array),
+/// or sort(arr, fn(a, b)) with a comparator returning a negative, zero or positive integer.
+/// The sort is stable, so equal elements keep their order.
+pub fn array_sort(args: Vec<Object>, apply: &mut dyn FnMut(Object, Vec<Object>) -> Object) -> Object {
+    if args.len() == 1 {
+        return match args.into_iter().next() {
+            Some(Object::Array(elements)) => sort_natural(elements),
+            Some(other) => Object::Error(format!("sort() requires an array, got {}", other.type_name())),
+            None => unreachable!(),
+        };
+    }
+    let (mut elements, comparator) = match array_and_function("sort", args) {
+        Ok(found) => found,
+        Err(error) => return error,
+    };
+
+    // sort_by can't stop early, so remember the first failure and treat the rest as equal
+    let mut failure = None;
+    elements.sort_by(|a, b| {
+        if failure.is_some() {
+            return Ordering::Equal;
+        }
+        match apply(comparator.clone(), vec![a.clone(), b.clone()]) {
+            Object::Integer(order) => order.cmp(&0),
+            Object::Error(message) => {
+                failure = Some(Object::Error(message));
+                Ordering::Equal
+            }
+            other => {
+                failure = Some(Object::Error(format!(
+                    "sort() comparator must return an integer, got {}",
+                    other.type_name()
+                )));
+                Ordering::Equal
+            }
+        }
+    });
+    failure.unwrap_or(Object::Array(elements))
+}
+
+// Sorts numbers, strings or chars by their natural order; mixing kinds is an error
+fn sort_natural(mut elements: Vec<Object>) -> Object {
+    let kind = |object: &Object| match object {
+        Object::Integer(_) | Object::Float(_) => Some("number"),
+        Object::String(_) => Some("string"),
+        Object::Char(_) => Some("char"),
+        _ => None,
+    };
+
+    let Some(first) = elements.first() else {
+        return Object::Array(elements);
+    };
+    let expected = kind(first);
+    if let Some(other) = elements.iter().find(|e| kind(e).is_none() || kind(e) != expected) {
+        return Object::Error(format!(
+            "sort() can't compare {} with {}; pass a comparator function",
+            first.type_name(),
+            other.type_name()
+        ));
+    }
+
+    elements.sort_by(|a, b| match (a, b) {
+        (Object::Integer(x), Object::Integer(y)) => x.cmp(y),
+        (Object::String(x), Object::String(y)) => x.cmp(y),
+        (Object::Char(x), Object::Char(y)) => x.cmp(y),
+        _ => as_float(a).unwrap().total_cmp(&as_float(b).unwrap()),
+    });
+    Object::Array(elements)
+}
+
+// Checks the (array, function) arguments shared by the higher-order functions
+fn array_and_function(name: &str, args: Vec<Object>) -> Result<(Vec<Object>, Object), Object> {
+    if args.len() != 2 {
+        return Err(Object::Error(format!("{}() takes exactly two arguments (array, function)", name)));
+    }
+    let mut args = args.into_iter();
+    match (args.next(), args.next()) {
+        (Some(Object::Array(elements)), Some(function)) if function.is_callable() => Ok((elements, function)),
+        (Some(Object::Array(_)), Some(other)) => {
+            Err(Object::Error(format!("{}() requires a function, got {}", name, other.type_name())))
+        }
+        (Some(other), _) => Err(Object::Error(format!("{}() requires an array, got {}", name, other.type_name()))),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(values: &[i64]) -> Object {
+        Object::Array(values.iter().map(|v| Object::Integer(*v)).collect())
+    }
+
+    #[test]
+    fn test_contains_and_index_of() {
+        let nested = Object::Array(vec![Object::Integer(1), ints(&[2, 3]), Object::String("ek".to_string())]);
+
+        assert_eq!(array_contains(vec![nested.clone(), Object::Integer(1)]), Object::Boolean(true));
+        assert_eq!(array_contains(vec![nested.clone(), ints(&[2, 3])]), Object::Boolean(true));
+        assert_eq!(array_contains(vec![nested.clone(), ints(&[3, 2])]), Object::Boolean(false));
+        assert_eq!(array_contains(vec![nested.clone(), Object::Integer(2)]), Object::Boolean(false));
+        assert_eq!(array_contains(vec![ints(&[]), Object::Null]), Object::Boolean(false));
+
+        assert_eq!(array_index_of(vec![nested.clone(), ints(&[2, 3])]), Object::Integer(1));
+        assert_eq!(array_index_of(vec![nested.clone(), Object::String("ek".to_string())]), Object::Integer(2));
+        assert_eq!(array_index_of(vec![nested.clone(), Object::String("dui".to_string())]), Object::Integer(-1));
+        assert_eq!(array_index_of(vec![ints(&[5, 7, 5]), Object::Integer(5)]), Object::Integer(0));
+
+        assert!(array_contains(vec![nested]).is_error());
+        assert!(array_index_of(vec![Object::Integer(1), Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    fn test_push() {
+        assert_eq!(array_push(vec![ints(&[1, 2]), Object::Integer(3)]), ints(&[1, 2, 3]));
+        assert_eq!(array_push(vec![ints(&[]), ints(&[1])]), Object::Array(vec![ints(&[1])]));
+        assert!(array_push(vec![Object::Integer(1), Object::Integer(2)]).is_error());
+        assert!(array_push(vec![ints(&[1])]).is_error());
+    }
+
+    #[test]
+    fn test_pop() {
+        assert_eq!(array_pop(vec![ints(&[1, 2, 3])]), ints(&[1, 2]));
+        assert_eq!(array_pop(vec![ints(&[1])]), ints(&[]));
+        assert!(array_pop(vec![ints(&[])]).is_error());
+        assert!(array_pop(vec![Object::String("abc".to_string())]).is_error());
+    }
+
+    #[test]
+    fn test_slice() {
+        let numbers = ints(&[0, 1, 2, 3, 4]);
+        let slice = |start, end| array_slice(vec![numbers.clone(), Object::Integer(start), Object::Integer(end)]);
+        assert_eq!(slice(1, 3), ints(&[1, 2]));
+        assert_eq!(slice(0, 5), ints(&[0, 1, 2, 3, 4]));
+        // Out-of-range bounds are clamped
+        assert_eq!(slice(3, 99), ints(&[3, 4]));
+        assert_eq!(slice(-2, 2), ints(&[0, 1]));
+        assert_eq!(slice(7, 9), ints(&[]));
+        assert_eq!(slice(3, 1), ints(&[]));
+        assert!(array_slice(vec![numbers, Object::Integer(0)]).is_error());
+    }
+
+    // Stand-in for the evaluator: calls native builtins directly
+    fn apply(function: Object, args: Vec<Object>) -> Object {
+        match function {
+            Object::BuiltinNative(native) => native(args),
+            other => Object::Error(format!("not a function: {}", other)),
+        }
+    }
+
+    #[test]
+    fn test_higher_order_arguments() {
+        let nested = Object::Array(vec![ints(&[1, 2]), ints(&[3])]);
+        let popped = Object::Array(vec![ints(&[1]), ints(&[])]);
+        assert_eq!(array_map(vec![nested.clone(), Object::BuiltinNative(array_pop)], &mut apply), popped);
+        assert_eq!(
+            array_reduce(vec![nested.clone(), Object::BuiltinNative(array_concat), ints(&[0])], &mut apply),
+            ints(&[0, 1, 2, 3])
+        );
+        assert!(array_filter(vec![nested.clone(), Object::Integer(1)], &mut apply).is_error());
+        assert!(array_map(vec![Object::Integer(1), Object::BuiltinNative(array_pop)], &mut apply).is_error());
+        assert!(array_reduce(vec![nested, Object::BuiltinNative(array_concat)], &mut apply).is_error());
+
+        // Every kind of callable gets past the argument check, to be applied
+        let record_type = Object::RecordType { name: "Ek".to_string(), fields: vec!["x".to_string()] };
+        let compiled = crate::compiler::CompiledFunction { arity: 1, locals: vec![], instructions: vec![] };
+        let functions = [
+            crate::run("fn(x) { x }").unwrap(),
+            Object::CompiledFunction(std::rc::Rc::new(compiled)),
+            crate::run("shomoy niropekkho fn(x) { x }").unwrap(),
+            record_type.clone(),
+            Object::BuiltinFunction(crate::object::BuiltinFunction::Map),
+            Object::BuiltinNative(array_pop),
+        ];
+        for function in functions {
+            let mut applied = Vec::new();
+            array_map(vec![ints(&[1]), function.clone()], &mut |f, _| {
+                applied.push(f);
+                Object::Null
+            });
+            assert_eq!(applied, vec![function]);
+        }
+        assert_eq!(
+            array_map(vec![ints(&[1]), Object::String("f".to_string())], &mut apply),
+            Object::Error("map() requires a function, got string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sort() {
+        let sort = |array: Object| array_sort(vec![array], &mut apply);
+        assert_eq!(sort(ints(&[3, 1, 2, -5])), ints(&[-5, 1, 2, 3]));
+        assert_eq!(sort(ints(&[])), ints(&[]));
+
+        let words = |list: &[&str]| Object::Array(list.iter().map(|w| Object::String(w.to_string())).collect());
+        assert_eq!(sort(words(&["kola", "aam", "jam"])), words(&["aam", "jam", "kola"]));
+
+        let mixed_numbers = Object::Array(vec![Object::Float(2.5), Object::Integer(1), Object::Float(-0.5)]);
+        assert_eq!(
+            sort(mixed_numbers),
+            Object::Array(vec![Object::Float(-0.5), Object::Integer(1), Object::Float(2.5)])
+        );
+
+        assert!(sort(Object::Array(vec![Object::Integer(1), Object::String("a".to_string())])).is_error());
+        assert!(sort(Object::Array(vec![ints(&[1]), ints(&[2])])).is_error());
+        assert!(sort(Object::Integer(3)).is_error());
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(array_concat(vec![ints(&[1, 2]), ints(&[3])]), ints(&[1, 2, 3]));
+        assert_eq!(array_concat(vec![ints(&[]), ints(&[])]), ints(&[]));
+        assert!(array_concat(vec![ints(&[1]), Object::Integer(2)]).is_error());
+    }
+}
@@ -3,6 +3,7 @@
 use crate::environment::Environment;
 use crate::object::Object;
 use std::fs;
+use std::io::Write;
 
 /// Load all file-related functions into environment
 pub fn load_file_functions(env: &mut Environment) {
@@ -14,6 +15,11 @@ pub fn load_file_functions(env: &mut Environment) {
     env.add_builtin("file_exists".to_string(), Object::BuiltinNative(file_exists));
     env.add_builtin("delete_file".to_string(), Object::BuiltinNative(delete_file));
     env.add_builtin("copy_file".to_string(), Object::BuiltinNative(copy_file));
+    env.add_builtin("append_file".to_string(), Object::BuiltinNative(append_file));
+    env.add_builtin("read_lines".to_string(), Object::BuiltinNative(read_lines));
+    env.add_builtin("file_size".to_string(), Object::BuiltinNative(file_size));
+    env.add_builtin("is_dir".to_string(), Object::BuiltinNative(is_dir));
+    env.add_builtin("modified_time".to_string(), Object::BuiltinNative(modified_time));
 }
 
 /// Read file content (moved from environment.rs)
@@ -95,4 +101,136 @@ fn copy_file(args: Vec<Object>) -> Object {
         }
         _ => Object::Error("copy_file() requires two string arguments".to_string()),
     }
-}
\ No newline at end of file
+}
+
+/// Append content to the end of a file, creating it if needed
+fn append_file(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("append_file() requires exactly two arguments (filename, content)".to_string());
+    }
+
+    match (&args[0], &args[1]) {
+        (Object::String(filename), content) => {
+            let result = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(filename)
+                .and_then(|mut file| write!(file, "{}", content));
+            match result {
+                Ok(_) => Object::Null,
+                Err(e) => Object::Error(format!("File append error: {}", e)),
+            }
+        }
+        _ => Object::Error("append_file() requires a string filename as first argument".to_string()),
+    }
+}
+
+/// Read a file as an array of lines (without trailing newlines)
+fn read_lines(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("read_lines() requires exactly one argument (filename)".to_string());
+    }
+
+    match &args[0] {
+        Object::String(filename) => match fs::read_to_string(filename) {
+            Ok(content) => Object::Array(
+                content.lines().map(|line| Object::String(line.to_string())).collect(),
+            ),
+            Err(e) => Object::Error(format!("File read error: {}", e)),
+        },
+        _ => Object::Error("read_lines() requires a string filename".to_string()),
+    }
+}
+
+/// Look up metadata for the single path argument of `name()`
+fn path_metadata(name: &str, args: &[Object]) -> Result<fs::Metadata, Object> {
+    if args.len() != 1 {
+        return Err(Object::Error(format!("{}() requires exactly one argument (path)", name)));
+    }
+
+    match &args[0] {
+        Object::String(path) => {
+            fs::metadata(path).map_err(|e| Object::Error(format!("File metadata error: {}", e)))
+        }
+        _ => Err(Object::Error(format!("{}() requires a string path", name))),
+    }
+}
+
+/// Size of a file in bytes
+fn file_size(args: Vec<Object>) -> Object {
+    match path_metadata("file_size", &args) {
+        Ok(meta) => Object::Integer(meta.len() as i64),
+        Err(err) => err,
+    }
+}
+
+/// Check if a path is a directory
+fn is_dir(args: Vec<Object>) -> Object {
+    match path_metadata("is_dir", &args) {
+        Ok(meta) => Object::Boolean(meta.is_dir()),
+        Err(err) => err,
+    }
+}
+
+/// Last modification time as a Unix timestamp (seconds)
+fn modified_time(args: Vec<Object>) -> Object {
+    let modified = path_metadata("modified_time", &args).and_then(|meta| {
+        meta.modified()
+            .map_err(|e| Object::Error(format!("File metadata error: {}", e)))
+    });
+    match modified {
+        Ok(time) => match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since_epoch) => Object::Integer(since_epoch.as_secs() as i64),
+            Err(e) => Object::Integer(-(e.duration().as_secs() as i64)),
+        },
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Object {
+        Object::String(text.to_string())
+    }
+
+    #[test]
+    fn test_append_then_read_lines() {
+        let path = std::env::temp_dir().join(format!("bplus_append_{}.txt", std::process::id()));
+        let path_str = path.to_string_lossy().to_string();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(append_file(vec![s(&path_str), s("prothom\n")]), Object::Null);
+        assert_eq!(append_file(vec![s(&path_str), s("ditiyo\r\n")]), Object::Null);
+        assert_eq!(append_file(vec![s(&path_str), Object::Integer(3)]), Object::Null);
+        assert_eq!(
+            read_lines(vec![s(&path_str)]),
+            Object::Array(vec![s("prothom"), s("ditiyo"), s("3")])
+        );
+
+        fs::remove_file(&path).unwrap();
+        assert!(read_lines(vec![s(&path_str)]).is_error());
+    }
+
+    #[test]
+    fn test_file_metadata() {
+        let path = std::env::temp_dir().join(format!("bplus_meta_{}.txt", std::process::id()));
+        let path_str = path.to_string_lossy().to_string();
+        fs::write(&path, "বাংলা").unwrap();
+
+        assert_eq!(file_size(vec![s(&path_str)]), Object::Integer(15));
+        assert_eq!(is_dir(vec![s(&path_str)]), Object::Boolean(false));
+        let dir = std::env::temp_dir().to_string_lossy().to_string();
+        assert_eq!(is_dir(vec![s(&dir)]), Object::Boolean(true));
+        match modified_time(vec![s(&path_str)]) {
+            Object::Integer(t) => assert!(t > 1_600_000_000),
+            other => panic!("expected timestamp, got {:?}", other),
+        }
+
+        fs::remove_file(&path).unwrap();
+        assert!(file_size(vec![s(&path_str)]).is_error());
+        assert!(is_dir(vec![s(&path_str)]).is_error());
+        assert!(modified_time(vec![s(&path_str)]).is_error());
+    }
+}
@@ -0,0 +1,369 @@
+// compiler/src/stdlib/json.rs
+
+use crate::environment::Environment;
+use crate::error::json_string;
+use crate::object::Object;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Load all JSON functions into environment
+pub fn load_json_functions(env: &mut Environment) {
+    env.add_builtin("json_parse".to_string(), Object::BuiltinNative(json_parse));
+    env.add_builtin("json_stringify".to_string(), Object::BuiltinNative(json_stringify));
+}
+
+/// Parse JSON text into B+ values: objects become hashes (keys in document order),
+/// numbers become integers unless they have a fraction or exponent
+fn json_parse(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("json_parse() takes exactly one argument".to_string());
+    }
+
+    match &args[0] {
+        Object::String(text) => {
+            let mut parser = JsonParser { chars: text.chars().peekable(), position: 0 };
+            match parser.parse_document() {
+                Ok(value) => value,
+                Err(message) => Object::Error(format!("json_parse(): {}", message)),
+            }
+        }
+        other => Object::Error(format!("json_parse() requires a string, got {}", other.type_name())),
+    }
+}
+
+/// Turn a B+ value into compact JSON text. Hash keys are written as strings and
+/// chars as one-character strings; functions and non-finite floats can't be represented.
+fn json_stringify(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("json_stringify() takes exactly one argument".to_string());
+    }
+
+    let mut output = String::new();
+    match write_json(&args[0], &mut output) {
+        Ok(()) => Object::String(output),
+        Err(message) => Object::Error(format!("json_stringify(): {}", message)),
+    }
+}
+
+fn write_json(value: &Object, output: &mut String) -> Result<(), String> {
+    match value {
+        Object::Null => output.push_str("null"),
+        Object::Boolean(b) => output.push_str(if *b { "true" } else { "false" }),
+        Object::Integer(i) => output.push_str(&i.to_string()),
+        Object::Float(x) if x.is_finite() => output.push_str(&value.to_string()),
+        Object::Float(x) => return Err(format!("{} has no JSON representation", x)),
+        Object::String(s) => output.push_str(&json_string(s)),
+        Object::Char(c) => output.push_str(&json_string(&c.to_string())),
+        Object::Array(elements) => {
+            output.push('[');
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_json(element, output)?;
+            }
+            output.push(']');
+        }
+        Object::Hash(pairs) => {
+            output.push('{');
+            for (i, (key, value)) in pairs.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                output.push_str(&json_string(&key.to_string()));
+                output.push(':');
+                write_json(value, output)?;
+            }
+            output.push('}');
+        }
+        other => return Err(format!("a {} has no JSON representation", other.type_name())),
+    }
+    Ok(())
+}
+
+// Recursive descent parser over the characters of a JSON document
+struct JsonParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    position: usize, // Characters consumed so far, for error messages
+}
+
+impl JsonParser<'_> {
+    fn parse_document(&mut self) -> Result<Object, String> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        match self.chars.peek() {
+            None => Ok(value),
+            Some(c) => Err(format!("unexpected '{}' after the value at position {}", c, self.position)),
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c.is_some() {
+            self.position += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.chars.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("expected '{}' but found '{}' at position {}", expected, c, self.position - 1)),
+            None => Err(format!("expected '{}' but the input ended", expected)),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Object, String> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => self.parse_string().map(Object::String),
+            Some('t') => self.parse_word("true", Object::Boolean(true)),
+            Some('f') => self.parse_word("false", Object::Boolean(false)),
+            Some('n') => self.parse_word("null", Object::Null),
+            Some(c) if *c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) => Err(format!("unexpected '{}' at position {}", c, self.position)),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn parse_word(&mut self, word: &str, value: Object) -> Result<Object, String> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> Result<Object, String> {
+        self.expect('{')?;
+        let mut pairs: Vec<(Object, Object)> = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.next();
+            return Ok(Object::Hash(pairs));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.chars.peek() != Some(&'"') {
+                return Err(format!("expected a string key at position {}", self.position));
+            }
+            let key = Object::String(self.parse_string()?);
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.parse_value()?;
+
+            // A repeated key keeps its first position and takes the last value, like hash literals
+            match pairs.iter_mut().find(|(k, _)| *k == key) {
+                Some(entry) => entry.1 = value,
+                None => pairs.push((key, value)),
+            }
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Object::Hash(pairs)),
+                Some(c) => return Err(format!("expected ',' or '}}' but found '{}' at position {}", c, self.position - 1)),
+                None => return Err("unterminated object".to_string()),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Object, String> {
+        self.expect('[')?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.next();
+            return Ok(Object::Array(elements));
+        }
+
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Object::Array(elements)),
+                Some(c) => return Err(format!("expected ',' or ']' but found '{}' at position {}", c, self.position - 1)),
+                None => return Err("unterminated array".to_string()),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut text = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.parse_unicode_escape()?,
+                        Some(c) => return Err(format!("invalid escape '\\{}' at position {}", c, self.position - 1)),
+                        None => return Err("unterminated string".to_string()),
+                    };
+                    text.push(escaped);
+                }
+                Some(c) if (c as u32) < 0x20 => {
+                    return Err(format!("control character in string at position {}", self.position - 1))
+                }
+                Some(c) => text.push(c),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
+    }
+
+    // \uXXXX, including surrogate pairs written as two escapes
+    fn parse_unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.parse_hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| format!("invalid \\u escape at position {}", self.position));
+        }
+        self.expect('\\')?;
+        self.expect('u')?;
+        let low = self.parse_hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(format!("invalid surrogate pair at position {}", self.position));
+        }
+        let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+        char::from_u32(code).ok_or_else(|| format!("invalid \\u escape at position {}", self.position))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self.next().and_then(|c| c.to_digit(16));
+            match digit {
+                Some(d) => code = code * 16 + d,
+                None => return Err(format!("invalid \\u escape at position {}", self.position)),
+            }
+        }
+        Ok(code)
+    }
+
+    fn parse_number(&mut self) -> Result<Object, String> {
+        let start = self.position;
+        let mut literal = String::new();
+        let mut is_float = false;
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '0'..='9' | '-' | '+' => literal.push(c),
+                '.' | 'e' | 'E' => {
+                    is_float = true;
+                    literal.push(c);
+                }
+                _ => break,
+            }
+            self.next();
+        }
+
+        // Rust's number parsing is more lenient than JSON, so check the shape first
+        let digits = literal.strip_prefix('-').unwrap_or(&literal);
+        let leading_zero = digits.len() > 1 && digits.starts_with('0') && digits.as_bytes()[1].is_ascii_digit();
+        let malformed = !digits.starts_with(|c: char| c.is_ascii_digit())
+            || leading_zero
+            || digits.contains(".e")
+            || digits.contains(".E")
+            || digits.ends_with(['.', 'e', 'E', '+', '-']);
+        if malformed {
+            return Err(format!("invalid number '{}' at position {}", literal, start));
+        }
+
+        if !is_float {
+            if let Ok(i) = literal.parse::<i64>() {
+                return Ok(Object::Integer(i));
+            }
+        }
+        // Integers too large for i64 fall back to floats
+        literal
+            .parse::<f64>()
+            .map(Object::Float)
+            .map_err(|_| format!("invalid number '{}' at position {}", literal, start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Object {
+        Object::String(text.to_string())
+    }
+
+    fn parse(text: &str) -> Object {
+        json_parse(vec![s(text)])
+    }
+
+    #[test]
+    fn test_parse_nested_document() {
+        let parsed = parse(r#" {"naam": "রহিম", "boyosh": 30, "uchchota": 1.75, "chatro": false,
+                              "bishoy": ["gonit", null, {"nombor": -2e2}], "thikana": {}} "#);
+        assert_eq!(
+            parsed,
+            Object::Hash(vec![
+                (s("naam"), s("রহিম")),
+                (s("boyosh"), Object::Integer(30)),
+                (s("uchchota"), Object::Float(1.75)),
+                (s("chatro"), Object::Boolean(false)),
+                (
+                    s("bishoy"),
+                    Object::Array(vec![
+                        s("gonit"),
+                        Object::Null,
+                        Object::Hash(vec![(s("nombor"), Object::Float(-200.0))]),
+                    ])
+                ),
+                (s("thikana"), Object::Hash(vec![])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = r#"{"a":[1,2.5,"x\"y\n"],"b":{"c":null,"d":true},"e":"ব"}"#;
+        let parsed = parse(text);
+        assert_eq!(json_stringify(vec![parsed.clone()]), s(r#"{"a":[1,2.5,"x\"y\n"],"b":{"c":null,"d":true},"e":"ব"}"#));
+        let again = json_stringify(vec![parsed.clone()]);
+        assert_eq!(json_parse(vec![again]), parsed);
+    }
+
+    #[test]
+    fn test_escapes() {
+        assert_eq!(parse(r#""tab\tslash\/""#), s("tab\tslash/"));
+        assert_eq!(parse(r#""😀""#), s("😀"));
+        assert!(parse(r#""\ud83d""#).is_error());
+        assert!(parse(r#""\x""#).is_error());
+    }
+
+    #[test]
+    fn test_invalid_json_is_an_error() {
+        for text in ["", "{", "[1, 2", "{\"a\" 1}", "{'a': 1}", "[1,]", "tru", "01", "1.", "-", "1 2", "\"abc", "{\"a\":1,}"] {
+            assert!(parse(text).is_error(), "expected an error for {:?}", text);
+        }
+        assert!(json_parse(vec![Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    fn test_stringify_values() {
+        assert_eq!(json_stringify(vec![Object::Float(2.0)]), s("2.0"));
+        assert_eq!(json_stringify(vec![Object::Char('c')]), s("\"c\""));
+        assert_eq!(json_stringify(vec![Object::Hash(vec![(Object::Integer(1), Object::Null)])]), s("{\"1\":null}"));
+        assert!(json_stringify(vec![Object::Float(f64::NAN)]).is_error());
+        assert!(json_stringify(vec![Object::BuiltinNative(json_parse)]).is_error());
+    }
+}
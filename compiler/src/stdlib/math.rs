@@ -1,7 +1,10 @@
 // compiler/src/stdlib/math.rs
 
 use crate::environment::Environment;
+use crate::evaluator::{as_float, eval_infix_expression};
 use crate::object::Object;
+use std::cell::Cell;
+use std::cmp::Ordering;
 
 /// Load all math functions into environment
 pub fn load_math_functions(env: &mut Environment) {
@@ -10,7 +13,25 @@ pub fn load_math_functions(env: &mut Environment) {
     env.add_builtin("pow".to_string(), Object::BuiltinNative(pow_function));
     env.add_builtin("min".to_string(), Object::BuiltinNative(min_function));
     env.add_builtin("max".to_string(), Object::BuiltinNative(max_function));
+    env.add_builtin("sum".to_string(), Object::BuiltinNative(sum_function));
+    env.add_builtin("product".to_string(), Object::BuiltinNative(product_function));
+    env.add_builtin("average".to_string(), Object::BuiltinNative(average_function));
     env.add_builtin("random".to_string(), Object::BuiltinNative(random_function));
+    env.add_builtin("random_range".to_string(), Object::BuiltinNative(random_range_function));
+    env.add_builtin("seed".to_string(), Object::BuiltinNative(seed_function));
+    env.add_builtin("floor".to_string(), Object::BuiltinNative(floor_function));
+    env.add_builtin("ceil".to_string(), Object::BuiltinNative(ceil_function));
+    env.add_builtin("round".to_string(), Object::BuiltinNative(round_function));
+    env.add_builtin("truncate".to_string(), Object::BuiltinNative(truncate_function));
+    env.add_builtin("round_to".to_string(), Object::BuiltinNative(round_to_function));
+    env.add_builtin("gcd".to_string(), Object::BuiltinNative(gcd_function));
+    env.add_builtin("lcm".to_string(), Object::BuiltinNative(lcm_function));
+    env.add_builtin("factorial".to_string(), Object::BuiltinNative(factorial_function));
+
+    // Bangla aliases (gosagu = GCD, lasagu = LCM)
+    env.add_builtin("gosagu".to_string(), Object::BuiltinNative(gcd_function));
+    env.add_builtin("lasagu".to_string(), Object::BuiltinNative(lcm_function));
+    env.add_builtin("kromogunito".to_string(), Object::BuiltinNative(factorial_function));
 }
 
 /// Square root function
@@ -18,15 +39,18 @@ fn sqrt_function(args: Vec<Object>) -> Object {
     if args.len() != 1 {
         return Object::Error("sqrt() takes exactly one argument".to_string());
     }
+    let n = match as_float(&args[0]) {
+        Some(n) => n,
+        None => return Object::Error("sqrt() requires a number".to_string()),
+    };
+    if n < 0.0 {
+        return Object::Error("Cannot take square root of negative number".to_string());
+    }
+    let result = n.sqrt();
+    // Perfect squares of integers stay integers
     match &args[0] {
-        Object::Integer(n) => {
-            if *n < 0 {
-                Object::Error("Cannot take square root of negative number".to_string())
-            } else {
-                Object::Integer((*n as f64).sqrt() as i64)
-            }
-        }
-        _ => Object::Error("sqrt() requires a number".to_string()),
+        Object::Integer(_) => whole_or_float(result),
+        _ => Object::Float(result),
     }
 }
 
@@ -36,59 +60,543 @@ fn abs_function(args: Vec<Object>) -> Object {
         return Object::Error("abs() takes exactly one argument".to_string());
     }
     match &args[0] {
-        Object::Integer(n) => Object::Integer(n.abs()),
+        Object::Integer(n) => match n.checked_abs() {
+            Some(value) => Object::Integer(value),
+            None => Object::Error("abs() result is too large".to_string()),
+        },
+        Object::Float(x) => Object::Float(x.abs()),
         _ => Object::Error("abs() requires a number".to_string()),
     }
 }
 
 /// Power function (base^exponent)
-fn pow_function(args: Vec<Object>) -> Object {
+pub(crate) fn pow_function(args: Vec<Object>) -> Object {
     if args.len() != 2 {
         return Object::Error("pow() takes exactly two arguments".to_string());
     }
     match (&args[0], &args[1]) {
-        (Object::Integer(base), Object::Integer(exp)) => {
-            if *exp < 0 {
-                Object::Error("Negative exponents not supported yet".to_string())
-            } else {
-                Object::Integer(base.pow(*exp as u32))
+        (Object::Integer(base), Object::Integer(exp)) if *exp >= 0 => {
+            match u32::try_from(*exp).ok().and_then(|e| base.checked_pow(e)) {
+                Some(value) => Object::Integer(value),
+                None => Object::Error("pow() result is too large".to_string()),
             }
         }
-        _ => Object::Error("pow() requires two numbers".to_string()),
+        (Object::Integer(base), Object::Integer(exp)) => {
+            // Negative exponent: whole results like pow(1, -3) stay integers
+            whole_or_float((*base as f64).powf(*exp as f64))
+        }
+        (base, exp) => match (as_float(base), as_float(exp)) {
+            (Some(b), Some(e)) => Object::Float(b.powf(e)),
+            _ => Object::Error("pow() requires two numbers".to_string()),
+        },
     }
 }
 
-/// Minimum of two numbers
+/// Smallest of the arguments: min(3, 1, 2) or min([3, 1, 2])
 fn min_function(args: Vec<Object>) -> Object {
-    if args.len() != 2 {
-        return Object::Error("min() takes exactly two arguments".to_string());
+    extreme("min", args, Ordering::Less)
+}
+
+/// Largest of the arguments: max(1, 5, 3) or max([1, 5, 3])
+fn max_function(args: Vec<Object>) -> Object {
+    extreme("max", args, Ordering::Greater)
+}
+
+/// Shared body of min and max: the number that compares `wanted` to all the others,
+/// taken from the arguments or from a single array argument. Integers and floats
+/// compare by value and the winner keeps its type; on ties the first one wins.
+fn extreme(name: &str, args: Vec<Object>, wanted: Ordering) -> Object {
+    let values = match args.as_slice() {
+        [Object::Array(elements)] => elements.as_slice(),
+        [] => return Object::Error(format!("{}() takes numbers or an array of numbers", name)),
+        _ => args.as_slice(),
+    };
+
+    let mut best: Option<&Object> = None;
+    for value in values {
+        if !matches!(value, Object::Integer(_) | Object::Float(_)) {
+            return Object::Error(format!("{}() requires numbers, got {}", name, value.type_name()));
+        }
+        best = match best.map(|current| compare_numbers(value, current)) {
+            None => Some(value),
+            Some(Some(ordering)) if ordering == wanted => Some(value),
+            Some(Some(_)) => best,
+            Some(None) => return Object::Error(format!("{}() cannot compare NaN", name)),
+        };
     }
-    match (&args[0], &args[1]) {
-        (Object::Integer(a), Object::Integer(b)) => Object::Integer(*a.min(b)),
-        _ => Object::Error("min() requires two numbers".to_string()),
+
+    match best {
+        Some(value) => value.clone(),
+        None => Object::Error(format!("{}() of an empty array", name)),
     }
 }
 
-/// Maximum of two numbers  
-fn max_function(args: Vec<Object>) -> Object {
-    if args.len() != 2 {
-        return Object::Error("max() takes exactly two arguments".to_string());
+/// Orders two numbers by value; None when either is NaN
+fn compare_numbers(a: &Object, b: &Object) -> Option<Ordering> {
+    match (a, b) {
+        (Object::Integer(x), Object::Integer(y)) => Some(x.cmp(y)),
+        (Object::Integer(x), Object::Float(y)) => (*x as f64).partial_cmp(y),
+        (Object::Float(x), Object::Integer(y)) => x.partial_cmp(&(*y as f64)),
+        (Object::Float(x), Object::Float(y)) => x.partial_cmp(y),
+        _ => None,
     }
-    match (&args[0], &args[1]) {
-        (Object::Integer(a), Object::Integer(b)) => Object::Integer(*a.max(b)),
-        _ => Object::Error("max() requires two numbers".to_string()),
+}
+
+/// Sum of an array of numbers; an integer unless a float is involved, 0 for an empty array
+fn sum_function(args: Vec<Object>) -> Object {
+    fold_numbers("sum", args, Object::Integer(0), "+")
+}
+
+/// Product of an array of numbers; an integer unless a float is involved, 1 for an empty array
+fn product_function(args: Vec<Object>) -> Object {
+    fold_numbers("product", args, Object::Integer(1), "*")
+}
+
+/// Mean of a non-empty array of numbers, always a float
+fn average_function(args: Vec<Object>) -> Object {
+    let count = match args.as_slice() {
+        [Object::Array(elements)] if elements.is_empty() => {
+            return Object::Error("average() of an empty array".to_string())
+        }
+        [Object::Array(elements)] => elements.len(),
+        _ => 0,
+    };
+    match fold_numbers("average", args, Object::Float(0.0), "+") {
+        Object::Float(total) => Object::Float(total / count as f64),
+        other => other,
     }
 }
 
-/// Random number generator
-fn random_function(_args: Vec<Object>) -> Object {
+/// Shared body of sum, product and average: combines the elements of one array
+/// argument with an arithmetic operator, so int/float mixing and overflow errors
+/// work the same as in B+ expressions
+fn fold_numbers(name: &str, args: Vec<Object>, initial: Object, operator: &str) -> Object {
+    let elements = match args.as_slice() {
+        [Object::Array(elements)] => elements,
+        [other] => return Object::Error(format!("{}() requires an array, got {}", name, other.type_name())),
+        _ => return Object::Error(format!("{}() takes exactly one argument", name)),
+    };
+
+    let mut total = initial;
+    for element in elements {
+        if as_float(element).is_none() {
+            return Object::Error(format!("{}() requires numbers, got {}", name, element.type_name()));
+        }
+        total = match eval_infix_expression(operator, total, element.clone()) {
+            Object::Error(message) => return Object::Error(format!("{}(): {}", name, message)),
+            value => value,
+        };
+    }
+    total
+}
+
+// Per-thread xorshift64 state; 0 means "not seeded yet"
+thread_local! {
+    static RNG_STATE: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Next xorshift64 value, seeding from the clock on first use
+fn next_random() -> u64 {
+    RNG_STATE.with(|state| {
+        let mut x = state.get();
+        if x == 0 {
+            x = time_seed();
+        }
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+        x
+    })
+}
+
+/// Seed derived from the current time (never zero)
+fn time_seed() -> u64 {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
     use std::time::{SystemTime, UNIX_EPOCH};
-    
-    // Simple random number generator
+
     let mut hasher = DefaultHasher::new();
     SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos().hash(&mut hasher);
-    let random_value = (hasher.finish() % 100) as i64; // 0-99
-    Object::Integer(random_value)
-}
\ No newline at end of file
+    hasher.finish() | 1
+}
+
+/// Random float in [0, 1)
+fn random_function(args: Vec<Object>) -> Object {
+    if !args.is_empty() {
+        return Object::Error("random() takes no arguments".to_string());
+    }
+    // Top 53 bits give every representable step of an f64 mantissa
+    Object::Float((next_random() >> 11) as f64 / (1u64 << 53) as f64)
+}
+
+/// Random integer in [min, max)
+fn random_range_function(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("random_range() takes exactly two arguments".to_string());
+    }
+    match (&args[0], &args[1]) {
+        (Object::Integer(min), Object::Integer(max)) => {
+            if min >= max {
+                return Object::Error(format!("random_range() requires min < max, got {} and {}", min, max));
+            }
+            let span = (*max as i128 - *min as i128) as u128;
+            let offset = next_random() as u128 % span;
+            Object::Integer((*min as i128 + offset as i128) as i64)
+        }
+        _ => Object::Error("random_range() requires two integers".to_string()),
+    }
+}
+
+/// Seed the random generator so later random()/random_range() calls are reproducible
+fn seed_function(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("seed() takes exactly one argument".to_string());
+    }
+    match &args[0] {
+        Object::Integer(n) => {
+            // Spread the seed out (splitmix64 step) so small seeds don't start near zero
+            let mut z = (*n as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+            RNG_STATE.with(|state| state.set(if z == 0 { 1 } else { z }));
+            Object::Null
+        }
+        _ => Object::Error("seed() requires an integer".to_string()),
+    }
+}
+
+/// Floor: largest integer not greater than the argument
+fn floor_function(args: Vec<Object>) -> Object {
+    round_with("floor", args, f64::floor)
+}
+
+/// Ceil: smallest integer not less than the argument
+fn ceil_function(args: Vec<Object>) -> Object {
+    round_with("ceil", args, f64::ceil)
+}
+
+/// Round to the nearest integer; halves round away from zero (2.5 -> 3, -2.5 -> -3)
+fn round_function(args: Vec<Object>) -> Object {
+    round_with("round", args, f64::round)
+}
+
+/// Truncate: drop the fractional part, rounding toward zero
+fn truncate_function(args: Vec<Object>) -> Object {
+    round_with("truncate", args, f64::trunc)
+}
+
+/// Round to a number of decimal places: round_to(3.14159, 2) -> 3.14, with halves
+/// away from zero: round_to(2.5, 0) -> 3.0.
+/// Integers have no decimals to drop, so they pass through unchanged.
+fn round_to_function(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("round_to() takes exactly two arguments".to_string());
+    }
+    let digits = match decimal_places("round_to", &args[1]) {
+        Ok(digits) => digits,
+        Err(error) => return error,
+    };
+    match &args[0] {
+        Object::Integer(n) => Object::Integer(*n),
+        Object::Float(x) => Object::Float(round_half_away(*x, digits)),
+        _ => Object::Error("round_to() requires a number".to_string()),
+    }
+}
+
+/// `x` rounded to `digits` decimal places, halves away from zero like round();
+/// shared by round_to and format_float so both give the same digits.
+pub(crate) fn round_half_away(x: f64, digits: usize) -> f64 {
+    // Once the scaled value is past 2^52 it has no fractional part left,
+    // so x is already that precise
+    let scale = 10f64.powi(digits as i32);
+    let scaled = x * scale;
+    if scaled.is_finite() && scaled.abs() < (1u64 << 52) as f64 {
+        scaled.round() / scale
+    } else {
+        x
+    }
+}
+
+/// Most decimal places round_to and format_float accept
+pub(crate) const MAX_DECIMAL_PLACES: i64 = 100;
+
+/// Digits argument of round_to and format_float, checked to be 0..=MAX_DECIMAL_PLACES
+pub(crate) fn decimal_places(name: &str, digits: &Object) -> Result<usize, Object> {
+    match digits {
+        Object::Integer(d) if (0..=MAX_DECIMAL_PLACES).contains(d) => Ok(*d as usize),
+        Object::Integer(_) => Err(Object::Error(format!(
+            "{}() digits must be between 0 and {}",
+            name, MAX_DECIMAL_PLACES
+        ))),
+        other => Err(Object::Error(format!("{}() digits must be an integer, got {}", name, other.type_name()))),
+    }
+}
+
+/// Shared body of the rounding helpers.
+/// Integers pass through unchanged; floats are rounded and returned as an
+/// Integer, or as a Float when the result doesn't fit in an integer (inf, NaN, huge values).
+fn round_with(name: &str, args: Vec<Object>, op: fn(f64) -> f64) -> Object {
+    if args.len() != 1 {
+        return Object::Error(format!("{}() takes exactly one argument", name));
+    }
+    match &args[0] {
+        Object::Integer(n) => Object::Integer(*n),
+        Object::Float(x) => whole_or_float(op(*x)),
+        _ => Object::Error(format!("{}() requires a number", name)),
+    }
+}
+
+/// Greatest common divisor (Euclid's algorithm), always non-negative
+fn gcd_function(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("gcd() takes exactly two arguments".to_string());
+    }
+    match (&args[0], &args[1]) {
+        (Object::Integer(a), Object::Integer(b)) => match gcd(*a, *b) {
+            Some(g) => Object::Integer(g),
+            None => Object::Error("gcd() result is too large".to_string()),
+        },
+        _ => Object::Error("gcd() requires two integers".to_string()),
+    }
+}
+
+/// Least common multiple, computed as |a / gcd(a, b) * b|
+fn lcm_function(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("lcm() takes exactly two arguments".to_string());
+    }
+    match (&args[0], &args[1]) {
+        (Object::Integer(0), Object::Integer(_)) | (Object::Integer(_), Object::Integer(0)) => {
+            Object::Integer(0)
+        }
+        (Object::Integer(a), Object::Integer(b)) => {
+            let result = gcd(*a, *b)
+                .and_then(|g| (a / g).checked_mul(*b))
+                .and_then(|m| m.checked_abs());
+            match result {
+                Some(value) => Object::Integer(value),
+                None => Object::Error("lcm() result is too large".to_string()),
+            }
+        }
+        _ => Object::Error("lcm() requires two integers".to_string()),
+    }
+}
+
+/// Factorial of a non-negative integer
+fn factorial_function(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("factorial() takes exactly one argument".to_string());
+    }
+    match &args[0] {
+        Object::Integer(n) if *n < 0 => {
+            Object::Error("Cannot take factorial of negative number".to_string())
+        }
+        Object::Integer(n) => match (1..=*n).try_fold(1i64, |acc, k| acc.checked_mul(k)) {
+            Some(value) => Object::Integer(value),
+            None => Object::Error(format!("factorial({}) is too large", n)),
+        },
+        _ => Object::Error("factorial() requires an integer".to_string()),
+    }
+}
+
+/// Euclid's algorithm; None only when the answer (2^63) doesn't fit in i64
+fn gcd(a: i64, b: i64) -> Option<i64> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    i64::try_from(a).ok()
+}
+
+/// Integer when the value is whole and fits, otherwise Float
+fn whole_or_float(value: f64) -> Object {
+    if value.is_finite() && value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        Object::Integer(value as i64)
+    } else {
+        Object::Float(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(obj: Object, expected: f64) {
+        match obj {
+            Object::Float(x) => assert!((x - expected).abs() < 1e-9, "{} != {}", x, expected),
+            other => panic!("expected float, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_close(sqrt_function(vec![Object::Integer(2)]), std::f64::consts::SQRT_2);
+        assert_eq!(sqrt_function(vec![Object::Integer(9)]), Object::Integer(3));
+        assert_close(sqrt_function(vec![Object::Float(2.25)]), 1.5);
+        assert!(sqrt_function(vec![Object::Integer(-1)]).is_error());
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(abs_function(vec![Object::Integer(-7)]), Object::Integer(7));
+        assert_eq!(abs_function(vec![Object::Float(-2.5)]), Object::Float(2.5));
+        assert_eq!(abs_function(vec![Object::Float(1.5)]), Object::Float(1.5));
+        assert_eq!(
+            abs_function(vec![Object::Integer(i64::MIN)]),
+            Object::Error("abs() result is too large".to_string())
+        );
+        assert!(abs_function(vec![Object::String("-1".to_string())]).is_error());
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(pow_function(vec![Object::Integer(2), Object::Integer(10)]), Object::Integer(1024));
+        assert_close(pow_function(vec![Object::Integer(2), Object::Integer(-1)]), 0.5);
+        assert_close(pow_function(vec![Object::Float(2.0), Object::Float(0.5)]), std::f64::consts::SQRT_2);
+        assert!(pow_function(vec![Object::Integer(10), Object::Integer(100)]).is_error());
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(floor_function(vec![Object::Float(2.7)]), Object::Integer(2));
+        assert_eq!(ceil_function(vec![Object::Float(2.1)]), Object::Integer(3));
+        assert_eq!(round_function(vec![Object::Float(2.5)]), Object::Integer(3));
+        assert_eq!(round_function(vec![Object::Float(-2.5)]), Object::Integer(-3));
+        assert_eq!(truncate_function(vec![Object::Float(-2.7)]), Object::Integer(-2));
+        assert_eq!(floor_function(vec![Object::Integer(7)]), Object::Integer(7));
+        assert_eq!(round_function(vec![Object::Integer(-4)]), Object::Integer(-4));
+        assert!(ceil_function(vec![Object::String("x".to_string())]).is_error());
+    }
+
+    #[test]
+    fn test_sum_product_average() {
+        let ints = Object::Array(vec![Object::Integer(1), Object::Integer(2), Object::Integer(3), Object::Integer(4)]);
+        let floats = Object::Array(vec![Object::Float(0.5), Object::Float(1.5), Object::Float(4.0)]);
+        let mixed = Object::Array(vec![Object::Integer(2), Object::Float(0.5)]);
+
+        assert_eq!(sum_function(vec![ints.clone()]), Object::Integer(10));
+        assert_eq!(product_function(vec![ints.clone()]), Object::Integer(24));
+        assert_eq!(average_function(vec![ints]), Object::Float(2.5));
+
+        assert_eq!(sum_function(vec![floats.clone()]), Object::Float(6.0));
+        assert_eq!(product_function(vec![floats.clone()]), Object::Float(3.0));
+        assert_eq!(average_function(vec![floats]), Object::Float(2.0));
+
+        assert_eq!(sum_function(vec![mixed.clone()]), Object::Float(2.5));
+        assert_eq!(product_function(vec![mixed]), Object::Float(1.0));
+
+        let empty = Object::Array(vec![]);
+        assert_eq!(sum_function(vec![empty.clone()]), Object::Integer(0));
+        assert_eq!(product_function(vec![empty.clone()]), Object::Integer(1));
+        assert_eq!(average_function(vec![empty]), Object::Error("average() of an empty array".to_string()));
+    }
+
+    #[test]
+    fn test_sum_product_average_errors() {
+        let strings = Object::Array(vec![Object::Integer(1), Object::String("2".to_string())]);
+        assert_eq!(sum_function(vec![strings.clone()]), Object::Error("sum() requires numbers, got string".to_string()));
+        assert!(average_function(vec![strings]).is_error());
+        assert!(product_function(vec![Object::Integer(3)]).is_error());
+        assert!(sum_function(vec![]).is_error());
+
+        let huge = Object::Array(vec![Object::Integer(i64::MAX), Object::Integer(2)]);
+        match product_function(vec![huge]) {
+            Object::Error(message) => assert!(message.starts_with("product(): integer overflow"), "{}", message),
+            other => panic!("expected an overflow error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_min_max() {
+        let ints = |values: &[i64]| values.iter().map(|&n| Object::Integer(n)).collect::<Vec<_>>();
+
+        assert_eq!(max_function(ints(&[1, 5, 3])), Object::Integer(5));
+        assert_eq!(min_function(ints(&[4, -2, 9, -2])), Object::Integer(-2));
+        assert_eq!(max_function(ints(&[7])), Object::Integer(7));
+        assert_eq!(min_function(vec![Object::Array(ints(&[3, 1, 2]))]), Object::Integer(1));
+        assert_eq!(max_function(vec![Object::Array(ints(&[3, 1, 2]))]), Object::Integer(3));
+
+        // Integers and floats compare by value and keep their own type
+        assert_eq!(max_function(vec![Object::Integer(2), Object::Float(2.5)]), Object::Float(2.5));
+        assert_eq!(min_function(vec![Object::Float(2.5), Object::Integer(2)]), Object::Integer(2));
+        assert_eq!(max_function(vec![Object::Integer(3), Object::Float(3.0)]), Object::Integer(3));
+
+        assert!(max_function(vec![Object::Array(vec![])]).is_error());
+        assert!(min_function(vec![]).is_error());
+        assert!(max_function(vec![Object::Integer(1), Object::String("2".to_string())]).is_error());
+        assert!(min_function(vec![Object::Array(vec![Object::Integer(1), Object::Null])]).is_error());
+        assert!(max_function(vec![Object::Float(f64::NAN), Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_round_to() {
+        assert_eq!(round_to_function(vec![Object::Float(3.14159), Object::Integer(2)]), Object::Float(3.14));
+        assert_eq!(round_to_function(vec![Object::Float(-2.5), Object::Integer(0)]), Object::Float(-3.0));
+        assert_eq!(round_to_function(vec![Object::Float(2.5), Object::Integer(0)]), Object::Float(3.0));
+        assert_eq!(round_to_function(vec![Object::Float(0.125), Object::Integer(2)]), Object::Float(0.13));
+        assert_eq!(round_to_function(vec![Object::Float(0.125), Object::Integer(5)]), Object::Float(0.125));
+        assert_eq!(round_to_function(vec![Object::Float(1e300), Object::Integer(2)]), Object::Float(1e300));
+        assert_eq!(round_to_function(vec![Object::Integer(7), Object::Integer(2)]), Object::Integer(7));
+        assert!(round_to_function(vec![Object::Float(1.5), Object::Integer(-1)]).is_error());
+        assert!(round_to_function(vec![Object::Float(1.5), Object::Float(2.0)]).is_error());
+        assert!(round_to_function(vec![Object::String("1.5".to_string()), Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd_function(vec![Object::Integer(12), Object::Integer(18)]), Object::Integer(6));
+        assert_eq!(gcd_function(vec![Object::Integer(-12), Object::Integer(18)]), Object::Integer(6));
+        assert_eq!(lcm_function(vec![Object::Integer(4), Object::Integer(6)]), Object::Integer(12));
+        assert_eq!(lcm_function(vec![Object::Integer(0), Object::Integer(6)]), Object::Integer(0));
+        assert!(lcm_function(vec![Object::Integer(i64::MAX), Object::Integer(i64::MAX - 1)]).is_error());
+    }
+
+    #[test]
+    fn test_seeded_random_is_deterministic() {
+        let sample = || {
+            seed_function(vec![Object::Integer(42)]);
+            (0..5)
+                .map(|_| random_range_function(vec![Object::Integer(0), Object::Integer(1000)]))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sample(), sample());
+
+        seed_function(vec![Object::Integer(7)]);
+        let first = random_function(vec![]);
+        seed_function(vec![Object::Integer(7)]);
+        assert_eq!(random_function(vec![]), first);
+    }
+
+    #[test]
+    fn test_random_bounds() {
+        seed_function(vec![Object::Integer(1)]);
+        for _ in 0..1000 {
+            match random_range_function(vec![Object::Integer(-3), Object::Integer(4)]) {
+                Object::Integer(n) => assert!((-3..4).contains(&n)),
+                other => panic!("expected integer, got {:?}", other),
+            }
+            match random_function(vec![]) {
+                Object::Float(x) => assert!((0.0..1.0).contains(&x)),
+                other => panic!("expected float, got {:?}", other),
+            }
+        }
+        assert!(random_range_function(vec![Object::Integer(5), Object::Integer(5)]).is_error());
+        assert!(matches!(
+            random_range_function(vec![Object::Integer(i64::MIN), Object::Integer(i64::MAX)]),
+            Object::Integer(_)
+        ));
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial_function(vec![Object::Integer(5)]), Object::Integer(120));
+        assert_eq!(factorial_function(vec![Object::Integer(0)]), Object::Integer(1));
+        assert!(factorial_function(vec![Object::Integer(-1)]).is_error());
+        assert!(factorial_function(vec![Object::Integer(21)]).is_error());
+    }
+}
@@ -8,59 +8,128 @@ pub mod file;
 pub mod system;
 pub mod math;
 pub mod string;
+pub mod collections;
+pub mod json;
+pub mod regex;
+pub mod types;
 
 use crate::environment::Environment;
 
-/// Load a standard library module into the environment
-pub fn load_stdlib_module(env: &mut Environment, module_name: &str) -> Result<(), String> {
+/// Function registering a module's builtins in an environment
+type ModuleLoader = fn(&mut Environment);
+
+/// Label and loader of a standard library module, looked up by any of its names
+fn find_module(module_name: &str) -> Option<(&'static str, ModuleLoader)> {
     match module_name {
         // Time module variants
-        "time" | "shomoy" | "somoy" => {
-            time::load_time_functions(env);
-            println!("Time module loaded successfully");
-            Ok(())
-        }
-        
-        // File module variants  
-        "file" | "faile" => {
-            file::load_file_functions(env);
-            println!("File module loaded successfully");
-            Ok(())
-        }
-        
+        "time" | "shomoy" | "somoy" => Some(("Time", time::load_time_functions)),
+
+        // File module variants
+        "file" | "faile" => Some(("File", file::load_file_functions)),
+
         // System module variants
-        "system" | "sistam" => {
-            system::load_system_functions(env);
-            println!("System module loaded successfully");
-            Ok(())
-        }
-        
+        "system" | "sistam" => Some(("System", system::load_system_functions)),
+
         // Math module variants
-        "math" | "gonit" => {
-            math::load_math_functions(env);
-            println!("Math module loaded successfully");
-            Ok(())
-        }
-        
+        "math" | "gonit" => Some(("Math", math::load_math_functions)),
+
         // String module variants
-        "string" | "shobdo" => {
-            string::load_string_functions(env);
-            println!("String module loaded successfully");
-            Ok(())
-        }
-        
-        _ => Err(format!("Unknown module: '{}'. Available modules: time, file, system, math, string", module_name))
+        "string" | "shobdo" => Some(("String", string::load_string_functions)),
+
+        // Collections (array) module variants
+        "collections" | "songroho" => Some(("Collections", collections::load_collection_functions)),
+
+        // JSON module
+        "json" => Some(("JSON", json::load_json_functions)),
+
+        // Regular expression module
+        "regex" => Some(("Regex", regex::load_regex_functions)),
+
+        // Type predicate module variants
+        "types" | "dhoron" => Some(("Types", types::load_type_functions)),
+
+        _ => None,
     }
 }
 
+fn unknown_module_error(module_name: &str) -> String {
+    format!(
+        "Unknown module: '{}'. Available modules: {}",
+        module_name,
+        get_available_modules().join(", ")
+    )
+}
+
+/// Load a standard library module into the environment
+pub fn load_stdlib_module(env: &mut Environment, module_name: &str) -> Result<(), String> {
+    let (label, load) = find_module(module_name).ok_or_else(|| unknown_module_error(module_name))?;
+    load(env);
+    println!("{} module loaded successfully", label);
+    Ok(())
+}
+
+/// Sorted names of the functions a module provides, found by loading it into a
+/// throwaway environment and keeping the names it added or replaced
+pub fn module_functions(module_name: &str) -> Result<Vec<String>, String> {
+    let (_, load) = find_module(module_name).ok_or_else(|| unknown_module_error(module_name))?;
+    let base = Environment::new();
+    let mut env = Environment::new();
+    load(&mut env);
+
+    let mut names: Vec<String> = env
+        .names()
+        .into_iter()
+        .filter(|name| base.get(name) != env.get(name))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
 /// Get list of available modules
 pub fn get_available_modules() -> Vec<&'static str> {
-    vec!["time", "file", "system", "math", "string"]
+    vec!["time", "file", "system", "math", "string", "collections", "json", "regex", "types"]
 }
 
-/// Load commonly used modules automatically  
+/// Modules loaded by load_default_modules (the prelude)
+pub const DEFAULT_MODULES: [&str; 2] = ["time", "math"];
+
+/// Load commonly used modules automatically, without the per-module "loaded" messages
 pub fn load_default_modules(env: &mut Environment) {
-    // Optionally auto-load commonly used modules
-    let _ = load_stdlib_module(env, "time");
-    let _ = load_stdlib_module(env, "math");
-}
\ No newline at end of file
+    for module_name in DEFAULT_MODULES {
+        if let Some((_, load)) = find_module(module_name) {
+            load(env);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_functions() {
+        let math = module_functions("math").unwrap();
+        for name in ["sqrt", "pow", "abs", "floor"] {
+            assert!(math.contains(&name.to_string()), "math is missing {}", name);
+        }
+        assert!(!math.contains(&"dekhao".to_string()));
+        assert_eq!(module_functions("gonit").unwrap(), math);
+
+        for module in get_available_modules() {
+            assert!(!module_functions(module).unwrap().is_empty(), "{} lists no functions", module);
+        }
+        assert!(module_functions("nei").unwrap_err().starts_with("Unknown module: 'nei'"));
+    }
+
+    #[test]
+    fn test_load_default_modules() {
+        let mut env = Environment::new();
+        assert!(!env.contains("sqrt"));
+        load_default_modules(&mut env);
+        for module in DEFAULT_MODULES {
+            for name in module_functions(module).unwrap() {
+                assert!(env.contains(&name), "prelude is missing {}", name);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// compiler/src/stdlib/regex.rs
+
+use crate::environment::Environment;
+use crate::object::Object;
+use regex::Regex;
+
+/// Load all regular expression functions into environment
+pub fn load_regex_functions(env: &mut Environment) {
+    env.add_builtin("regex_match".to_string(), Object::BuiltinNative(regex_match));
+    env.add_builtin("regex_find_all".to_string(), Object::BuiltinNative(regex_find_all));
+    env.add_builtin("regex_replace".to_string(), Object::BuiltinNative(regex_replace));
+}
+
+/// Check whether the pattern matches anywhere in the text
+fn regex_match(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("regex_match() takes exactly 2 arguments".to_string());
+    }
+
+    match (compile("regex_match", &args[0]), &args[1]) {
+        (Ok(re), Object::String(text)) => Object::Boolean(re.is_match(text)),
+        (Err(error), _) => error,
+        (Ok(_), other) => Object::Error(format!("regex_match() requires a string text, got {}", other.type_name())),
+    }
+}
+
+/// Collect every non-overlapping match of the pattern, left to right
+fn regex_find_all(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("regex_find_all() takes exactly 2 arguments".to_string());
+    }
+
+    match (compile("regex_find_all", &args[0]), &args[1]) {
+        (Ok(re), Object::String(text)) => Object::Array(
+            re.find_iter(text).map(|m| Object::String(m.as_str().to_string())).collect(),
+        ),
+        (Err(error), _) => error,
+        (Ok(_), other) => Object::Error(format!("regex_find_all() requires a string text, got {}", other.type_name())),
+    }
+}
+
+/// Replace every match of the pattern; the replacement may refer to groups as $1 or ${name}
+fn regex_replace(args: Vec<Object>) -> Object {
+    if args.len() != 3 {
+        return Object::Error("regex_replace() takes exactly 3 arguments".to_string());
+    }
+
+    match (compile("regex_replace", &args[0]), &args[1], &args[2]) {
+        (Ok(re), Object::String(text), Object::String(replacement)) => {
+            Object::String(re.replace_all(text, replacement.as_str()).into_owned())
+        }
+        (Err(error), _, _) => error,
+        _ => Object::Error("regex_replace() requires string text and replacement".to_string()),
+    }
+}
+
+fn compile(function: &str, pattern: &Object) -> Result<Regex, Object> {
+    match pattern {
+        Object::String(pattern) => Regex::new(pattern)
+            .map_err(|e| Object::Error(format!("{}(): invalid pattern {:?}: {}", function, pattern, e))),
+        other => Err(Object::Error(format!("{}() requires a string pattern, got {}", function, other.type_name()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Object {
+        Object::String(text.to_string())
+    }
+
+    #[test]
+    fn test_regex_match() {
+        assert_eq!(regex_match(vec![s(r"\d+"), s("room 42")]), Object::Boolean(true));
+        assert_eq!(regex_match(vec![s(r"^\d+$"), s("room 42")]), Object::Boolean(false));
+    }
+
+    #[test]
+    fn test_regex_find_all() {
+        assert_eq!(
+            regex_find_all(vec![s(r"\d+"), s("1 apple, 22 pears, 333 plums")]),
+            Object::Array(vec![s("1"), s("22"), s("333")])
+        );
+        assert_eq!(regex_find_all(vec![s(r"\d+"), s("none")]), Object::Array(vec![]));
+    }
+
+    #[test]
+    fn test_regex_replace() {
+        assert_eq!(regex_replace(vec![s(r"\d"), s("a1b22"), s("#")]), s("a#b##"));
+        assert_eq!(
+            regex_replace(vec![s(r"(\w+)@(\w+)"), s("ali@home"), s("$2:$1")]),
+            s("home:ali")
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_an_error() {
+        match regex_match(vec![s("(unclosed"), s("text")]) {
+            Object::Error(message) => assert!(message.starts_with("regex_match(): invalid pattern"), "{}", message),
+            other => panic!("expected an error, got {:?}", other),
+        }
+        assert!(regex_find_all(vec![s("["), s("text")]).is_error());
+        assert!(regex_replace(vec![s("*"), s("text"), s("")]).is_error());
+        assert!(regex_match(vec![Object::Integer(1), s("text")]).is_error());
+    }
+}
@@ -1,6 +1,7 @@
 // compiler/src/stdlib/string.rs
 
 use crate::environment::Environment;
+use crate::evaluator::repeat_string;
 use crate::object::Object;
 
 /// Load all string manipulation functions into environment
@@ -8,6 +9,8 @@ pub fn load_string_functions(env: &mut Environment) {
     env.add_builtin("str_length".to_string(), Object::BuiltinNative(string_length));
     env.add_builtin("str_len".to_string(), Object::BuiltinNative(string_length));
     env.add_builtin("length".to_string(), Object::BuiltinNative(string_length));
+    env.add_builtin("len".to_string(), Object::BuiltinNative(string_length));
+    env.add_builtin("byte_length".to_string(), Object::BuiltinNative(string_byte_length));
     
     env.add_builtin("str_upper".to_string(), Object::BuiltinNative(string_upper));
     env.add_builtin("str_lower".to_string(), Object::BuiltinNative(string_lower));
@@ -15,32 +18,68 @@ pub fn load_string_functions(env: &mut Environment) {
     env.add_builtin("lower".to_string(), Object::BuiltinNative(string_lower));
     
     env.add_builtin("str_contains".to_string(), Object::BuiltinNative(string_contains));
-    env.add_builtin("contains".to_string(), Object::BuiltinNative(string_contains));
+    env.add_builtin("contains".to_string(), Object::BuiltinNative(contains));
     
     env.add_builtin("str_split".to_string(), Object::BuiltinNative(string_split));
     env.add_builtin("split".to_string(), Object::BuiltinNative(string_split));
     
+    env.add_builtin("str_join".to_string(), Object::BuiltinNative(string_join));
+    env.add_builtin("join".to_string(), Object::BuiltinNative(string_join));
+    
     env.add_builtin("str_trim".to_string(), Object::BuiltinNative(string_trim));
     env.add_builtin("trim".to_string(), Object::BuiltinNative(string_trim));
     
     env.add_builtin("str_replace".to_string(), Object::BuiltinNative(string_replace));
     env.add_builtin("replace".to_string(), Object::BuiltinNative(string_replace));
     
+    env.add_builtin("substring".to_string(), Object::BuiltinNative(string_substring));
+    env.add_builtin("index_of".to_string(), Object::BuiltinNative(index_of));
+    env.add_builtin("char_at".to_string(), Object::BuiltinNative(string_char_at));
+    
+    env.add_builtin("str_reverse".to_string(), Object::BuiltinNative(string_reverse));
+    env.add_builtin("repeat".to_string(), Object::BuiltinNative(string_repeat));
+    env.add_builtin("starts_with".to_string(), Object::BuiltinNative(string_starts_with));
+    env.add_builtin("ends_with".to_string(), Object::BuiltinNative(string_ends_with));
+    
+    env.add_builtin("format".to_string(), Object::BuiltinNative(string_format));
+    
+    env.add_builtin("to_int".to_string(), Object::BuiltinNative(to_int));
+    env.add_builtin("to_float".to_string(), Object::BuiltinNative(to_float));
+    env.add_builtin("to_string".to_string(), Object::BuiltinNative(to_string));
+    env.add_builtin("format_float".to_string(), Object::BuiltinNative(format_float));
+    
     // Bangla variants
     env.add_builtin("lambai".to_string(), Object::BuiltinNative(string_length));  // length in Bangla
+    env.add_builtin("byte_lambai".to_string(), Object::BuiltinNative(string_byte_length));
     env.add_builtin("boro".to_string(), Object::BuiltinNative(string_upper));     // upper in Bangla
     env.add_builtin("choto".to_string(), Object::BuiltinNative(string_lower));    // lower in Bangla
+    env.add_builtin("ulta".to_string(), Object::BuiltinNative(string_reverse));   // reverse in Bangla
+    env.add_builtin("shonkhya".to_string(), Object::BuiltinNative(to_int));        // number in Bangla
+    env.add_builtin("doshomik".to_string(), Object::BuiltinNative(to_float));      // decimal in Bangla
 }
 
-/// Get string length
+/// Get string length in Unicode characters, or the number of elements in an array
 fn string_length(args: Vec<Object>) -> Object {
     if args.len() != 1 {
-        return Object::Error("str_length() takes exactly one argument".to_string());
+        return Object::Error("length() takes exactly one argument".to_string());
+    }
+    
+    match &args[0] {
+        Object::String(s) => Object::Integer(s.chars().count() as i64),
+        Object::Array(elements) => Object::Integer(elements.len() as i64),
+        _ => Object::Error("length() requires a string or array argument".to_string()),
+    }
+}
+
+/// Get the UTF-8 byte length of a string
+fn string_byte_length(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("byte_length() takes exactly one argument".to_string());
     }
     
     match &args[0] {
         Object::String(s) => Object::Integer(s.len() as i64),
-        _ => Object::Error("str_length() requires a string argument".to_string()),
+        _ => Object::Error("byte_length() requires a string argument".to_string()),
     }
 }
 
@@ -82,6 +121,14 @@ fn string_contains(args: Vec<Object>) -> Object {
     }
 }
 
+/// contains(text, part) for strings, contains(arr, x) for arrays
+pub(crate) fn contains(args: Vec<Object>) -> Object {
+    match args.first() {
+        Some(Object::Array(_)) => super::collections::array_contains(args),
+        _ => string_contains(args),
+    }
+}
+
 /// Split string by delimiter
 fn string_split(args: Vec<Object>) -> Object {
     if args.len() != 2 {
@@ -100,6 +147,21 @@ fn string_split(args: Vec<Object>) -> Object {
     }
 }
 
+/// Join array elements with a separator (inverse of split); non-strings are stringified
+fn string_join(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("str_join() takes exactly two arguments".to_string());
+    }
+    
+    match (&args[0], &args[1]) {
+        (Object::Array(elements), Object::String(separator)) => {
+            let parts: Vec<String> = elements.iter().map(|e| e.to_string()).collect();
+            Object::String(parts.join(separator))
+        }
+        _ => Object::Error("str_join() requires an array and a string separator".to_string()),
+    }
+}
+
 /// Trim whitespace from string
 fn string_trim(args: Vec<Object>) -> Object {
     if args.len() != 1 {
@@ -124,4 +186,407 @@ fn string_replace(args: Vec<Object>) -> Object {
         }
         _ => Object::Error("str_replace() requires three string arguments".to_string()),
     }
-}
\ No newline at end of file
+}
+
+// Positions in the functions below count Unicode characters (not bytes),
+// so "বাংলা" has indices 0..5 the same way "hello" does.
+
+/// Get part of a string: substring(s, start, end) covers chars [start, end).
+/// An end past the string is clamped to its length; negative or reversed ranges are errors.
+fn string_substring(args: Vec<Object>) -> Object {
+    if args.len() != 3 {
+        return Object::Error("substring() takes exactly three arguments (string, start, end)".to_string());
+    }
+    
+    match (&args[0], &args[1], &args[2]) {
+        (Object::String(text), Object::Integer(start), Object::Integer(end)) => {
+            if *start < 0 || *end < 0 {
+                return Object::Error(format!("substring() indices must not be negative, got {} and {}", start, end));
+            }
+            if start > end {
+                return Object::Error(format!("substring() start {} is after end {}", start, end));
+            }
+            let length = text.chars().count();
+            let start = (*start as usize).min(length);
+            let end = (*end as usize).min(length);
+            Object::String(text.chars().skip(start).take(end - start).collect())
+        }
+        _ => Object::Error("substring() requires a string and two integers".to_string()),
+    }
+}
+
+/// Find the char index of the first occurrence of needle, or -1 if it isn't there
+fn string_index_of(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("index_of() takes exactly two arguments".to_string());
+    }
+    
+    match (&args[0], &args[1]) {
+        (Object::String(haystack), Object::String(needle)) => match haystack.find(needle.as_str()) {
+            Some(byte_index) => Object::Integer(haystack[..byte_index].chars().count() as i64),
+            None => Object::Integer(-1),
+        },
+        _ => Object::Error("index_of() requires two string arguments".to_string()),
+    }
+}
+
+/// index_of(text, part) for strings, index_of(arr, x) for arrays
+pub(crate) fn index_of(args: Vec<Object>) -> Object {
+    match args.first() {
+        Some(Object::Array(_)) => super::collections::array_index_of(args),
+        _ => string_index_of(args),
+    }
+}
+
+/// Get the character at a char index as a one-character string
+fn string_char_at(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("char_at() takes exactly two arguments".to_string());
+    }
+    
+    match (&args[0], &args[1]) {
+        (Object::String(text), Object::Integer(index)) => {
+            let found = usize::try_from(*index).ok().and_then(|i| text.chars().nth(i));
+            match found {
+                Some(c) => Object::Char(c),
+                None => Object::Error(format!(
+                    "char_at() index {} out of bounds (length {})",
+                    index,
+                    text.chars().count()
+                )),
+            }
+        }
+        _ => Object::Error("char_at() requires a string and an integer".to_string()),
+    }
+}
+
+/// Reverse a string by Unicode scalar values
+fn string_reverse(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("str_reverse() takes exactly one argument".to_string());
+    }
+    
+    match &args[0] {
+        Object::String(s) => Object::String(s.chars().rev().collect()),
+        _ => Object::Error("str_reverse() requires a string argument".to_string()),
+    }
+}
+
+/// Repeat a string n times
+fn string_repeat(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("repeat() takes exactly two arguments".to_string());
+    }
+    
+    match (&args[0], &args[1]) {
+        (Object::String(_), Object::Integer(n)) if *n < 0 => {
+            Object::Error(format!("repeat() count must not be negative, got {}", n))
+        }
+        (Object::String(s), Object::Integer(n)) => repeat_string(s, *n as usize),
+        _ => Object::Error("repeat() requires a string and an integer".to_string()),
+    }
+}
+
+/// Check if string starts with prefix
+fn string_starts_with(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("starts_with() takes exactly two arguments".to_string());
+    }
+    
+    match (&args[0], &args[1]) {
+        (Object::String(text), Object::String(prefix)) => Object::Boolean(text.starts_with(prefix.as_str())),
+        _ => Object::Error("starts_with() requires two string arguments".to_string()),
+    }
+}
+
+/// Check if string ends with suffix
+fn string_ends_with(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("ends_with() takes exactly two arguments".to_string());
+    }
+    
+    match (&args[0], &args[1]) {
+        (Object::String(text), Object::String(suffix)) => Object::Boolean(text.ends_with(suffix.as_str())),
+        _ => Object::Error("ends_with() requires two string arguments".to_string()),
+    }
+}
+
+/// Build a string from a template: format("{0} + {1}", a, b).
+/// `{n}` is replaced by the n-th argument (same placeholders as the error message templates),
+/// `{{` and `}}` produce literal braces, and a placeholder without a matching argument is an error.
+fn string_format(args: Vec<Object>) -> Object {
+    let template = match args.first() {
+        Some(Object::String(t)) => t,
+        Some(_) => return Object::Error("format() requires a string template as first argument".to_string()),
+        None => return Object::Error("format() takes at least one argument".to_string()),
+    };
+    let values = &args[1..];
+    
+    let mut result = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                // Collect the placeholder up to the closing brace
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for next in chars.by_ref() {
+                    if next == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(next);
+                }
+                if !closed {
+                    return Object::Error("format() template has an unclosed '{'".to_string());
+                }
+                match placeholder.trim().parse::<usize>() {
+                    Ok(i) if i < values.len() => result.push_str(&values[i].to_string()),
+                    Ok(i) => {
+                        return Object::Error(format!(
+                            "format() placeholder {{{}}} has no argument ({} given)",
+                            i,
+                            values.len()
+                        ))
+                    }
+                    Err(_) => {
+                        return Object::Error(format!("format() invalid placeholder {{{}}}", placeholder))
+                    }
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+    
+    Object::String(result)
+}
+
+/// Parse a string (surrounding whitespace ignored) into an integer.
+/// Integers pass through and floats are truncated toward zero.
+fn to_int(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("to_int() takes exactly one argument".to_string());
+    }
+    
+    match &args[0] {
+        Object::String(s) => match s.trim().parse::<i64>() {
+            Ok(n) => Object::Integer(n),
+            Err(_) => Object::Error(format!("to_int() could not parse '{}' as an integer", s)),
+        },
+        Object::Integer(n) => Object::Integer(*n),
+        Object::Float(x) if x.is_finite() && x.abs() < i64::MAX as f64 => Object::Integer(x.trunc() as i64),
+        Object::Float(x) => Object::Error(format!("to_int() cannot convert {} to an integer", x)),
+        other => Object::Error(format!("to_int() cannot convert a {}", other.type_name())),
+    }
+}
+
+/// Parse a string (surrounding whitespace ignored) into a float
+fn to_float(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("to_float() takes exactly one argument".to_string());
+    }
+    
+    match &args[0] {
+        Object::String(s) => match s.trim().parse::<f64>() {
+            Ok(x) => Object::Float(x),
+            Err(_) => Object::Error(format!("to_float() could not parse '{}' as a number", s)),
+        },
+        Object::Integer(n) => Object::Float(*n as f64),
+        Object::Float(x) => Object::Float(*x),
+        other => Object::Error(format!("to_float() cannot convert a {}", other.type_name())),
+    }
+}
+
+/// Stringify any value the same way dekhao prints it
+fn to_string(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("to_string() takes exactly one argument".to_string());
+    }
+    
+    Object::String(args[0].to_string())
+}
+
+/// Write a number with exactly the given decimal places: format_float(2.5, 3) -> "2.500".
+/// Halves round away from zero, as in round_to: format_float(2.5, 0) -> "3"
+fn format_float(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("format_float() takes exactly two arguments".to_string());
+    }
+    let digits = match super::math::decimal_places("format_float", &args[1]) {
+        Ok(digits) => digits,
+        Err(error) => return error,
+    };
+    match &args[0] {
+        Object::Integer(n) => Object::String(format!("{:.*}", digits, *n as f64)),
+        Object::Float(x) => Object::String(format!("{:.*}", digits, super::math::round_half_away(*x, digits))),
+        other => Object::Error(format!("format_float() requires a number, got {}", other.type_name())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Object {
+        Object::String(text.to_string())
+    }
+
+    #[test]
+    fn test_length_counts_chars() {
+        assert_eq!(string_length(vec![s("hello")]), Object::Integer(5));
+        assert_eq!(string_length(vec![s("বাংলা")]), Object::Integer(5));
+        assert_eq!(string_length(vec![s("")]), Object::Integer(0));
+        assert_eq!(string_byte_length(vec![s("hello")]), Object::Integer(5));
+        assert_eq!(string_byte_length(vec![s("বাংলা")]), Object::Integer(15));
+    }
+
+    #[test]
+    fn test_length_counts_array_elements() {
+        let array = Object::Array(vec![Object::Integer(1), s("দুই"), Object::Array(vec![])]);
+        assert_eq!(string_length(vec![array]), Object::Integer(3));
+        assert_eq!(string_length(vec![Object::Array(vec![])]), Object::Integer(0));
+        assert_eq!(string_length(vec![s("abc")]), Object::Integer(3));
+        assert!(string_length(vec![Object::Integer(5)]).is_error());
+    }
+
+    #[test]
+    fn test_substring() {
+        assert_eq!(string_substring(vec![s("hello"), Object::Integer(1), Object::Integer(4)]), s("ell"));
+        assert_eq!(string_substring(vec![s("hello"), Object::Integer(2), Object::Integer(99)]), s("llo"));
+        assert_eq!(string_substring(vec![s("বাংলা"), Object::Integer(0), Object::Integer(2)]), s("বা"));
+        assert!(string_substring(vec![s("hello"), Object::Integer(3), Object::Integer(1)]).is_error());
+        assert!(string_substring(vec![s("hello"), Object::Integer(-1), Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    fn test_index_of() {
+        assert_eq!(string_index_of(vec![s("hello"), s("ll")]), Object::Integer(2));
+        assert_eq!(string_index_of(vec![s("hello"), s("z")]), Object::Integer(-1));
+        assert_eq!(string_index_of(vec![s("আমি বাংলা"), s("বাংলা")]), Object::Integer(4));
+    }
+
+    #[test]
+    fn test_contains_and_index_of_dispatch_on_arrays() {
+        let words = Object::Array(vec![s("ek"), s("dui")]);
+        assert_eq!(contains(vec![s("hello"), s("ell")]), Object::Boolean(true));
+        assert_eq!(contains(vec![words.clone(), s("dui")]), Object::Boolean(true));
+        assert_eq!(contains(vec![words.clone(), s("d")]), Object::Boolean(false));
+        assert_eq!(index_of(vec![s("hello"), s("lo")]), Object::Integer(3));
+        assert_eq!(index_of(vec![words.clone(), s("dui")]), Object::Integer(1));
+        assert!(contains(vec![s("hello"), words]).is_error());
+
+        // Whichever of string and collections is loaded last, contains handles both
+        for modules in [["string", "collections"], ["collections", "string"]] {
+            let mut env = Environment::new();
+            for module in modules {
+                crate::stdlib::load_stdlib_module(&mut env, module).unwrap();
+            }
+            assert_eq!(env.get("contains"), Some(Object::BuiltinNative(contains)));
+        }
+    }
+
+    #[test]
+    fn test_char_at() {
+        assert_eq!(string_char_at(vec![s("hello"), Object::Integer(1)]), Object::Char('e'));
+        assert_eq!(string_char_at(vec![s("বাংলা"), Object::Integer(1)]), Object::Char('া'));
+        assert_eq!(
+            crate::object::builtin_type_of(vec![string_char_at(vec![s("hello"), Object::Integer(0)])]),
+            s("char")
+        );
+        assert!(string_char_at(vec![s("hello"), Object::Integer(5)]).is_error());
+        assert!(string_char_at(vec![s("hello"), Object::Integer(-1)]).is_error());
+    }
+
+    #[test]
+    fn test_reverse_and_repeat() {
+        assert_eq!(string_reverse(vec![s("abc")]), s("cba"));
+        assert_eq!(string_reverse(vec![s("আমি")]), s("িমআ"));
+        assert_eq!(string_repeat(vec![s("ab"), Object::Integer(3)]), s("ababab"));
+        assert_eq!(string_repeat(vec![s("ab"), Object::Integer(0)]), s(""));
+        assert!(string_repeat(vec![s("ab"), Object::Integer(-1)]).is_error());
+        assert!(string_repeat(vec![s("ab"), Object::Integer(i64::MAX)]).is_error());
+    }
+
+    #[test]
+    fn test_starts_ends_with() {
+        assert_eq!(string_starts_with(vec![s("বাংলা"), s("বা")]), Object::Boolean(true));
+        assert_eq!(string_starts_with(vec![s("hello"), s("")]), Object::Boolean(true));
+        assert_eq!(string_starts_with(vec![s(""), s("a")]), Object::Boolean(false));
+        assert_eq!(string_ends_with(vec![s("hello"), s("llo")]), Object::Boolean(true));
+        assert_eq!(string_ends_with(vec![s(""), s("")]), Object::Boolean(true));
+        assert_eq!(string_ends_with(vec![s("hello"), s("he")]), Object::Boolean(false));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            string_format(vec![s("{0} + {1} = {2}"), Object::Integer(1), Object::Integer(2), Object::Integer(3)]),
+            s("1 + 2 = 3")
+        );
+        assert_eq!(string_format(vec![s("{1}{0}{1}"), s("a"), s("b")]), s("bab"));
+        assert_eq!(string_format(vec![s("নাম: {0}"), s("রহিম")]), s("নাম: রহিম"));
+        assert_eq!(string_format(vec![s("{{0}} is {0}"), Object::Boolean(true)]), s("{0} is Ha"));
+        assert!(string_format(vec![s("{1}"), s("a")]).is_error());
+        assert!(string_format(vec![s("{x}")]).is_error());
+        assert!(string_format(vec![s("{0")]).is_error());
+    }
+
+    #[test]
+    fn test_number_parsing() {
+        assert_eq!(to_int(vec![s("42")]), Object::Integer(42));
+        assert_eq!(to_int(vec![s("  -7\n")]), Object::Integer(-7));
+        assert_eq!(to_int(vec![Object::Float(3.9)]), Object::Integer(3));
+        assert!(to_int(vec![s("4x")]).is_error());
+        assert!(to_int(vec![s("3.5")]).is_error());
+        assert!(to_int(vec![s("")]).is_error());
+
+        assert_eq!(to_float(vec![s(" 2.5 ")]), Object::Float(2.5));
+        assert_eq!(to_float(vec![s("1e3")]), Object::Float(1000.0));
+        assert_eq!(to_float(vec![Object::Integer(2)]), Object::Float(2.0));
+        assert!(to_float(vec![s("abc")]).is_error());
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(to_string(vec![Object::Integer(5)]), s("5"));
+        assert_eq!(to_string(vec![Object::Float(2.0)]), s("2.0"));
+        assert_eq!(to_string(vec![Object::Boolean(false)]), s("Na"));
+        assert_eq!(to_string(vec![Object::Array(vec![Object::Integer(1), s("a")])]), s("[1, a]"));
+        // Floats print in plain decimal, never with an exponent
+        assert_eq!(to_string(vec![Object::Float(1e20)]), s("100000000000000000000.0"));
+        assert_eq!(to_string(vec![Object::Float(0.000125)]), s("0.000125"));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_format_float() {
+        assert_eq!(format_float(vec![Object::Float(2.5), Object::Integer(3)]), s("2.500"));
+        assert_eq!(format_float(vec![Object::Float(3.14159), Object::Integer(2)]), s("3.14"));
+        assert_eq!(format_float(vec![Object::Float(0.75), Object::Integer(0)]), s("1"));
+        assert_eq!(format_float(vec![Object::Integer(7), Object::Integer(1)]), s("7.0"));
+        // Halves round away from zero, the same digits round_to gives
+        assert_eq!(format_float(vec![Object::Float(2.5), Object::Integer(0)]), s("3"));
+        assert_eq!(format_float(vec![Object::Float(-2.5), Object::Integer(0)]), s("-3"));
+        assert_eq!(format_float(vec![Object::Float(0.125), Object::Integer(2)]), s("0.13"));
+        assert_eq!(format_float(vec![Object::Float(1.5), Object::Integer(100)]).to_string().len(), 102);
+        assert!(format_float(vec![Object::Float(2.5), Object::Integer(-2)]).is_error());
+        assert!(format_float(vec![s("2.5"), Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    fn test_join() {
+        let numbers = Object::Array(vec![Object::Integer(1), Object::Integer(2), Object::Integer(3)]);
+        assert_eq!(string_join(vec![numbers, s(", ")]), s("1, 2, 3"));
+        assert_eq!(string_join(vec![Object::Array(vec![]), s(",")]), s(""));
+        assert_eq!(string_join(vec![Object::Array(vec![s("a"), s("b")]), s("")]), s("ab"));
+        assert!(string_join(vec![s("abc"), s(",")]).is_error());
+    }
+}
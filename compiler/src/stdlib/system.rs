@@ -2,6 +2,17 @@
 
 use crate::environment::Environment;
 use crate::object::Object;
+use std::cell::RefCell;
+
+// Arguments given after the script name on the command line
+thread_local! {
+    static SCRIPT_ARGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Record the script's command-line arguments for `args()`
+pub fn set_script_args(args: Vec<String>) {
+    SCRIPT_ARGS.with(|stored| *stored.borrow_mut() = args);
+}
 
 /// Load all system-related functions into environment
 pub fn load_system_functions(env: &mut Environment) {
@@ -13,6 +24,12 @@ pub fn load_system_functions(env: &mut Environment) {
     // Add new system functions
     env.add_builtin("platform".to_string(), Object::BuiltinNative(get_platform));
     env.add_builtin("env_var".to_string(), Object::BuiltinNative(get_env_var));
+    env.add_builtin("set_env_var".to_string(), Object::BuiltinNative(set_env_var));
+    env.add_builtin("args".to_string(), Object::BuiltinNative(script_args));
+    env.add_builtin("run_command".to_string(), Object::BuiltinNative(run_command));
+    env.add_builtin("cwd".to_string(), Object::BuiltinNative(get_cwd));
+    env.add_builtin("hostname".to_string(), Object::BuiltinNative(get_hostname));
+    env.add_builtin("cpu_count".to_string(), Object::BuiltinNative(get_cpu_count));
 }
 
 /// Exit program with code (moved from environment.rs)
@@ -57,6 +74,17 @@ fn get_platform(_args: Vec<Object>) -> Object {
     Object::String(platform.to_string())
 }
 
+/// Get the script's command-line arguments as an array of strings
+pub fn script_args(args: Vec<Object>) -> Object {
+    if !args.is_empty() {
+        return Object::Error("args() takes no arguments".to_string());
+    }
+
+    SCRIPT_ARGS.with(|stored| {
+        Object::Array(stored.borrow().iter().map(|a| Object::String(a.clone())).collect())
+    })
+}
+
 /// Get environment variable
 fn get_env_var(args: Vec<Object>) -> Object {
     if args.len() != 1 {
@@ -72,4 +100,158 @@ fn get_env_var(args: Vec<Object>) -> Object {
         }
         _ => Object::Error("env_var() requires a string argument".to_string()),
     }
-}
\ No newline at end of file
+}
+
+/// Set an environment variable (visible to commands started afterwards)
+fn set_env_var(args: Vec<Object>) -> Object {
+    if args.len() != 2 {
+        return Object::Error("set_env_var() requires exactly two arguments (name, value)".to_string());
+    }
+
+    match (&args[0], &args[1]) {
+        (Object::String(name), value) => {
+            // std::env::set_var panics on these, so reject them up front
+            if name.is_empty() || name.contains('=') || name.contains('\0') {
+                return Object::Error(format!("set_env_var(): invalid variable name '{}'", name));
+            }
+            let value = value.to_string();
+            if value.contains('\0') {
+                return Object::Error("set_env_var(): value must not contain NUL characters".to_string());
+            }
+            std::env::set_var(name, value);
+            Object::Null
+        }
+        _ => Object::Error("set_env_var() requires a string name as first argument".to_string()),
+    }
+}
+
+/// Get the current working directory
+fn get_cwd(_args: Vec<Object>) -> Object {
+    match std::env::current_dir() {
+        Ok(path) => Object::String(path.to_string_lossy().to_string()),
+        Err(e) => Object::Error(format!("Could not read current directory: {}", e)),
+    }
+}
+
+/// Get the machine's host name
+fn get_hostname(_args: Vec<Object>) -> Object {
+    let from_env = std::env::var("COMPUTERNAME").or_else(|_| std::env::var("HOSTNAME"));
+    let hostname = from_env.ok().or_else(|| {
+        ["/proc/sys/kernel/hostname", "/etc/hostname"]
+            .iter()
+            .find_map(|path| std::fs::read_to_string(path).ok())
+    });
+
+    match hostname.map(|name| name.trim().to_string()) {
+        Some(name) if !name.is_empty() => Object::String(name),
+        _ => Object::Error("Could not determine hostname".to_string()),
+    }
+}
+
+/// Get the number of logical CPU cores (1 if the OS can't tell)
+fn get_cpu_count(_args: Vec<Object>) -> Object {
+    let count = std::thread::available_parallelism().map_or(1, |n| n.get());
+    Object::Integer(count as i64)
+}
+
+/// Run an external program: run_command("git", "status").
+/// Returns a hash {stdout, stderr, exit_code}; exit_code is null if the process was killed by a signal.
+///
+/// The program is started directly (no shell), but it still runs with the full
+/// permissions of the interpreter, so only run B+ scripts you trust when they
+/// load the system module.
+fn run_command(args: Vec<Object>) -> Object {
+    let (program, rest) = match args.split_first() {
+        Some((Object::String(program), rest)) => (program, rest),
+        Some(_) => return Object::Error("run_command() requires a string command".to_string()),
+        None => return Object::Error("run_command() requires at least one argument (command)".to_string()),
+    };
+
+    let output = std::process::Command::new(program)
+        .args(rest.iter().map(|arg| arg.to_string()))
+        .output();
+
+    match output {
+        Ok(output) => Object::Hash(vec![
+            (
+                Object::String("stdout".to_string()),
+                Object::String(String::from_utf8_lossy(&output.stdout).to_string()),
+            ),
+            (
+                Object::String("stderr".to_string()),
+                Object::String(String::from_utf8_lossy(&output.stderr).to_string()),
+            ),
+            (
+                Object::String("exit_code".to_string()),
+                output.status.code().map_or(Object::Null, |code| Object::Integer(code as i64)),
+            ),
+        ]),
+        Err(e) => Object::Error(format!("Could not run '{}': {}", program, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(hash: &Object, key: &str) -> Object {
+        match hash {
+            Object::Hash(pairs) => pairs
+                .iter()
+                .find(|(k, _)| k == &Object::String(key.to_string()))
+                .map(|(_, v)| v.clone())
+                .unwrap_or(Object::Null),
+            other => panic!("expected hash, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_run_command_captures_output() {
+        let result = if cfg!(target_os = "windows") {
+            run_command(vec![
+                Object::String("cmd".to_string()),
+                Object::String("/C".to_string()),
+                Object::String("echo hello".to_string()),
+            ])
+        } else {
+            run_command(vec![Object::String("echo".to_string()), Object::String("hello".to_string())])
+        };
+        assert_eq!(field(&result, "stdout").to_string().trim(), "hello");
+        assert_eq!(field(&result, "exit_code"), Object::Integer(0));
+    }
+
+    #[test]
+    fn test_system_introspection() {
+        match get_cwd(vec![]) {
+            Object::String(dir) => assert!(!dir.is_empty()),
+            other => panic!("expected string, got {:?}", other),
+        }
+        match get_cpu_count(vec![]) {
+            Object::Integer(n) => assert!(n >= 1),
+            other => panic!("expected integer, got {:?}", other),
+        }
+        match get_hostname(vec![]) {
+            Object::String(name) => assert!(!name.is_empty()),
+            Object::Error(_) => {}
+            other => panic!("expected string or error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_set_env_var_round_trip() {
+        let name = Object::String("BPLUS_TEST_SET_ENV_VAR".to_string());
+        assert_eq!(
+            set_env_var(vec![name.clone(), Object::String("bhalo".to_string())]),
+            Object::Null
+        );
+        assert_eq!(get_env_var(vec![name]), Object::String("bhalo".to_string()));
+        assert!(set_env_var(vec![Object::String(String::new()), Object::Integer(1)]).is_error());
+        assert!(set_env_var(vec![Object::String("A=B".to_string()), Object::Integer(1)]).is_error());
+    }
+
+    #[test]
+    fn test_run_command_spawn_failure() {
+        assert!(run_command(vec![Object::String("bplus-no-such-command".to_string())]).is_error());
+        assert!(run_command(vec![]).is_error());
+    }
+}
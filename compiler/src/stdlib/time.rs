@@ -2,6 +2,7 @@
 
 use crate::environment::Environment;
 use crate::object::Object;
+use chrono::{Datelike, Timelike};
 
 /// Load all time-related functions into environment
 pub fn load_time_functions(env: &mut Environment) {
@@ -13,6 +14,17 @@ pub fn load_time_functions(env: &mut Environment) {
     env.add_builtin("timestamp".to_string(), Object::BuiltinNative(timestamp_function));
     env.add_builtin("date".to_string(), Object::BuiltinNative(date_function));
     env.add_builtin("sleep".to_string(), Object::BuiltinNative(sleep_function));
+    env.add_builtin("sleep_ms".to_string(), Object::BuiltinNative(sleep_ms_function));
+    env.add_builtin("format_time".to_string(), Object::BuiltinNative(format_time_function));
+
+    // Date components of a Unix timestamp (UTC)
+    env.add_builtin("year".to_string(), Object::BuiltinNative(year_function));
+    env.add_builtin("month".to_string(), Object::BuiltinNative(month_function));
+    env.add_builtin("day".to_string(), Object::BuiltinNative(day_function));
+    env.add_builtin("hour".to_string(), Object::BuiltinNative(hour_function));
+    env.add_builtin("minute".to_string(), Object::BuiltinNative(minute_function));
+    env.add_builtin("second".to_string(), Object::BuiltinNative(second_function));
+    env.add_builtin("day_of_week".to_string(), Object::BuiltinNative(day_of_week_function));
 }
 
 /// Original shomoy function
@@ -54,10 +66,155 @@ fn sleep_function(args: Vec<Object>) -> Object {
     }
     
     match &args[0] {
+        Object::Integer(seconds) if *seconds < 0 => {
+            Object::Error(format!("sleep() duration must not be negative, got {}", seconds))
+        }
         Object::Integer(seconds) => {
             std::thread::sleep(std::time::Duration::from_secs(*seconds as u64));
             Object::Null
         }
         _ => Object::Error("sleep() requires an integer argument".to_string()),
     }
-}
\ No newline at end of file
+}
+
+/// Sleep for specified milliseconds
+fn sleep_ms_function(args: Vec<Object>) -> Object {
+    if args.len() != 1 {
+        return Object::Error("sleep_ms() requires exactly one argument (milliseconds)".to_string());
+    }
+
+    match &args[0] {
+        Object::Integer(millis) if *millis < 0 => {
+            Object::Error(format!("sleep_ms() duration must not be negative, got {}", millis))
+        }
+        Object::Integer(millis) => {
+            std::thread::sleep(std::time::Duration::from_millis(*millis as u64));
+            Object::Null
+        }
+        _ => Object::Error("sleep_ms() requires an integer argument".to_string()),
+    }
+}
+
+/// Format a Unix timestamp (in UTC) with a chrono/strftime pattern, e.g. "%Y-%m-%d"
+fn format_time_function(args: Vec<Object>) -> Object {
+    use chrono::DateTime;
+    use std::fmt::Write;
+
+    if args.len() != 2 {
+        return Object::Error("format_time() requires exactly two arguments (timestamp, pattern)".to_string());
+    }
+
+    match (&args[0], &args[1]) {
+        (Object::Integer(timestamp), Object::String(pattern)) => {
+            let datetime = match DateTime::from_timestamp(*timestamp, 0) {
+                Some(dt) => dt,
+                None => return Object::Error(format!("format_time(): invalid timestamp {}", timestamp)),
+            };
+            // chrono reports unknown specifiers as a fmt::Error while formatting
+            let mut result = String::new();
+            match write!(result, "{}", datetime.format(pattern)) {
+                Ok(_) => Object::String(result),
+                Err(_) => Object::Error(format!("format_time(): invalid pattern '{}'", pattern)),
+            }
+        }
+        _ => Object::Error("format_time() requires an integer timestamp and a string pattern".to_string()),
+    }
+}
+
+/// Extract one calendar component from a Unix timestamp (interpreted in UTC)
+fn date_component(name: &str, args: Vec<Object>, component: fn(&chrono::DateTime<chrono::Utc>) -> i64) -> Object {
+    use chrono::DateTime;
+
+    if args.len() != 1 {
+        return Object::Error(format!("{}() requires exactly one argument (timestamp)", name));
+    }
+
+    match &args[0] {
+        Object::Integer(timestamp) => match DateTime::from_timestamp(*timestamp, 0) {
+            Some(dt) => Object::Integer(component(&dt)),
+            None => Object::Error(format!("{}(): invalid timestamp {}", name, timestamp)),
+        },
+        _ => Object::Error(format!("{}() requires an integer timestamp", name)),
+    }
+}
+
+/// Year of a timestamp, e.g. 2024
+fn year_function(args: Vec<Object>) -> Object {
+    date_component("year", args, |dt| dt.year() as i64)
+}
+
+/// Month of a timestamp, 1-12
+fn month_function(args: Vec<Object>) -> Object {
+    date_component("month", args, |dt| dt.month() as i64)
+}
+
+/// Day of the month, 1-31
+fn day_function(args: Vec<Object>) -> Object {
+    date_component("day", args, |dt| dt.day() as i64)
+}
+
+/// Hour of the day, 0-23
+fn hour_function(args: Vec<Object>) -> Object {
+    date_component("hour", args, |dt| dt.hour() as i64)
+}
+
+/// Minute of the hour, 0-59
+fn minute_function(args: Vec<Object>) -> Object {
+    date_component("minute", args, |dt| dt.minute() as i64)
+}
+
+/// Second of the minute, 0-59
+fn second_function(args: Vec<Object>) -> Object {
+    date_component("second", args, |dt| dt.second() as i64)
+}
+
+/// Day of the week, 0 (Sunday/Robibar) to 6 (Saturday/Shonibar)
+fn day_of_week_function(args: Vec<Object>) -> Object {
+    date_component("day_of_week", args, |dt| dt.weekday().num_days_from_sunday() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Object {
+        Object::String(text.to_string())
+    }
+
+    #[test]
+    fn test_format_time() {
+        // 2024-03-15 13:45:30 UTC
+        let ts = Object::Integer(1_710_510_330);
+        assert_eq!(format_time_function(vec![ts.clone(), s("%Y-%m-%d")]), s("2024-03-15"));
+        assert_eq!(
+            format_time_function(vec![ts.clone(), s("%Y-%m-%d %H:%M:%S")]),
+            s("2024-03-15 13:45:30")
+        );
+        assert!(format_time_function(vec![ts, s("%Q")]).is_error());
+        assert!(format_time_function(vec![Object::Integer(i64::MAX), s("%Y")]).is_error());
+    }
+
+    #[test]
+    fn test_sleep_ms() {
+        let start = std::time::Instant::now();
+        assert_eq!(sleep_ms_function(vec![Object::Integer(10)]), Object::Null);
+        assert!(start.elapsed() >= std::time::Duration::from_millis(10));
+        assert!(sleep_ms_function(vec![Object::Integer(-1)]).is_error());
+        assert!(sleep_function(vec![Object::Integer(-1)]).is_error());
+    }
+
+    #[test]
+    fn test_date_components() {
+        // Friday 2024-03-15 13:45:30 UTC
+        let ts = || vec![Object::Integer(1_710_510_330)];
+        assert_eq!(year_function(ts()), Object::Integer(2024));
+        assert_eq!(month_function(ts()), Object::Integer(3));
+        assert_eq!(day_function(ts()), Object::Integer(15));
+        assert_eq!(hour_function(ts()), Object::Integer(13));
+        assert_eq!(minute_function(ts()), Object::Integer(45));
+        assert_eq!(second_function(ts()), Object::Integer(30));
+        assert_eq!(day_of_week_function(ts()), Object::Integer(5));
+        assert_eq!(day_of_week_function(vec![Object::Integer(0)]), Object::Integer(4));
+        assert!(year_function(vec![s("2024")]).is_error());
+    }
+}
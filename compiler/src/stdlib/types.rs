@@ -0,0 +1,119 @@
+// compiler/src/stdlib/types.rs
+
+use crate::environment::Environment;
+use crate::object::Object;
+
+/// Load all type predicate functions into environment
+pub fn load_type_functions(env: &mut Environment) {
+    env.add_builtin("is_number".to_string(), Object::BuiltinNative(is_number));
+    env.add_builtin("is_string".to_string(), Object::BuiltinNative(is_string));
+    env.add_builtin("is_bool".to_string(), Object::BuiltinNative(is_bool));
+    env.add_builtin("is_array".to_string(), Object::BuiltinNative(is_array));
+    env.add_builtin("is_function".to_string(), Object::BuiltinNative(is_function));
+    env.add_builtin("is_null".to_string(), Object::BuiltinNative(is_null));
+}
+
+/// Ha for integers and floats
+fn is_number(args: Vec<Object>) -> Object {
+    check("is_number", args, |value| matches!(value, Object::Integer(_) | Object::Float(_)))
+}
+
+/// Ha for strings (a single char is not a string)
+fn is_string(args: Vec<Object>) -> Object {
+    check("is_string", args, |value| matches!(value, Object::String(_)))
+}
+
+/// Ha for Ha and Na
+fn is_bool(args: Vec<Object>) -> Object {
+    check("is_bool", args, |value| matches!(value, Object::Boolean(_)))
+}
+
+/// Ha for arrays
+fn is_array(args: Vec<Object>) -> Object {
+    check("is_array", args, |value| matches!(value, Object::Array(_)))
+}
+
+/// Ha for anything that can be called like a function: B+ functions, async functions,
+/// builtins and record types
+fn is_function(args: Vec<Object>) -> Object {
+    check("is_function", args, Object::is_callable)
+}
+
+/// Ha for null, what functions without a result return
+fn is_null(args: Vec<Object>) -> Object {
+    check("is_null", args, |value| matches!(value, Object::Null))
+}
+
+/// Shared body of the predicates: one argument in, a Boolean out
+fn check(name: &str, args: Vec<Object>, test: fn(&Object) -> bool) -> Object {
+    match args.as_slice() {
+        [value] => Object::Boolean(test(value)),
+        _ => Object::Error(format!("{}() takes exactly one argument", name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::CompiledFunction;
+    use crate::object::BuiltinFunction;
+    use std::rc::Rc;
+
+    // One value of each callable kind
+    fn callables() -> Vec<Object> {
+        let compiled = CompiledFunction { arity: 0, locals: vec![], instructions: vec![] };
+        vec![
+            crate::run("fn(x) { x }").unwrap(),
+            Object::CompiledFunction(Rc::new(compiled)),
+            crate::run("shomoy niropekkho fn(x) { x }").unwrap(),
+            Object::RecordType { name: "Bindu".to_string(), fields: vec!["x".to_string()] },
+            Object::BuiltinFunction(BuiltinFunction::Map),
+            Object::BuiltinNative(is_null),
+        ]
+    }
+
+    fn samples() -> Vec<Object> {
+        vec![
+            Object::Integer(1),
+            Object::Float(2.5),
+            Object::String("ek".to_string()),
+            Object::Char('a'),
+            Object::Boolean(false),
+            Object::Array(vec![]),
+            Object::Hash(vec![]),
+            Object::Null,
+        ]
+        .into_iter()
+        .chain(callables())
+        .collect()
+    }
+
+    // Which of the samples the predicate accepts
+    fn accepted(predicate: fn(Vec<Object>) -> Object) -> Vec<Object> {
+        samples()
+            .into_iter()
+            .filter(|value| match predicate(vec![value.clone()]) {
+                Object::Boolean(result) => result,
+                other => panic!("expected a boolean, got {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert_eq!(accepted(is_number), vec![Object::Integer(1), Object::Float(2.5)]);
+        assert_eq!(accepted(is_string), vec![Object::String("ek".to_string())]);
+        assert_eq!(accepted(is_bool), vec![Object::Boolean(false)]);
+        assert_eq!(accepted(is_array), vec![Object::Array(vec![])]);
+        assert_eq!(accepted(is_null), vec![Object::Null]);
+        // Functions made by separate runs never compare equal, so they are told apart by how they print
+        let printed = |values: Vec<Object>| values.iter().map(Object::to_string).collect::<Vec<_>>();
+        assert_eq!(printed(accepted(is_function)), printed(callables()));
+    }
+
+    #[test]
+    fn test_predicates_take_one_argument() {
+        assert!(is_number(vec![]).is_error());
+        assert!(is_null(vec![Object::Null, Object::Null]).is_error());
+    }
+}
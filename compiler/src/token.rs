@@ -2,7 +2,9 @@
 
 use std::fmt;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 /// Categories for tokens, useful for classification and parsing logic.
 /// Each token type belongs to exactly one category for consistent classification.
@@ -93,6 +95,8 @@ pub enum TokenType {
     Bang,
     /// Multiplication operator *
     Asterisk,
+    /// Exponentiation operator **
+    DoubleStar,
     /// Division operator /
     Slash,
     /// Less than operator <
@@ -107,6 +111,16 @@ pub enum TokenType {
     GtEq,
     /// Not equal operator !=
     NotEq,
+    /// Compound assignment +=
+    PlusEq,
+    /// Compound assignment -=
+    MinusEq,
+    /// Compound assignment *=
+    StarEq,
+    /// Compound assignment /=
+    SlashEq,
+    /// Conditional operator ? as in cond ? a : b
+    Question,
 
     // Bitwise Operators
     /// Bitwise AND &
@@ -141,6 +155,8 @@ pub enum TokenType {
     RBracket,
     /// Dot/period .
     Fullstop,
+    /// Ellipsis ... before a variadic parameter: fn(a, ...rest)
+    Ellipsis,
     /// Colon :
     Colon,
 
@@ -274,13 +290,19 @@ impl TokenType {
             | TokenType::Minus 
             | TokenType::Bang 
             | TokenType::Asterisk 
+            | TokenType::DoubleStar 
             | TokenType::Slash 
             | TokenType::Lt 
             | TokenType::Gt 
             | TokenType::Eq 
             | TokenType::LtEq 
             | TokenType::GtEq 
-            | TokenType::NotEq => TokenCategory::Operator,
+            | TokenType::NotEq 
+            | TokenType::PlusEq 
+            | TokenType::MinusEq 
+            | TokenType::StarEq 
+            | TokenType::SlashEq 
+            | TokenType::Question => TokenCategory::Operator,
 
             TokenType::Ampersand 
             | TokenType::Pipe 
@@ -298,6 +320,7 @@ impl TokenType {
             | TokenType::LBracket 
             | TokenType::RBracket 
             | TokenType::Fullstop 
+            | TokenType::Ellipsis 
             | TokenType::Colon => TokenCategory::Delimiter,
 
             TokenType::Function 
@@ -353,14 +376,56 @@ impl TokenType {
     }
 }
 
+/// Shared, immutable text of an identifier or keyword. Cloning one is a reference
+/// count bump, so the same name can appear in tokens, the AST and environments
+/// without copying the string each time.
+pub type Symbol = Rc<str>;
+
+/// Hands out one shared [`Symbol`] per distinct text, so repeated names share storage.
+///
+/// # Examples
+/// ```
+/// # use bplus_compiler::token::*;
+/// let mut interner = Interner::default();
+/// let first = interner.intern("naam");
+/// let second = interner.intern("naam");
+/// assert!(std::rc::Rc::ptr_eq(&first, &second));
+/// ```
+#[derive(Debug, Default)]
+pub struct Interner {
+    symbols: HashSet<Symbol>,
+}
+
+impl Interner {
+    /// Returns the symbol for `text`, allocating it only the first time it is seen.
+    pub fn intern(&mut self, text: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(text) {
+            return symbol.clone();
+        }
+        let symbol: Symbol = Rc::from(text);
+        self.symbols.insert(symbol.clone());
+        symbol
+    }
+
+    /// Number of distinct symbols handed out so far.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Whether no symbol has been handed out yet.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
 /// Struct representing a token, consisting of type, literal, and position info.
 /// This is the fundamental unit of lexical analysis.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Token {
     /// The type of token (keyword, operator, literal, etc.)
     pub token_type: TokenType,
-    /// The actual text that was tokenized
-    pub literal: String,
+    /// The actual text that was tokenized, shared between tokens with the same text
+    pub literal: Symbol,
     /// Line number in source code (1-indexed)
     pub line: usize,
     /// Column number in source code (1-indexed)
@@ -372,21 +437,28 @@ impl Token {
     /// 
     /// # Examples
     /// ```
+    /// # use bplus_compiler::token::*;
     /// let token = Token::new(TokenType::Ident, "variable_name", 1, 5);
     /// ```
     pub fn new(token_type: TokenType, literal: &str, line: usize, column: usize) -> Self {
         Token {
             token_type,
-            literal: literal.to_string(),
+            literal: Rc::from(literal),
             line,
             column,
         }
     }
 
+    /// Like [`Token::new`], with a literal that is already a shared symbol.
+    pub fn with_symbol(token_type: TokenType, literal: Symbol, line: usize, column: usize) -> Self {
+        Token { token_type, literal, line, column }
+    }
+
     /// Creates a string representation of the token, useful for debugging.
     /// 
     /// # Examples
     /// ```
+    /// # use bplus_compiler::token::*;
     /// let token = Token::new(TokenType::Ident, "x", 1, 1);
     /// println!("{}", token.to_string()); // Outputs: Ident('x') at 1:1
     /// ```
@@ -425,6 +497,7 @@ impl fmt::Display for TokenType {
             TokenType::Minus => "-",
             TokenType::Bang => "!",
             TokenType::Asterisk => "*",
+            TokenType::DoubleStar => "**",
             TokenType::Slash => "/",
             TokenType::Lt => "<",
             TokenType::Gt => ">",
@@ -432,6 +505,11 @@ impl fmt::Display for TokenType {
             TokenType::LtEq => "<=",
             TokenType::GtEq => ">=",
             TokenType::NotEq => "!=",
+            TokenType::PlusEq => "+=",
+            TokenType::MinusEq => "-=",
+            TokenType::StarEq => "*=",
+            TokenType::SlashEq => "/=",
+            TokenType::Question => "?",
 
             TokenType::Ampersand => "&",
             TokenType::Pipe => "|",
@@ -449,6 +527,7 @@ impl fmt::Display for TokenType {
             TokenType::LBracket => "[",
             TokenType::RBracket => "]",
             TokenType::Fullstop => ".",
+            TokenType::Ellipsis => "...",
             TokenType::Colon => ":",
 
             TokenType::Function => "function",
@@ -509,16 +588,37 @@ impl fmt::Display for TokenType {
 /// This allows flexible keyword recognition regardless of case or spacing variations.
 /// 
 /// # Examples
-/// ```
+/// ```text
 /// assert_eq!(normalize_keyword("Mone  Koro"), "mone koro");
 /// assert_eq!(normalize_keyword("JODI"), "jodi");
 /// ```
-fn normalize_keyword(ident: &str) -> String {
-    ident
-        .to_lowercase()
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
+/// Text that is already lowercase with single spaces between words (the usual case) is
+/// borrowed as is, so keyword lookups only allocate for text that needs changing.
+fn normalize_keyword(ident: &str) -> Cow<'_, str> {
+    if is_normalized(ident) {
+        return Cow::Borrowed(ident);
+    }
+    Cow::Owned(
+        ident
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Whether normalize_keyword would leave `ident` unchanged
+fn is_normalized(ident: &str) -> bool {
+    !ident.starts_with(' ')
+        && !ident.ends_with(' ')
+        && !ident.contains("  ")
+        && !ident.chars().any(|c| {
+            if c.is_ascii() {
+                c.is_ascii_uppercase() || (c.is_ascii_whitespace() && c != ' ')
+            } else {
+                c.is_whitespace() || c.to_lowercase().ne(std::iter::once(c))
+            }
+        })
 }
 
 /// A map of keyword strings and their synonyms to the canonical TokenType.
@@ -591,6 +691,7 @@ pub static KEYWORDS: Lazy<HashMap<&'static str, TokenType>> = Lazy::new(|| {
     map.insert("othoba", TokenType::Othoba);
     map.insert("ba", TokenType::Othoba);
     map.insert("or", TokenType::Othoba);
+    map.insert("noy", TokenType::Bang); // not: noy x is !x
 
     // Return statement variants
     map.insert("ferot", TokenType::ReturnKoro);
@@ -712,6 +813,7 @@ pub static KEYWORDS: Lazy<HashMap<&'static str, TokenType>> = Lazy::new(|| {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert_eq!(lookup_ident("dhoro"), TokenType::Dhoro);
 /// assert_eq!(lookup_ident("Dhoro"), TokenType::Dhoro);
 /// assert_eq!(lookup_ident("unknown_var"), TokenType::Ident);
@@ -722,12 +824,37 @@ pub fn lookup_ident(ident: &str) -> TokenType {
     }
 
     // Try normalized variant with lowercase and collapsed whitespace
-    let normalized = normalize_keyword(ident);
-    if let Some(&tok_type) = KEYWORDS.get(normalized.as_str()) {
-        return tok_type;
+    match normalize_keyword(ident) {
+        Cow::Owned(normalized) => KEYWORDS.get(normalized.as_str()).copied().unwrap_or(TokenType::Ident),
+        Cow::Borrowed(_) => TokenType::Ident,
     }
+}
 
-    TokenType::Ident
+/// Leading words of the multi-word keywords: "protitar" and "age" for "protitar jonno"
+/// and "age koro", "dhoron ber" for "dhoron ber koro", and so on.
+static KEYWORD_PREFIXES: Lazy<HashSet<String>> = Lazy::new(|| {
+    let mut prefixes = HashSet::new();
+    for keyword in KEYWORDS.keys() {
+        let words: Vec<&str> = keyword.split(' ').collect();
+        for end in 1..words.len() {
+            prefixes.insert(words[..end].join(" "));
+        }
+    }
+    prefixes
+});
+
+/// Whether more words after `words` could complete a multi-word keyword. The lexer
+/// checks this before reading ahead, so most identifiers need no extra lookups.
+///
+/// # Examples
+/// ```
+/// # use bplus_compiler::token::*;
+/// assert!(continues_to_keyword("protitar"));
+/// assert!(continues_to_keyword("Age"));
+/// assert!(!continues_to_keyword("dhoro"));
+/// ```
+pub fn continues_to_keyword(words: &str) -> bool {
+    KEYWORD_PREFIXES.contains(normalize_keyword(words).as_ref())
 }
 
 /// Helper: checks if token is a literal type.
@@ -735,6 +862,7 @@ pub fn lookup_ident(ident: &str) -> TokenType {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_literal(TokenType::Int));
 /// assert!(is_literal(TokenType::String));
 /// assert!(!is_literal(TokenType::Plus));
@@ -762,6 +890,7 @@ pub fn is_literal(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_operator(TokenType::Plus));
 /// assert!(is_operator(TokenType::Eq));
 /// assert!(is_operator(TokenType::Ampersand));
@@ -775,6 +904,7 @@ pub fn is_operator(token_type: TokenType) -> bool {
             | TokenType::Minus
             | TokenType::Bang
             | TokenType::Asterisk
+            | TokenType::DoubleStar
             | TokenType::Slash
             | TokenType::Lt
             | TokenType::Gt
@@ -782,6 +912,11 @@ pub fn is_operator(token_type: TokenType) -> bool {
             | TokenType::LtEq
             | TokenType::GtEq
             | TokenType::NotEq
+            | TokenType::PlusEq
+            | TokenType::MinusEq
+            | TokenType::StarEq
+            | TokenType::SlashEq
+            | TokenType::Question
             | TokenType::Ampersand
             | TokenType::Pipe
             | TokenType::Caret
@@ -796,6 +931,7 @@ pub fn is_operator(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_keyword(TokenType::Function));
 /// assert!(is_keyword(TokenType::Jodi));
 /// assert!(is_keyword(TokenType::Temp));
@@ -826,6 +962,7 @@ pub fn is_keyword(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_loop(TokenType::Jotokhon));
 /// assert!(is_loop(TokenType::Choluk));
 /// assert!(is_loop(TokenType::Thamo));
@@ -849,6 +986,7 @@ pub fn is_loop(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_comment(TokenType::EkLineMontobbo));
 /// assert!(is_comment(TokenType::BohuLineMontobboShuru));
 /// assert!(!is_comment(TokenType::String));
@@ -866,6 +1004,7 @@ pub fn is_comment(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_module(TokenType::ImportKoro));
 /// assert!(is_module(TokenType::ExportKoro));
 /// assert!(is_module(TokenType::Module));
@@ -885,6 +1024,7 @@ pub fn is_module(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_exception_handling(TokenType::CheshtaKoro));
 /// assert!(is_exception_handling(TokenType::DhoreFelo));
 /// assert!(is_exception_handling(TokenType::ThrowKoro));
@@ -904,6 +1044,7 @@ pub fn is_exception_handling(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_type_system(TokenType::TypeBanao));
 /// assert!(is_type_system(TokenType::Dhoroner));
 /// assert!(is_type_system(TokenType::Kisuna));
@@ -922,6 +1063,7 @@ pub fn is_type_system(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_data_structure(TokenType::Talika));
 /// assert!(is_data_structure(TokenType::Arrow));
 /// assert!(is_data_structure(TokenType::DoubleColon));
@@ -940,6 +1082,7 @@ pub fn is_data_structure(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_async(TokenType::OpekkhaKoro));
 /// assert!(is_async(TokenType::ShomoyNiropekho));
 /// assert!(!is_async(TokenType::Function));
@@ -956,6 +1099,7 @@ pub fn is_async(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_delimiter(TokenType::LParen));
 /// assert!(is_delimiter(TokenType::Comma));
 /// assert!(is_delimiter(TokenType::Semicolon));
@@ -973,6 +1117,7 @@ pub fn is_delimiter(token_type: TokenType) -> bool {
             | TokenType::LBracket
             | TokenType::RBracket
             | TokenType::Fullstop
+            | TokenType::Ellipsis
             | TokenType::Colon
     )
 }
@@ -981,6 +1126,7 @@ pub fn is_delimiter(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_bitwise_operator(TokenType::Ampersand));
 /// assert!(is_bitwise_operator(TokenType::ShiftLeft));
 /// assert!(!is_bitwise_operator(TokenType::Plus));
@@ -1003,6 +1149,7 @@ pub fn is_bitwise_operator(token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(!is_reserved(TokenType::Function)); // Currently no reserved tokens
 /// ```
 pub fn is_reserved(_token_type: TokenType) -> bool {
@@ -1017,13 +1164,14 @@ pub fn is_reserved(_token_type: TokenType) -> bool {
 /// 
 /// # Examples
 /// ```
+/// # use bplus_compiler::token::*;
 /// assert!(is_reserved_keyword("jodi"));
 /// assert!(is_reserved_keyword("function"));
 /// assert!(!is_reserved_keyword("myVariable"));
 /// ```
 pub fn is_reserved_keyword(ident: &str) -> bool {
     let normalized = normalize_keyword(ident);
-    RESERVED_KEYWORDS.contains(&normalized.as_str())
+    RESERVED_KEYWORDS.contains(&normalized.as_ref())
 }
 
 /// List of reserved keywords that cannot be used as variable names or identifiers.
@@ -1065,6 +1213,7 @@ pub static RESERVED_KEYWORDS: &[&str] = &[
     "othoba",       // or
     "or",           // or (English)
     "ba",           // or (synonym)
+    "noy",          // not
     
     // Loop keywords
     "jotokhon",     // while
@@ -1125,7 +1274,7 @@ mod tests {
     fn test_token_creation() {
         let token = Token::new(TokenType::Ident, "variable", 1, 5);
         assert_eq!(token.token_type, TokenType::Ident);
-        assert_eq!(token.literal, "variable");
+        assert_eq!(&*token.literal, "variable");
         assert_eq!(token.line, 1);
         assert_eq!(token.column, 5);
     }
@@ -1137,12 +1286,17 @@ mod tests {
         assert_eq!(lookup_ident("DHORO"), TokenType::Dhoro);
         assert_eq!(lookup_ident("ha"), TokenType::Ha);
         assert_eq!(lookup_ident("true"), TokenType::Ha);
+        assert_eq!(lookup_ident("noy"), TokenType::Bang);
+        assert_eq!(lookup_ident("thik noy"), TokenType::Na);
         assert_eq!(lookup_ident("unknown"), TokenType::Ident);
     }
 
     #[test]
     fn test_normalize_keyword() {
         assert_eq!(normalize_keyword("Dhoro"), "dhoro");
+        assert!(matches!(normalize_keyword("protitar jonno"), Cow::Borrowed(_)));
+        assert_eq!(normalize_keyword("Protitar\tJonno"), "protitar jonno");
+        assert_eq!(normalize_keyword("শেষ"), "শেষ");
         assert_eq!(normalize_keyword("JODI"), "jodi");
         assert_eq!(normalize_keyword("  Ha  "), "ha");
     }
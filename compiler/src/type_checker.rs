@@ -1,36 +1,674 @@
 // compiler/src/type_checker.rs
 
-use crate::ast::Program;
+use crate::ast::{Expression, Program, Span, Statement};
+use crate::environment::Environment;
+use crate::error::{ErrorMessages, ErrorType};
 use crate::object::Object;
+use std::collections::HashMap;
 use std::fmt;
 
 /// Custom error type representing type checking errors.
-#[derive(Debug, Clone)]
-pub struct TypeError(String);
+/// Wraps the shared `ErrorType` so the error manager can localize it, along with
+/// the position of the code it is about when the checker knows one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeError {
+    pub error_type: ErrorType,
+    pub span: Option<Span>,
+}
 
 impl fmt::Display for TypeError {
-    /// Format the error message for display purposes.
+    /// Format the error message for display purposes, prefixed with `line:column:` when known.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = ErrorMessages::new_default_banglish().get_message(&self.error_type);
+        match self.span {
+            Some(span) => write!(f, "{}:{}: Type Error: {}", span.line, span.column, message),
+            None => write!(f, "Type Error: {}", message),
+        }
+    }
+}
+
+/// Static type of an expression as far as the checker can tell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Integer,
+    Float,
+    Boolean,
+    String,
+    Char,
+    Null,
+    Array,
+    Hash,
+    /// A callable; `Some(n)` when the number of parameters is known
+    Function(Option<usize>),
+    /// Anything the checker can't pin down (parameters, index results, builtin results...)
+    Unknown,
+}
+
+impl Type {
+    /// Type of a runtime value, used to seed the checker from an environment
+    pub fn of_object(obj: &Object) -> Type {
+        match obj {
+            Object::Integer(_) => Type::Integer,
+            Object::Float(_) => Type::Float,
+            Object::Boolean(_) => Type::Boolean,
+            Object::String(_) => Type::String,
+            Object::Char(_) => Type::Char,
+            Object::Null => Type::Null,
+            Object::Array(_) => Type::Array,
+            Object::Hash(_) => Type::Hash,
+            Object::Function { parameters, defaults, variadic, .. } => Type::Function(fixed_arity(parameters, defaults, *variadic)),
+            Object::BuiltinFunction(_) | Object::BuiltinNative(_) => Type::Function(None),
+            Object::AsyncFunction(function) => Type::of_object(function),
+            Object::RecordType { fields, .. } => Type::Function(Some(fields.len())),
+            Object::Record { .. } => Type::Hash,
+            _ => Type::Unknown,
+        }
+    }
+
+    /// Type of an expression that can be told without looking anything up,
+    /// used to declare names before their definitions are checked
+    fn of_literal(expression: &Expression) -> Type {
+        match expression {
+            Expression::IntegerLiteral(_) => Type::Integer,
+            Expression::FloatLiteral(_) => Type::Float,
+            Expression::StringLiteral(_) | Expression::TemplateLiteral { .. } => Type::String,
+            Expression::CharLiteral(_) => Type::Char,
+            Expression::Boolean(_) => Type::Boolean,
+            Expression::ArrayLiteral { .. } => Type::Array,
+            Expression::HashLiteral { .. } => Type::Hash,
+            Expression::FunctionLiteral { parameters, parameter_defaults, variadic, .. } => {
+                Type::Function(fixed_arity(parameters, parameter_defaults, *variadic))
+            }
+            _ => Type::Unknown,
+        }
+    }
+
+    /// Type named by an annotation such as `purno_songkha` or `shobdo` (English names work too)
+    pub fn from_annotation(name: &str) -> Option<Type> {
+        match name {
+            "purno_songkha" | "int" | "integer" => Some(Type::Integer),
+            "doshomik" | "float" => Some(Type::Float),
+            "shotti_mittha" | "bool" | "boolean" => Some(Type::Boolean),
+            "shobdo" | "string" => Some(Type::String),
+            "okkhor" | "char" => Some(Type::Char),
+            "talika" | "array" => Some(Type::Array),
+            "hash" => Some(Type::Hash),
+            "jekono" | "any" => Some(Type::Unknown),
+            _ => None,
+        }
+    }
+
+    // Whether a value of type `value` may be stored where `self` was declared
+    fn accepts(&self, value: &Type) -> bool {
+        self == value
+            || *self == Type::Unknown
+            || *value == Type::Unknown
+            || (*self == Type::Float && *value == Type::Integer)
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Type::Integer | Type::Float)
+    }
+
+    fn is_textual(&self) -> bool {
+        matches!(self, Type::String | Type::Char)
+    }
+}
+
+impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Type Error: {}", self.0)
+        let name = match self {
+            Type::Integer => "integer",
+            Type::Float => "float",
+            Type::Boolean => "boolean",
+            Type::String => "string",
+            Type::Char => "char",
+            Type::Null => "null",
+            Type::Array => "array",
+            Type::Hash => "hash",
+            Type::Function(_) => "function",
+            Type::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
     }
 }
 
 /// The main TypeChecker struct responsible for verifying type correctness in the AST.
-pub struct TypeChecker;
+pub struct TypeChecker {
+    scopes: Vec<HashMap<String, Binding>>, // Innermost scope last
+    errors: Vec<TypeError>,
+    span: Option<Span>, // Position of the innermost expression being checked, given to errors
+}
+
+/// What the checker knows about a name
+#[derive(Debug, Clone)]
+struct Binding {
+    ty: Type,
+    declared: Option<Type>, // Set when the name was annotated; later assignments must match
+}
+
+impl Binding {
+    fn inferred(ty: Type) -> Self {
+        Binding { ty, declared: None }
+    }
+}
 
 impl TypeChecker {
-    /// Create a new instance of the TypeChecker.
-    pub fn new() -> Self {
-        TypeChecker
+    /// Create a checker that knows every name bound in `env` (builtins, loaded modules, globals).
+    pub fn from_environment(env: &Environment) -> Self {
+        let mut globals = HashMap::new();
+        for name in env.names() {
+            if let Some(value) = env.get(&name) {
+                globals.insert(name, Binding::inferred(Type::of_object(&value)));
+            }
+        }
+        TypeChecker { scopes: vec![globals], errors: Vec::new(), span: None }
     }
 
     /// Perform type checking on the given program AST.
-    /// Returns Ok(()) if types are valid, or TypeError otherwise.
-    pub fn check(&self, program: &Program) -> Result<(), TypeError> {
-        // Placeholder implementation:
-        // A full implementation would traverse the AST nodes,
-        // verify type rules, detect mismatches, and return errors as needed.
-        println!("Type checking passed (not yet implemented).");
-        Ok(())
+    /// Returns Ok(()) if types are valid, or every TypeError found otherwise.
+    pub fn check(&mut self, program: &Program) -> Result<(), Vec<TypeError>> {
+        self.errors.clear();
+        self.span = None;
+        // Top-level names are globals, so any function body may use them,
+        // including functions declared before them (mutual recursion)
+        for statement in program {
+            if let Statement::Let { name: Expression::Identifier(n, _), value, .. } = statement {
+                self.declare(n, Type::of_literal(value));
+            }
+        }
+        for statement in program {
+            self.check_statement(statement);
+        }
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    // === SCOPES ===
+
+    fn lookup(&self, name: &str) -> Option<&Type> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name)).map(|binding| &binding.ty)
+    }
+
+    fn declare(&mut self, name: &str, ty: Type) {
+        self.bind(name, Binding::inferred(ty));
+    }
+
+    fn bind(&mut self, name: &str, binding: Binding) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), binding);
+        }
+    }
+
+    // Assignment updates the nearest scope that has the name, like Environment::assign
+    fn assign(&mut self, name: &str, ty: Type) {
+        let existing = self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name));
+        match existing {
+            Some(binding) => match &binding.declared {
+                Some(declared) if !declared.accepts(&ty) => {
+                    let error = ErrorType::TypeMismatch(declared.to_string(), ty.to_string());
+                    self.error(error);
+                }
+                Some(_) => {}
+                None => binding.ty = ty,
+            },
+            None => self.declare(name, ty),
+        }
+    }
+
+    // Resolve an annotation, reporting unknown type names
+    fn annotated_type(&mut self, annotation: &Option<String>) -> Option<Type> {
+        let name = annotation.as_ref()?;
+        let ty = Type::from_annotation(name);
+        if ty.is_none() {
+            self.error(ErrorType::InvalidExpression(format!("unknown type '{}'", name)));
+        }
+        ty
+    }
+
+    fn error(&mut self, error_type: ErrorType) {
+        self.errors.push(TypeError { error_type, span: self.span });
+    }
+
+    // === STATEMENTS ===
+
+    fn check_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Let { name, type_annotation, value, .. } => {
+                // Declare functions before checking their body so they can refer to themselves
+                if let (Expression::Identifier(n, _), Expression::FunctionLiteral { .. }) = (name, value) {
+                    self.declare(n, Type::of_literal(value));
+                }
+                let ty = self.infer(value);
+                self.span = name.span();
+                let declared = self.annotated_type(type_annotation);
+                if let Some(declared) = &declared {
+                    if !declared.accepts(&ty) {
+                        self.error(ErrorType::TypeMismatch(declared.to_string(), ty.to_string()));
+                    }
+                }
+                if let Expression::Identifier(n, _) = name {
+                    let ty = declared.clone().unwrap_or(ty);
+                    self.bind(n, Binding { ty, declared });
+                }
+            }
+            Statement::Assign { name, value } => {
+                let ty = self.infer(value);
+                self.span = name.span();
+                if let Expression::Identifier(n, _) = name {
+                    self.assign(n, ty);
+                }
+            }
+            Statement::Expression(expression)
+            | Statement::ExpressionStatement { expression }
+            | Statement::Return { return_value: expression } => {
+                self.infer(expression);
+            }
+            Statement::While { condition, body } | Statement::DoWhile { body, condition } => {
+                self.infer(condition);
+                self.check_scoped_block(body);
+            }
+            Statement::For { init, condition, update, body } => {
+                // What the init part declares belongs to the loop
+                self.scopes.push(HashMap::new());
+                if let Some(init) = init {
+                    self.check_statement(init);
+                }
+                if let Some(condition) = condition {
+                    self.infer(condition);
+                }
+                if let Some(update) = update {
+                    self.infer(update);
+                }
+                self.check_scoped_block(body);
+                self.scopes.pop();
+            }
+            Statement::ForEach { var, iterable, body } => {
+                let iterable_ty = self.infer(iterable);
+                self.span = iterable.span().or(var.span());
+                let element_ty = match iterable_ty {
+                    Type::String => Type::Char,
+                    Type::Array | Type::Unknown => Type::Unknown,
+                    other => {
+                        self.error(ErrorType::TypeMismatch("array or string".to_string(), other.to_string()));
+                        Type::Unknown
+                    }
+                };
+                // The loop variable lives in its own scope, as in the evaluator
+                self.scopes.push(HashMap::new());
+                if let Expression::Identifier(n, _) = var {
+                    self.declare(n, element_ty);
+                }
+                self.check_block(body);
+                self.scopes.pop();
+            }
+            // A module's bindings are only known once the file is evaluated
+            Statement::Import { alias, .. } => self.declare(alias, Type::Hash),
+            Statement::TypeDefinition { name, fields } => self.declare(name, Type::Function(Some(fields.len()))),
+            Statement::CommentSingleLine { .. }
+            | Statement::CommentMultiLine { .. }
+            | Statement::Break(_)
+            | Statement::Continue(_) => {}
+            Statement::Labeled { body, .. } => self.check_statement(body),
+        }
+    }
+
+    // Statements checked in the current scope, as for a function body
+    fn check_block(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.check_statement(statement);
+        }
+    }
+
+    // An if or loop body, whose declarations end with it, matching eval_scoped_block
+    fn check_scoped_block(&mut self, statements: &[Statement]) {
+        self.scopes.push(HashMap::new());
+        self.check_block(statements);
+        self.scopes.pop();
+    }
+
+    // === EXPRESSIONS ===
+
+    // Errors found while inferring an expression point at it, or at the
+    // nearest enclosing expression that has a position
+    fn infer(&mut self, expression: &Expression) -> Type {
+        let enclosing = self.span;
+        if let Some(span) = expression.span() {
+            self.span = Some(span);
+        }
+        let ty = self.infer_expression(expression);
+        self.span = enclosing;
+        ty
     }
+
+    fn infer_expression(&mut self, expression: &Expression) -> Type {
+        match expression {
+            Expression::IntegerLiteral(_) => Type::Integer,
+            Expression::FloatLiteral(_) => Type::Float,
+            Expression::StringLiteral(_) => Type::String,
+            Expression::CharLiteral(_) => Type::Char,
+            Expression::Boolean(_) => Type::Boolean,
+
+            Expression::Identifier(name, _) => match self.lookup(name) {
+                Some(ty) => ty.clone(),
+                None => {
+                    self.error(ErrorType::UndefinedVariable(name.to_string()));
+                    Type::Unknown
+                }
+            },
+
+            Expression::Prefix { operator, right, .. } => {
+                let right_ty = self.infer(right);
+                match operator.as_str() {
+                    "!" => Type::Boolean,
+                    "-" if right_ty.is_numeric() || right_ty == Type::Unknown => right_ty,
+                    "-" => {
+                        self.error(ErrorType::TypeMismatch("number".to_string(), format!("-{}", right_ty)));
+                        Type::Unknown
+                    }
+                    _ => Type::Unknown,
+                }
+            }
+
+            Expression::Infix { left, operator, right, .. } => {
+                let left_ty = self.infer(left);
+                let right_ty = self.infer(right);
+                self.infer_infix(operator, left_ty, right_ty)
+            }
+
+            Expression::If { condition, consequence, alternative } => {
+                self.infer(condition);
+                self.check_scoped_block(consequence);
+                if let Some(alternative) = alternative {
+                    self.infer(alternative);
+                }
+                Type::Unknown
+            }
+
+            Expression::FunctionLiteral { parameters, parameter_types, parameter_defaults, variadic, body } => {
+                // A default can use the parameters before it, so each one is checked
+                // after those are declared and before its own parameter is
+                self.scopes.push(HashMap::new());
+                for ((param, annotation), default) in parameters.iter().zip(parameter_types).zip(parameter_defaults) {
+                    if let Some(default) = default {
+                        self.infer(default);
+                    }
+                    self.span = param.span();
+                    let declared = self.annotated_type(annotation);
+                    if let Expression::Identifier(n, _) = param {
+                        let ty = declared.clone().unwrap_or(Type::Unknown);
+                        self.bind(n, Binding { ty, declared });
+                    }
+                }
+                self.check_block(body);
+                self.scopes.pop();
+                Type::Function(fixed_arity(parameters, parameter_defaults, *variadic))
+            }
+
+            Expression::Call { function, arguments, .. } => {
+                let function_ty = match function.as_ref() {
+                    Expression::Identifier(name, _) => match self.lookup(name) {
+                        Some(ty) => ty.clone(),
+                        None => {
+                            self.error(ErrorType::UndefinedFunction(name.to_string()));
+                            Type::Unknown
+                        }
+                    },
+                    other => self.infer(other),
+                };
+                for argument in arguments {
+                    self.infer(argument);
+                }
+                match function_ty {
+                    Type::Function(Some(arity)) if arity != arguments.len() => {
+                        self.error(ErrorType::WrongArgumentCount(arity, arguments.len()));
+                    }
+                    Type::Function(_) | Type::Unknown => {}
+                    other => {
+                        self.error(ErrorType::TypeMismatch("function".to_string(), other.to_string()));
+                    }
+                }
+                Type::Unknown
+            }
+
+            Expression::TemplateLiteral { parts } => {
+                for part in parts {
+                    self.infer(part);
+                }
+                Type::String
+            }
+
+            Expression::ArrayLiteral { elements } => {
+                for element in elements {
+                    self.infer(element);
+                }
+                Type::Array
+            }
+
+            Expression::HashLiteral { pairs } => {
+                for (key, value) in pairs {
+                    self.infer(key);
+                    self.infer(value);
+                }
+                Type::Hash
+            }
+
+            Expression::Index { left, index, .. } => {
+                let left_ty = self.infer(left);
+                self.infer(index);
+                match left_ty {
+                    Type::String => Type::Char,
+                    Type::Array | Type::Hash | Type::Unknown => Type::Unknown,
+                    other => {
+                        self.error(ErrorType::TypeMismatch("array, hash or string".to_string(), other.to_string()));
+                        Type::Unknown
+                    }
+                }
+            }
+
+            // Calls give futures, whose settled values aren't typed yet
+            Expression::AsyncFunction(function) => match self.infer(function) {
+                Type::Function(arity) => Type::Function(arity),
+                _ => Type::Unknown,
+            },
+            Expression::Await(value) => {
+                self.infer(value);
+                Type::Unknown
+            }
+        }
+    }
+
+    // Result type of a binary operator, mirroring eval_infix_expression
+    fn infer_infix(&mut self, operator: &str, left: Type, right: Type) -> Type {
+        use Type::*;
+
+        if left == Unknown || right == Unknown {
+            return match operator {
+                "<" | ">" | "<=" | ">=" | "==" | "!=" => Boolean,
+                _ => Unknown,
+            };
+        }
+
+        let result = match operator {
+            "==" | "!=" => Some(Boolean),
+            "+" | "-" | "*" | "/" if left.is_numeric() && right.is_numeric() => {
+                Some(if left == Integer && right == Integer { Integer } else { Float })
+            }
+            // Integer powers with a negative exponent come out as floats
+            "**" if left.is_numeric() && right.is_numeric() => {
+                Some(if left == Integer && right == Integer { Unknown } else { Float })
+            }
+            "+" if left.is_textual() && right.is_textual() => Some(String),
+            "*" if (left == String && right == Integer) || (left == Integer && right == String) => Some(String),
+            "<" | ">" | "<=" | ">=" => {
+                let comparable = (left.is_numeric() && right.is_numeric())
+                    || (left == String && right == String)
+                    || (left == Char && right == Char);
+                comparable.then_some(Boolean)
+            }
+            "+" | "-" | "*" | "/" | "**" => None,
+            // Logical and other operators aren't typed yet
+            _ => Some(Unknown),
+        };
+
+        result.unwrap_or_else(|| {
+            self.error(ErrorType::TypeMismatch(
+                format!("compatible types for {}", operator),
+                format!("{} {} {}", left, operator, right),
+            ));
+            Unknown
+        })
+    }
+}
+
+/// Number of arguments a function takes, if that is a single number:
+/// defaults and a variadic parameter make the count flexible
+fn fixed_arity(parameters: &[Expression], defaults: &[Option<Expression>], variadic: bool) -> Option<usize> {
+    (!variadic && defaults.iter().all(Option::is_none)).then_some(parameters.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn check(input: &str) -> Result<(), Vec<TypeError>> {
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+        TypeChecker::from_environment(&Environment::new()).check(&program)
+    }
+
+    fn errors(input: &str) -> Vec<ErrorType> {
+        check(input).unwrap_err().into_iter().map(|e| e.error_type).collect()
+    }
+
+    #[test]
+    fn test_valid_program() {
+        let input = r#"
+            dhoro x = 5;
+            dhoro naam = "B+";
+            dhoro jog = fn(a, b) { a + b };
+            dekhao(jog(x, 2), naam + "!", x * 1.5);
+            protitar jonno (c jekhane naam) { dekhao(c); }
+        "#;
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn test_incompatible_operands() {
+        let found = errors(r#"dhoro x = "a" - 1; dhoro y = Ha * 2;"#);
+        assert_eq!(found.len(), 2);
+        assert!(matches!(&found[0], ErrorType::TypeMismatch(_, got) if got == "string - integer"));
+        assert!(matches!(&found[1], ErrorType::TypeMismatch(_, got) if got == "boolean * integer"));
+    }
+
+    #[test]
+    fn test_calling_non_function() {
+        assert_eq!(
+            errors("dhoro x = 5; x(1);"),
+            vec![ErrorType::TypeMismatch("function".to_string(), "integer".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_undefined_identifiers() {
+        assert_eq!(
+            errors("dekhao(y); jani(1);"),
+            vec![
+                ErrorType::UndefinedVariable("y".to_string()),
+                ErrorType::UndefinedFunction("jani".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_block_declarations_end_with_the_block() {
+        assert_eq!(
+            errors("jodi (Ha) { dhoro y = 1; } dekhao(y);"),
+            vec![ErrorType::UndefinedVariable("y".to_string())]
+        );
+        assert_eq!(check("dhoro x = 1; jotokhon (x < 3) { dhoro d = 1; x = x + d; } dekhao(x);"), Ok(()));
+    }
+
+    #[test]
+    fn test_mutual_recursion() {
+        let input = r#"
+            dhoro is_even = fn(n) { jodi (n == 0) { ferot Ha; } ferot is_odd(n - 1); };
+            dhoro is_odd = fn(n) { jodi (n == 0) { ferot Na; } ferot is_even(n - 1); };
+            dekhao(is_even(10));
+        "#;
+        assert_eq!(check(input), Ok(()));
+        // Later names are known to function bodies with their arity
+        assert_eq!(
+            errors("dhoro f = fn() { g(1) }; dhoro g = fn(a, b) { a };"),
+            vec![ErrorType::WrongArgumentCount(2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_errors_have_positions() {
+        let found = check("dhoro x = 1;\ndekhao(x, y);\ndhoro z = \"a\" - x;").unwrap_err();
+        let positions: Vec<_> = found.iter().map(|e| e.span.map(|span| (span.line, span.column))).collect();
+        assert_eq!(positions, vec![Some((2, 11)), Some((3, 15))]);
+    }
+
+    #[test]
+    fn test_wrong_argument_count() {
+        assert_eq!(
+            errors("dhoro f = fn(a, b) { a }; f(1); f(1, 2); f(1, 2, 3);"),
+            vec![ErrorType::WrongArgumentCount(2, 1), ErrorType::WrongArgumentCount(2, 3)]
+        );
+    }
+
+    #[test]
+    fn test_defaults_see_earlier_parameters() {
+        assert_eq!(check("dhoro f = fn(a, b = a * 2) { a + b }; dekhao(f(5));"), Ok(()));
+        assert_eq!(
+            errors("dhoro f = fn(a = b, b = 1) { a };"),
+            vec![ErrorType::UndefinedVariable("b".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_display_uses_banglish_messages() {
+        let err = TypeError { error_type: ErrorType::UndefinedVariable("y".to_string()), span: None };
+        assert_eq!(err.to_string(), "Type Error: Ojana variable 'y' - prothome ghoshona korun");
+        let err = TypeError { span: Some(Span { line: 2, column: 5 }), ..err };
+        assert_eq!(err.to_string(), "2:5: Type Error: Ojana variable 'y' - prothome ghoshona korun");
+    }
+
+    #[test]
+    fn test_declared_type_mismatch() {
+        assert_eq!(
+            errors(r#"dhoro naam: shobdo = 5;"#),
+            vec![ErrorType::TypeMismatch("string".to_string(), "integer".to_string())]
+        );
+        assert_eq!(
+            errors(r#"dhoro naam: shobdo = "Rahim"; naam = 7;"#),
+            vec![ErrorType::TypeMismatch("string".to_string(), "integer".to_string())]
+        );
+        assert_eq!(check("dhoro x: doshomik = 1; x = 2.5; dhoro y: purno_songkha = 3;"), Ok(()));
+    }
+
+    #[test]
+    fn test_annotated_parameters() {
+        assert_eq!(
+            errors(r#"dhoro f = fn(a: shobdo, b: purno_songkha) { a - b };"#),
+            vec![ErrorType::TypeMismatch(
+                "compatible types for -".to_string(),
+                "string - integer".to_string()
+            )]
+        );
+        assert!(matches!(
+            errors("dhoro x: rong = 1;").as_slice(),
+            [ErrorType::InvalidExpression(_)]
+        ));
+    }
+}
+
@@ -0,0 +1,293 @@
+// compiler/src/vm.rs
+
+// === IMPORTS ===
+use crate::compiler::{Bytecode, CompiledFunction, Instruction};
+use crate::environment::{Environment, Variable};
+use crate::evaluator::{
+    eval_infix_expression, eval_prefix_expression, format_boolean, is_truthy, DEFAULT_MAX_CALL_DEPTH,
+    STACK_OVERFLOW_ERROR, WRONG_ARGUMENT_COUNT_ERROR,
+};
+use crate::object::Object;
+use std::panic;
+use std::rc::Rc;
+
+// One active call: the function, where it is in its code, its local slots and
+// where its callee sits on the value stack
+struct Frame {
+    function: Rc<CompiledFunction>,
+    ip: usize,
+    locals: Vec<Option<Variable>>,
+    base: usize,
+}
+
+// === VM ===
+// Runs bytecode from compiler.rs on a value stack. Operators, truthiness and error
+// messages come from the evaluator, so both backends give the same results.
+pub struct Vm {
+    max_call_depth: usize,
+}
+
+impl Default for Vm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Vm {
+    // === FUNCTION: new ===
+    pub fn new() -> Self {
+        Vm { max_call_depth: DEFAULT_MAX_CALL_DEPTH }
+    }
+
+    // Changes how deeply B+ functions may call each other, like Evaluator::set_max_call_depth
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = depth;
+    }
+
+    // === FUNCTION: run ===
+    // Runs a compiled program; globals it doesn't define are looked up in `env`,
+    // which is how it reaches the builtins. Returns what Evaluator::eval would.
+    pub fn run(&mut self, bytecode: &Bytecode, env: &Environment) -> Object {
+        let mut globals: Vec<Option<Variable>> = bytecode
+            .globals
+            .iter()
+            .map(|name| env.get(name).map(|value| Variable { value, mutable: true }))
+            .collect();
+        let mut stack: Vec<Object> = Vec::new();
+        let mut frames = vec![Frame {
+            function: bytecode.main.clone(),
+            ip: 0,
+            locals: vec![None; bytecode.main.locals.len()],
+            base: 0,
+        }];
+
+        loop {
+            // frames[0] is the top level, the rest are calls in progress
+            let call_depth = frames.len() - 1;
+            let frame = frames.last_mut().expect("no active frame");
+            let instruction = &frame.function.instructions[frame.ip];
+            frame.ip += 1;
+
+            match instruction {
+                Instruction::Constant(value) => stack.push(value.clone()),
+                Instruction::Pop => {
+                    stack.pop();
+                }
+                Instruction::GetGlobal(slot) => match &globals[*slot] {
+                    Some(variable) => stack.push(variable.value.clone()),
+                    None => return not_found(&bytecode.globals[*slot]),
+                },
+                Instruction::DefineGlobal(slot, mutable) => {
+                    let value = pop(&mut stack);
+                    globals[*slot] = Some(Variable { value, mutable: *mutable });
+                }
+                Instruction::AssignGlobal(slot) => {
+                    if let Err(error) = assign(&mut globals[*slot], &bytecode.globals[*slot], &stack) {
+                        return error;
+                    }
+                }
+                Instruction::GetLocal(slot) => match &frame.locals[*slot] {
+                    Some(variable) => stack.push(variable.value.clone()),
+                    None => return not_found(&frame.function.locals[*slot]),
+                },
+                Instruction::DefineLocal(slot, mutable) => {
+                    let value = pop(&mut stack);
+                    frame.locals[*slot] = Some(Variable { value, mutable: *mutable });
+                }
+                Instruction::AssignLocal(slot) => {
+                    if let Err(error) = assign(&mut frame.locals[*slot], &frame.function.locals[*slot], &stack) {
+                        return error;
+                    }
+                }
+                Instruction::Prefix(operator) => {
+                    let right = pop(&mut stack);
+                    match eval_prefix_expression(operator, right) {
+                        error @ Object::Error(_) => return error,
+                        value => stack.push(value),
+                    }
+                }
+                Instruction::Infix(operator) => {
+                    let right = pop(&mut stack);
+                    let left = pop(&mut stack);
+                    match eval_infix_expression(operator, left, right) {
+                        error @ Object::Error(_) => return error,
+                        value => stack.push(value),
+                    }
+                }
+                Instruction::Jump(target) => frame.ip = *target,
+                Instruction::JumpIfFalse(target) => {
+                    if !is_truthy(&pop(&mut stack)) {
+                        frame.ip = *target;
+                    }
+                }
+                Instruction::Call(argument_count) => {
+                    let base = stack.len() - argument_count - 1;
+                    match stack[base].clone() {
+                        Object::CompiledFunction(function) => {
+                            if call_depth >= self.max_call_depth {
+                                return Object::Error(format!(
+                                    "{}: maximum call depth of {} exceeded",
+                                    STACK_OVERFLOW_ERROR, self.max_call_depth
+                                ));
+                            }
+                            if *argument_count != function.arity {
+                                return Object::Error(format!(
+                                    "{}: expected {}, got {}",
+                                    WRONG_ARGUMENT_COUNT_ERROR, function.arity, argument_count
+                                ));
+                            }
+
+                            // Parameters are mutable, as in the evaluator
+                            let mut locals: Vec<Option<Variable>> = stack
+                                .drain(base + 1..)
+                                .map(|value| Some(Variable { value, mutable: true }))
+                                .collect();
+                            locals.resize(function.locals.len(), None);
+                            frames.push(Frame { function, ip: 0, locals, base });
+                        }
+                        Object::BuiltinNative(builtin_fn) => {
+                            let args = stack.split_off(base + 1);
+                            stack.pop();
+                            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| builtin_fn(args)));
+                            match result {
+                                Ok(error @ Object::Error(_)) => return error,
+                                Ok(value) => stack.push(value),
+                                Err(_) => return Object::Error("panic occurred in built-in function".to_string()),
+                            }
+                        }
+                        Object::BuiltinFunction(builtin) => {
+                            return Object::Error(format!("the VM doesn't support the {:?} builtin yet", builtin))
+                        }
+                        other => return Object::Error(format!("not a function: {:?}", other)),
+                    }
+                }
+                Instruction::Return => {
+                    let value = pop(&mut stack);
+                    let finished = frames.pop().expect("no active frame");
+                    if frames.is_empty() {
+                        return format_boolean(value);
+                    }
+                    stack.truncate(finished.base);
+                    stack.push(value);
+                }
+            }
+        }
+    }
+}
+
+// The compiler only pops values it pushed, so an empty stack is a compiler bug
+fn pop(stack: &mut Vec<Object>) -> Object {
+    stack.pop().expect("VM stack underflow")
+}
+
+// Stores the top of the stack in a slot, with Environment::assign's rules
+fn assign(slot: &mut Option<Variable>, name: &str, stack: &[Object]) -> Result<(), Object> {
+    let value = stack.last().expect("VM stack underflow").clone();
+    match slot {
+        Some(variable) if !variable.mutable => {
+            Err(Object::Error(format!("Cannot assign to immutable variable '{}'", name)))
+        }
+        Some(variable) => {
+            variable.value = value;
+            Ok(())
+        }
+        None => {
+            *slot = Some(Variable { value, mutable: false });
+            Ok(())
+        }
+    }
+}
+
+fn not_found(name: &str) -> Object {
+    Object::Error(format!("identifier not found: {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::evaluator::Evaluator;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    // Result of running source with the VM and with the tree-walking evaluator
+    fn run_both(source: &str) -> (Object, Object) {
+        let mut parser = Parser::new(Lexer::new(source.to_string()));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "parser errors: {:?}", parser.errors);
+
+        let bytecode = Compiler::new().compile(&program).unwrap_or_else(|e| panic!("{}: {}", e, source));
+        // Low enough for runaway recursion in the evaluator to fit a test thread's stack
+        let mut vm = Vm::new();
+        vm.set_max_call_depth(50);
+        let mut evaluator = Evaluator::new();
+        evaluator.set_max_call_depth(50);
+        let result = vm.run(&bytecode, &Environment::new());
+        (result, evaluator.eval(program, &mut Environment::new()))
+    }
+
+    #[test]
+    fn test_vm_matches_evaluator() {
+        let programs = [
+            "1 + 2 * 3",
+            "(10 - 4) / 3",
+            "-7 + 2.5",
+            "!Ha",
+            "3 < 4",
+            "5 == 5.0",
+            "\"ab\" + \"cd\"",
+            "dhoro x = 4; x = x * x; x",
+            "jodi (2 > 1) { 10 } nahoy { 20 }",
+            "jodi (2 < 1) { 10 }",
+            "jodi (Na) { 1 } nahoy jodi (Ha) { 2 } nahoy { 3 }",
+            "dhoro i = 0; dhoro s = 0; jotokhon (i < 5) { i = i + 1; s = s + i; } s",
+            "dhoro jog = fn(a, b) { a + b }; jog(2, 3)",
+            "dhoro fact = fn(n) { jodi (n < 2) { ferot 1; } ferot n * fact(n - 1); }; fact(10)",
+            "dhoro fib = fn(n) { jodi (n < 2) { n } nahoy { fib(n - 1) + fib(n - 2) } }; fib(15)",
+            "dhoro twice = fn(f, x) { f(f(x)) }; dhoro inc = fn(x) { x + 1 }; twice(inc, 5)",
+            "dhoro c = 0; dhoro barao = fn() { c = c + 1; }; barao(); barao(); c",
+            "dhoro f = fn() { c = 5; }; dhoro c = 0; f(); c",
+            "dhoro f = fn(x) { dhoro y = x * 2; y }; f(4)",
+            "dhoro f = fn() { ferot 1; 2 }; f()",
+            "ferot 5; 6",
+            "fn(a, b) { a }",
+            "dekhao",
+            "dhoro x = 3; x > 1 && x < 5",
+            "1 > 2 || 2 > 1",
+            "Na ebong ojana",
+            "Ha othoba ojana",
+            "Na || Na",
+            "dhoro x = 1; jodi (Ha) { dhoro x = 2; x = x + 10; } x",
+            "dhoro x = 1; jodi (Ha) { dhoro x = 2; x }",
+            "dhoro x = 1; jodi (Ha) { x = 5; } x",
+            "dhoro i = 0; dhoro s = 0; jotokhon (i < 3) { dhoro d = i * 2; s = s + d; i = i + 1; } s",
+            "dhoro f = fn(n) { dhoro r = 0; jodi (n > 0) { dhoro r = n; r = r * 2; } r }; f(4)",
+            "dhoro f = fn(n) { jodi (n > 0) { dhoro r = n; ferot r * 3; } 0 }; f(4)",
+        ];
+        for source in programs {
+            let (vm, evaluated) = run_both(source);
+            assert_eq!(vm.to_string(), evaluated.to_string(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_vm_errors_match_evaluator() {
+        let programs = [
+            "1 / 0",
+            "ojana + 1",
+            "Ha + 1",
+            "let x = 1; x = 2;",
+            "dhoro f = fn(a) { a }; f(1, 2)",
+            "dhoro x = 5; x(1)",
+            "9223372036854775807 + 1",
+            "dhoro f = fn(n) { f(n + 1) }; f(0)",
+            "jodi (Ha) { dhoro y = 1; } y",
+            "dhoro f = fn() { jodi (Ha) { dhoro y = 1; } y }; f()",
+        ];
+        for source in programs {
+            let (vm, evaluated) = run_both(source);
+            assert!(matches!(vm, Object::Error(_)), "{}: {:?}", source, vm);
+            assert_eq!(vm, evaluated, "{}", source);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// compiler/tests/allocations.rs
+
+// Counts heap allocations made while lexing and parsing, to check that repeated
+// identifiers share one interned string instead of allocating a copy per use
+
+use bplus_compiler::token::TokenType;
+use bplus_compiler::{Lexer, Parser};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct CountingAllocator;
+
+thread_local! {
+    // Per thread, so tests running in parallel don't count each other's allocations
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Allocations made by `f` on this thread
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    drop(f());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+// Source using the same few names over and over
+fn identifier_heavy(repeats: usize) -> String {
+    "motta = motta + gonona * gonona - motta;\n".repeat(repeats)
+}
+
+// Every token of `source`, without the final Eof
+fn lex(source: String) -> Vec<bplus_compiler::token::Token> {
+    let mut lexer = Lexer::new(source);
+    std::iter::from_fn(|| Some(lexer.next_token()))
+        .take_while(|token| token.token_type != TokenType::Eof)
+        .collect()
+}
+
+#[test]
+fn repeated_identifiers_share_one_allocation() {
+    let tokens = lex(identifier_heavy(3));
+    let motta: Vec<_> = tokens.iter().filter(|token| &*token.literal == "motta").collect();
+    assert_eq!(motta.len(), 9);
+    assert!(motta.iter().all(|token| Rc::ptr_eq(&token.literal, &motta[0].literal)));
+
+    // Lexing ten times as much source only costs the bigger buffers, not a string per identifier
+    let small = allocations(|| lex(identifier_heavy(100)));
+    let large = allocations(|| lex(identifier_heavy(1000)));
+    let identifiers_added = 900 * 5;
+    assert!(large - small < identifiers_added / 10, "{} extra allocations", large - small);
+}
+
+#[test]
+fn parsing_reuses_the_lexers_identifiers() {
+    let parse = |repeats| {
+        let mut parser = Parser::new(Lexer::new(identifier_heavy(repeats)));
+        let program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+        program
+    };
+    let small = allocations(|| parse(100));
+    let large = allocations(|| parse(1000));
+
+    // Each statement still allocates its AST nodes (about 18), but no longer a copy of
+    // every token text and name in it (about 55 per statement before interning)
+    let statements_added = 900;
+    let per_statement = (large - small) / statements_added;
+    assert!(per_statement < 30, "{} allocations per statement", per_statement);
+}
@@ -0,0 +1,255 @@
+// compiler/tests/cli.rs
+
+// Integration tests that run the compiled interpreter binary
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Write `source` to a fresh temp directory and run the interpreter on it from there
+fn run_bplus(flags: &[&str], source: &str) -> Output {
+    run_bplus_with_files(flags, source, &[])
+}
+
+// Like run_bplus, with extra files (such as a bplus.toml) next to the script. HOME
+// points at the temp directory too, so a ~/.bplusrc on the machine can't leak in.
+fn run_bplus_with_files(flags: &[&str], source: &str, files: &[(&str, &str)]) -> Output {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let run = RUNS.fetch_add(1, Ordering::SeqCst);
+    let dir = std::env::temp_dir().join(format!("bplus_cli_{}_{}", std::process::id(), run));
+    fs::create_dir_all(&dir).unwrap();
+    let script: PathBuf = dir.join("main.bp");
+    fs::write(&script, source).unwrap();
+    for (name, content) in files {
+        fs::write(dir.join(name), content).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bplus-compiler"))
+        .args(flags)
+        .arg(&script)
+        .current_dir(&dir)
+        .env("HOME", &dir)
+        .output()
+        .expect("failed to run bplus-compiler");
+    let _ = fs::remove_dir_all(&dir);
+    output
+}
+
+#[test]
+fn ast_flag_prints_parsed_program() {
+    let output = run_bplus(&["--ast"], "dhoro x = 5;\njodi (x > 3) { dekhao(x + 1) }\n");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "dhoro x = 5;\njodi (x > 3) {\n    dekhao(x + 1);\n}\n"
+    );
+}
+
+#[test]
+fn ast_flag_reports_parse_errors() {
+    let output = run_bplus(&["--ast"], "dhoro = 5;\n");
+    assert!(output.stdout.is_empty());
+    assert!(!output.stderr.is_empty());
+}
+
+#[test]
+fn eval_flag_runs_inline_code() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bplus-compiler"))
+        .args(["-e", "dekhao(2 + 3)"])
+        .current_dir(std::env::temp_dir())
+        .output()
+        .expect("failed to run bplus-compiler");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "5\n");
+}
+
+#[test]
+fn eval_flag_fails_on_error() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bplus-compiler"))
+        .args(["--eval", "dekhao(ojana)"])
+        .current_dir(std::env::temp_dir())
+        .output()
+        .expect("failed to run bplus-compiler");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!output.stderr.is_empty());
+}
+
+#[test]
+fn script_exit_status_reflects_errors() {
+    assert_eq!(run_bplus(&[], "dhoro = 5;\n").status.code(), Some(1));
+    assert_eq!(run_bplus(&[], "dekhao(1 / 0)\n").status.code(), Some(1));
+    assert_eq!(run_bplus(&[], "dhoro x = 1;\ndekhao(x)\n").status.code(), Some(0));
+}
+
+#[test]
+fn missing_script_exits_non_zero() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bplus-compiler"))
+        .arg("bplus-no-such-file.bp")
+        .current_dir(std::env::temp_dir())
+        .output()
+        .expect("failed to run bplus-compiler");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn runtime_errors_report_their_position() {
+    let output = run_bplus(&[], "dhoro x = 0;\ndekhao(10 / x)\n");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("main.bp:2:11: "), "stderr: {}", stderr);
+    assert!(stderr.contains("division by zero"), "stderr: {}", stderr);
+}
+
+#[test]
+fn json_errors_flag_prints_json_diagnostics() {
+    let output = run_bplus(&["--json-errors"], "dhoro x = 0;\ndekhao(10 / x)\n");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let json = stderr.lines().find(|line| line.starts_with('{')).expect("no JSON diagnostic");
+    assert!(json.starts_with(r#"{"type":"internal_error","message":"Antoronio truti: division by zero","line":2,"column":11,"file":""#));
+    assert!(json.ends_with(r#"main.bp"}"#));
+}
+
+#[test]
+fn runaway_recursion_exits_with_an_error() {
+    let output = run_bplus(&[], "dhoro f = fn(g) { ferot g(g); };\nf(f)\n");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Stack overflow - odhik recursive call"), "stderr: {}", stderr);
+}
+
+#[test]
+fn prelude_flag_loads_default_modules() {
+    let output = run_bplus(&["--prelude"], "dekhao(sqrt(9));\n");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).ends_with("\n3\n"));
+
+    let output = run_bplus(&[], "dekhao(sqrt(9));\n");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn version_flag_prints_version() {
+    for flag in ["--version", "-V"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_bplus-compiler"))
+            .arg(flag)
+            .output()
+            .expect("failed to run bplus-compiler");
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            format!("bplus-compiler {}\n", env!("CARGO_PKG_VERSION"))
+        );
+    }
+}
+
+#[test]
+fn time_flag_reports_phase_durations_on_stderr() {
+    let output = run_bplus(&["--time"], "dhoro x = 20;\ndekhao(x + 22);\n");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).ends_with("\n42\n"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for phase in ["lex:", "parse:", "eval:"] {
+        let line = stderr.lines().find(|line| line.starts_with(phase));
+        assert!(line.is_some_and(|line| line.ends_with(" ms")), "no {} line in: {}", phase, stderr);
+    }
+}
+
+#[test]
+fn vm_flag_runs_programs_on_the_bytecode_vm() {
+    let source = "dhoro fact = fn(n) { jodi (n < 2) { ferot 1; } ferot n * fact(n - 1); };\ndekhao(fact(10));\n";
+    for flags in [&[][..], &["--vm"][..]] {
+        let output = run_bplus(flags, source);
+        assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+        assert!(String::from_utf8_lossy(&output.stdout).ends_with("\n3628800\n"));
+    }
+
+    let output = run_bplus(&["--vm"], "dhoro x = 1;\ndhoro xs = [1, 2];\n");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("main.bp:2:7: Antoronio truti: the VM doesn't support arrays yet"), "stderr: {}", stderr);
+}
+
+#[test]
+fn check_flag_reports_type_errors_with_positions() {
+    let source = "dhoro is_even = fn(n) { jodi (n == 0) { ferot Ha; } ferot is_odd(n - 1); };\n\
+                  dhoro is_odd = fn(n) { jodi (n == 0) { ferot Na; } ferot is_even(n - 1); };\n\
+                  dekhao(is_even(10));\n";
+    let output = run_bplus(&["--check"], source);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).ends_with("\nHa\n"));
+
+    let output = run_bplus(&["--check"], "dhoro x = 1;\ndekhao(x - \"a\");\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("main.bp:2:10: "));
+}
+
+#[test]
+fn color_flag_controls_escapes_in_errors() {
+    let stderr_with = |flags: &[&str], no_color: Option<&str>| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bplus-compiler"));
+        command.args(flags).args(["-e", "dekhao(1 / 0)"]).current_dir(std::env::temp_dir());
+        match no_color {
+            Some(value) => command.env("NO_COLOR", value),
+            None => command.env_remove("NO_COLOR"),
+        };
+        let output = command.output().expect("failed to run bplus-compiler");
+        assert_eq!(output.status.code(), Some(1));
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+
+    let colored = stderr_with(&["--color=always"], None);
+    assert!(colored.contains("\x1b[31m"), "stderr: {:?}", colored);
+    // An explicit choice wins over NO_COLOR
+    assert!(stderr_with(&["--color", "always"], Some("1")).contains('\x1b'));
+
+    for flags in [&["--color=never"][..], &["--no-color"][..], &[][..]] {
+        let plain = stderr_with(flags, None);
+        assert!(plain.contains("division by zero"), "stderr: {:?}", plain);
+        assert!(!plain.contains('\x1b'), "{:?} stderr: {:?}", flags, plain);
+    }
+}
+
+#[test]
+fn config_file_sets_startup_defaults() {
+    // `let` and `if` are only keywords while the English language pack is active
+    let source = "let x = 41;\nif (x > 40) { dekhao(x + 1) }\n";
+    let config = [("bplus.toml", "# project defaults\nlanguage = \"english\"\n")];
+    let output = run_bplus_with_files(&[], source, &config);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("English language pack is active"), "stdout: {}", stdout);
+    assert!(stdout.ends_with("\n42\n"), "stdout: {}", stdout);
+
+    assert_eq!(run_bplus(&[], source).status.code(), Some(1));
+
+    // Activating the language keeps --ast and -e output limited to the program's own
+    let output = run_bplus_with_files(&["--ast"], source, &config);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "dhoro x = 41;\njodi (x > 40) {\n    dekhao(x + 1);\n}\n");
+    let output = run_bplus_with_files(&["--lang", "english", "-e", "let y = 2; dekhao(y)"], "", &[]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "2\n");
+
+    // Errors read as English too, parse and runtime alike
+    let output = run_bplus_with_files(&[], "let x = 0;\ndekhao(10 / x)\n", &config);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("main.bp:2:11: Internal error: division by zero"), "stderr: {}", stderr);
+    let output = run_bplus_with_files(&[], "let = 5;\n", &config);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid statement: "), "stderr: {}", stderr);
+    assert!(!stderr.contains("Template"), "stderr: {}", stderr);
+
+    // Flags win over the config file
+    let config = [("bplus.toml", "max_call_depth = 5\n")];
+    let countdown = "dhoro f = fn(n) { jodi (n > 0) { f(n - 1) } nahoy { 0 } };\ndekhao(f(10))\n";
+    assert_eq!(run_bplus_with_files(&[], countdown, &config).status.code(), Some(1));
+    assert!(run_bplus_with_files(&["--max-call-depth", "100"], countdown, &config).status.success());
+    let config = [("bplus.toml", "prelude = true\n")];
+    assert!(run_bplus_with_files(&[], "dekhao(sqrt(9));\n", &config).status.success());
+    assert!(!run_bplus_with_files(&["--no-prelude"], "dekhao(sqrt(9));\n", &config).status.success());
+
+    // A broken config is reported and otherwise ignored
+    let output = run_bplus_with_files(&[], "dekhao(1)\n", &[("bplus.toml", "rong = lal\n")]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown setting 'rong'"));
+}
@@ -0,0 +1,89 @@
+// compiler/tests/library.rs
+
+// Integration tests that use the interpreter as a library, the way an embedding program would
+
+use bplus_compiler::error::ErrorType;
+use bplus_compiler::{eval_str, load_file, run, run_capturing, Environment, Evaluator, Lexer, Object, Parser};
+use std::collections::HashMap;
+
+#[test]
+fn run_evaluates_source() {
+    assert_eq!(run("2 + 3").unwrap(), Object::Integer(5));
+    assert_eq!(run("5 > 3").unwrap(), Object::String("Ha".to_string()));
+    assert_eq!(eval_str("2 + 3"), "5");
+}
+
+#[test]
+fn run_reports_errors() {
+    let error = run("dhoro x = 1;\nx / 0").unwrap_err();
+    assert_eq!(error.error_type, ErrorType::InternalError("division by zero".to_string()));
+    let position = error.position.unwrap();
+    assert_eq!((position.line, position.column), (2, 3));
+    assert_eq!(eval_str("dhoro x = 1;\nx / 0"), "2:3: Antoronio truti: division by zero");
+
+    let error = run("dhoro f = fn(a, b) { ferot a; };\nf(1)").unwrap_err();
+    assert_eq!(error.error_type, ErrorType::WrongArgumentCount(2, 1));
+
+    let error = run("dhoro = 5;").unwrap_err();
+    assert!(matches!(error.error_type, ErrorType::InvalidStatement(_)));
+
+    let error = run("dhoro x = 1;\ndhoro y 2;").unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::InvalidStatement("expected next token to be Assign, got Int instead".to_string())
+    );
+    let position = error.position.unwrap();
+    assert_eq!((position.line, position.column), (2, 9));
+}
+
+#[test]
+fn run_stops_runaway_recursion_before_the_stack_does() {
+    // The size of a main thread's stack, where hosts usually call run
+    let worker = std::thread::Builder::new().stack_size(8 * 1024 * 1024).spawn(|| {
+        let error = run("dhoro f = fn(n) { f(n + 1) };\nf(0)").unwrap_err();
+        assert_eq!(error.error_type, ErrorType::StackOverflow);
+        let countdown = "dhoro f = fn(n) { jodi (n > 0) { f(n - 1) + 1 } nahoy { 0 } };\nf(100)";
+        assert_eq!(run(countdown).unwrap(), Object::Integer(100));
+        assert!(eval_str("dhoro f = fn(n) { f(n + 1) };\nf(0)").contains("Stack overflow"));
+    });
+    worker.unwrap().join().unwrap();
+}
+
+#[test]
+fn host_can_drive_the_pipeline() {
+    let mut evaluator = Evaluator::new();
+    let mut env = Environment::new();
+    for source in ["dhoro jog = fn(a, b) { ferot a + b; };", "dhoro x = jog(2, 3);"] {
+        let program = Parser::new(Lexer::new(source.to_string())).parse_program();
+        evaluator.eval(program, &mut env);
+    }
+    assert_eq!(env.get("x"), Some(Object::Integer(5)));
+    assert_eq!(run_capturing("dekhao(2 + 3)"), "5\n");
+}
+
+#[test]
+fn load_file_keeps_definitions() {
+    let dir = std::env::temp_dir().join(format!("bplus-load-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("gonit.bp");
+    std::fs::write(&path, "dhoro borgo = fn(n) { ferot n * n; };\nborgo(3)").unwrap();
+
+    let mut evaluator = Evaluator::new();
+    let mut env = Environment::new();
+    assert_eq!(load_file(&mut evaluator, &mut env, &path, HashMap::new()).unwrap(), Object::Integer(9));
+
+    let program = Parser::new(Lexer::new("borgo(12)".to_string())).parse_program();
+    assert_eq!(evaluator.eval(program, &mut env), Object::Integer(144));
+
+    std::fs::write(&path, "dhoro x = 1;\nx / 0").unwrap();
+    let error = load_file(&mut evaluator, &mut env, &path, HashMap::new()).unwrap_err();
+    assert_eq!(error.position.unwrap().file.as_deref(), Some(path.display().to_string().as_str()));
+    assert!(load_file(&mut evaluator, &mut env, &dir.join("nei.bp"), HashMap::new()).is_err());
+
+    // A loaded file lexes with the session's keyword aliases
+    std::fs::write(&path, "let y = 7;\ny").unwrap();
+    let aliases = HashMap::from([("let".to_string(), "dhoro".to_string())]);
+    assert_eq!(load_file(&mut evaluator, &mut env, &path, aliases).unwrap(), Object::Integer(7));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}